@@ -0,0 +1,100 @@
+use std::io;
+
+use requestty::{
+    prompt::{
+        backend::Backend,
+        events::{KeyCode, KeyEvent},
+        style::Color,
+    },
+    question::WidgetPrompt,
+    Answers, PromptModule, Question,
+};
+use ui::{layout::Layout, widgets, Prompt, Widget};
+
+const MAX_RATING: u16 = 5;
+
+/// A prompt to give a rating out of 5 stars using the arrow keys.
+#[derive(Debug)]
+struct Rating {
+    prompt: widgets::Prompt<String>,
+    rating: u16,
+}
+
+impl Rating {
+    fn new(message: String) -> Self {
+        Self {
+            prompt: widgets::Prompt::new(message).with_hint("Use arrow keys"),
+            rating: 3,
+        }
+    }
+}
+
+impl Widget for Rating {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        b.set_fg(Color::Yellow)?;
+        for _ in 0..self.rating {
+            b.write_all("★".as_bytes())?;
+        }
+        b.set_fg(Color::DarkGrey)?;
+        for _ in self.rating..MAX_RATING {
+            b.write_all("☆".as_bytes())?;
+        }
+        b.set_fg(Color::Reset)?;
+
+        layout.line_offset += MAX_RATING;
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.prompt.height(layout)
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let (x, y) = self.prompt.cursor_pos(layout);
+        (x + MAX_RATING, y)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Left if self.rating > 1 => self.rating -= 1,
+            KeyCode::Right if self.rating < MAX_RATING => self.rating += 1,
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl Prompt for Rating {
+    type ValidateErr = &'static str;
+    // The output must implement `Into<Answer>`
+    type Output = i64;
+
+    fn finish(self) -> i64 {
+        self.rating as i64
+    }
+}
+
+fn main() {
+    let module = PromptModule::new(vec![
+        Question::input("name")
+            .message("What is your name?")
+            .build(),
+        // The custom prompt is asked like any other question, and its answer is stored in the
+        // `Answers` for the following questions to use
+        Question::custom(
+            "rating",
+            WidgetPrompt::new(|message, _: &Answers| Rating::new(message)),
+        )
+        .message("How would you rate this crate?")
+        .build(),
+        Question::input("feedback")
+            .message("What can be improved?")
+            .when(|answers: &Answers| answers["rating"].as_int().unwrap() < 4)
+            .build(),
+    ]);
+
+    println!("{:#?}", module.prompt_all());
+}
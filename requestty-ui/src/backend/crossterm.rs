@@ -13,6 +13,7 @@ use crossterm::{
 };
 
 use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use crate::style::ColorSupport;
 
 /// A backend that uses the `crossterm` library.
 #[derive(Debug, Clone)]
@@ -20,24 +21,46 @@ use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
 pub struct CrosstermBackend<W> {
     buffer: W,
     attributes: Attributes,
+    color_support: ColorSupport,
+    /// The writes made since the current frame began, or `None` if there is no frame.
+    frame: Option<Vec<u8>>,
 }
 
 impl<W> CrosstermBackend<W> {
     /// Creates a new [`CrosstermBackend`]
+    ///
+    /// The colors that are set are downgraded to the [detected](ColorSupport::detect) color
+    /// support.
     pub fn new(buffer: W) -> CrosstermBackend<W> {
         CrosstermBackend {
             buffer,
             attributes: Attributes::empty(),
+            color_support: ColorSupport::detect(),
+            frame: None,
         }
     }
+
+    /// Sets the colors supported by the terminal instead of detecting them.
+    pub fn with_color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
 }
 
 impl<W: Write> Write for CrosstermBackend<W> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.buffer.write(buf)
+        match self.frame {
+            Some(ref mut frame) => frame.write(buf),
+            None => self.buffer.write(buf),
+        }
     }
 
     fn flush(&mut self) -> io::Result<()> {
+        // The frame is written and flushed all at once when it ends
+        if self.frame.is_some() {
+            return Ok(());
+        }
+
         self.buffer.flush()
     }
 }
@@ -52,11 +75,25 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
-        queue!(self.buffer, cursor::Hide)
+        queue!(self, cursor::Hide)
     }
 
     fn show_cursor(&mut self) -> io::Result<()> {
-        queue!(self.buffer, cursor::Show)
+        queue!(self, cursor::Show)
+    }
+
+    fn begin_frame(&mut self) -> io::Result<()> {
+        if self.frame.is_none() {
+            self.frame = Some(Vec::new());
+        }
+        Ok(())
+    }
+
+    fn end_frame(&mut self) -> io::Result<()> {
+        if let Some(frame) = self.frame.take() {
+            self.buffer.write_all(&frame)?;
+        }
+        self.buffer.flush()
     }
 
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
@@ -64,21 +101,21 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     }
 
     fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
-        queue!(self.buffer, cursor::MoveTo(x, y))
+        queue!(self, cursor::MoveTo(x, y))
     }
 
     fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
         match direction {
-            MoveDirection::Up(n) => queue!(self.buffer, cursor::MoveUp(n)),
-            MoveDirection::Down(n) => queue!(self.buffer, cursor::MoveDown(n)),
-            MoveDirection::Left(n) => queue!(self.buffer, cursor::MoveLeft(n)),
-            MoveDirection::Right(n) => queue!(self.buffer, cursor::MoveRight(n)),
+            MoveDirection::Up(n) => queue!(self, cursor::MoveUp(n)),
+            MoveDirection::Down(n) => queue!(self, cursor::MoveDown(n)),
+            MoveDirection::Left(n) => queue!(self, cursor::MoveLeft(n)),
+            MoveDirection::Right(n) => queue!(self, cursor::MoveRight(n)),
             MoveDirection::NextLine(n) => {
-                queue!(self.buffer, cursor::MoveToNextLine(n))
+                queue!(self, cursor::MoveToNextLine(n))
             }
-            MoveDirection::Column(n) => queue!(self.buffer, cursor::MoveToColumn(n)),
+            MoveDirection::Column(n) => queue!(self, cursor::MoveToColumn(n)),
             MoveDirection::PrevLine(n) => {
-                queue!(self.buffer, cursor::MoveToPreviousLine(n))
+                queue!(self, cursor::MoveToPreviousLine(n))
             }
         }
     }
@@ -86,35 +123,57 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     fn scroll(&mut self, dist: i16) -> io::Result<()> {
         match dist.cmp(&0) {
             Ordering::Greater => {
-                queue!(self.buffer, terminal::ScrollDown(dist as u16))
+                queue!(self, terminal::ScrollDown(dist as u16))
             }
             Ordering::Less => {
-                queue!(self.buffer, terminal::ScrollUp(-dist as u16))
+                queue!(self, terminal::ScrollUp(-dist as u16))
             }
             Ordering::Equal => Ok(()),
         }
     }
 
     fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
-        set_attributes(self.attributes, attributes, &mut self.buffer)?;
+        set_attributes(self.attributes, attributes, &mut *self)?;
         self.attributes = attributes;
         Ok(())
     }
 
     fn set_fg(&mut self, color: Color) -> io::Result<()> {
-        queue!(self.buffer, SetForegroundColor(color.into()))
+        match self.color_support.downgrade(color) {
+            Some(color) => queue!(self, SetForegroundColor(color.into())),
+            None => Ok(()),
+        }
     }
 
     fn set_bg(&mut self, color: Color) -> io::Result<()> {
-        queue!(self.buffer, SetBackgroundColor(color.into()))
+        match self.color_support.downgrade(color) {
+            Some(color) => queue!(self, SetBackgroundColor(color.into())),
+            None => Ok(()),
+        }
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        queue!(self, terminal::SetTitle(title))
+    }
+
+    fn save_title(&mut self) -> io::Result<()> {
+        // Pushes the title onto xterm's title stack
+        self.write_all(b"\x1B[22;0t")
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        // Pops the title from xterm's title stack
+        self.write_all(b"\x1B[23;0t")
     }
 
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
-        queue!(self.buffer, terminal::Clear(clear_type.into()))
+        queue!(self, terminal::Clear(clear_type.into()))
     }
 
     fn size(&self) -> io::Result<Size> {
-        terminal::size().map(Into::into)
+        Ok(super::size_with_fallback(|| {
+            terminal::size().map(Into::into)
+        }))
     }
 }
 
@@ -216,3 +275,50 @@ pub(super) fn set_attributes<W: Write>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records the writes it is given, and the number of times it is flushed
+    #[derive(Debug, Default)]
+    struct FlushCounter {
+        written: Vec<u8>,
+        flushes: usize,
+    }
+
+    impl Write for FlushCounter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_frame() {
+        let mut backend = CrosstermBackend::new(FlushCounter::default());
+
+        backend.begin_frame().unwrap();
+        backend.write_all(b"Hello").unwrap();
+        backend.move_cursor_to(0, 1).unwrap();
+        backend.flush().unwrap();
+        backend.write_all(b"World").unwrap();
+
+        // Nothing is written until the frame ends
+        assert!(backend.buffer.written.is_empty());
+        assert_eq!(backend.buffer.flushes, 0);
+
+        backend.end_frame().unwrap();
+        assert_eq!(backend.buffer.written, b"Hello\x1b[2;1HWorld");
+        assert_eq!(backend.buffer.flushes, 1);
+
+        // Outside of a frame, the writes go through immediately
+        backend.write_all(b"!").unwrap();
+        assert_eq!(backend.buffer.written, b"Hello\x1b[2;1HWorld!");
+    }
+}
@@ -1,6 +1,6 @@
 //! A module to represent a terminal and operations on it.
 
-use std::{fmt::Display, io};
+use std::{fmt::Display, io, time::Duration};
 
 /// Gets the default [`Backend`] based on the features enabled.
 #[cfg(any(feature = "crossterm", feature = "termion"))]
@@ -17,6 +17,76 @@ pub fn get_backend<W: io::Write>(buf: W) -> impl Backend {
     return TermionBackend::new(buf);
 }
 
+/// Gets the size of the terminal using the default [`Backend`] based on the features enabled.
+///
+/// This is useful to lay out other output around a prompt without creating a backend. If the
+/// terminal cannot be queried, the size falls back to the `COLUMNS` and `LINES` environment
+/// variables, and then to 80x24. An error is returned if the resulting size is still empty.
+///
+/// # Example
+///
+/// ```no_run
+/// let size = requestty_ui::terminal_size().unwrap();
+/// println!("{}x{}", size.width, size.height);
+/// ```
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+pub fn terminal_size() -> io::Result<Size> {
+    checked_size(&get_backend(io::sink()))
+}
+
+/// Gets the size of the terminal from the backend, failing if it is empty.
+pub(crate) fn checked_size<B: Backend + ?Sized>(backend: &B) -> io::Result<Size> {
+    let size = backend.size()?;
+
+    if size.area() == 0 {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "Invalid terminal {:?}. Both width and height must be larger than 0",
+                size
+            ),
+        ))
+    } else {
+        Ok(size)
+    }
+}
+
+/// The size used for the dimensions which can neither be queried from the terminal, nor read from
+/// the environment.
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+const DEFAULT_SIZE: Size = Size {
+    width: 80,
+    height: 24,
+};
+
+/// Gets the size of the terminal for the default backends. In order of precedence, it is:
+///
+/// 1. The size given by `query`, which asks the terminal for its size. It is skipped if it fails,
+///    or if either dimension is 0, which can happen if the output is not a terminal.
+/// 2. The `COLUMNS` and `LINES` environment variables, for each of them that is a positive
+///    number. These are set by some harnesses which do not run in a real terminal.
+/// 3. The [`DEFAULT_SIZE`].
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+fn size_with_fallback<F: FnOnce() -> io::Result<Size>>(query: F) -> Size {
+    match query() {
+        Ok(size) if size.area() != 0 => size,
+        _ => {
+            let env_dimension = |var| {
+                std::env::var(var)
+                    .ok()
+                    .and_then(|value| value.trim().parse::<u16>().ok())
+                    .filter(|&value| value != 0)
+            };
+
+            Size {
+                width: env_dimension("COLUMNS").unwrap_or(DEFAULT_SIZE.width),
+                height: env_dimension("LINES").unwrap_or(DEFAULT_SIZE.height),
+            }
+        }
+    }
+}
+
 mod test_backend;
 pub use test_backend::TestBackend;
 
@@ -32,7 +102,7 @@ mod crossterm;
 #[cfg(feature = "crossterm")]
 pub use self::crossterm::CrosstermBackend;
 
-use crate::style::{Attributes, Color, Styled};
+use crate::style::{Attributes, Color, Styled, StyledSpan};
 
 /// A 2D size.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
@@ -100,6 +170,22 @@ pub trait Backend: io::Write {
     /// Shows the cursor.
     fn show_cursor(&mut self) -> io::Result<()>;
 
+    /// Begins a frame. The writes until the frame [ends](Backend::end_frame) may be buffered, so
+    /// that they are shown all at once, reducing tearing.
+    ///
+    /// By default, this does nothing.
+    fn begin_frame(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Ends the frame begun by [`begin_frame`], writing and flushing everything written during it.
+    ///
+    /// By default, this only [flushes](io::Write::flush) the backend.
+    ///
+    /// [`begin_frame`]: Backend::begin_frame
+    fn end_frame(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+
     /// Gets the cursor position as (col, row). The top-left cell is (0, 0).
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)>;
     /// Moves the cursor to given position. The top-left cell is (0, 0).
@@ -128,6 +214,53 @@ pub trait Backend: io::Write {
     fn write_styled(&mut self, styled: &Styled<dyn Display + '_>) -> io::Result<()> {
         styled.write(self)
     }
+    /// Write a sequence of styled spans to the backend.
+    ///
+    /// This is equivalent to calling [`write_styled`] on each span, except that the style is
+    /// only changed when it differs from the previous span, and is only reset once at the end.
+    ///
+    /// See also [`StyledSpan`] and [`Stylize`].
+    ///
+    /// [`write_styled`]: Backend::write_styled
+    /// [`Stylize`]: crate::style::Stylize
+    fn write_styled_spans(&mut self, spans: &[StyledSpan<'_>]) -> io::Result<()> {
+        crate::style::write_spans(spans, self)
+    }
+
+    /// Sets the title of the terminal window.
+    ///
+    /// By default, this does nothing.
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        let _ = title;
+        Ok(())
+    }
+    /// Saves the current title of the terminal window, so that it can be restored later with
+    /// [`restore_title`]. Terminals which do not support this ignore it.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`restore_title`]: Backend::restore_title
+    fn save_title(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Restores the title of the terminal window saved by the last [`save_title`]. Terminals
+    /// which do not support this leave the title as it is.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`save_title`]: Backend::save_title
+    fn restore_title(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Waits for the given duration with the current contents shown, such as to keep an answered
+    /// prompt on the screen for a minimum time.
+    ///
+    /// By default, this blocks the current thread for the duration.
+    fn sleep(&mut self, duration: Duration) -> io::Result<()> {
+        std::thread::sleep(duration);
+        Ok(())
+    }
 
     /// Clears the cells given by clear_type
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()>;
@@ -160,7 +293,7 @@ fn default_move_cursor<B: Backend + ?Sized>(
     backend.move_cursor_to(x, y)
 }
 
-impl<'a, B: Backend> Backend for &'a mut B {
+impl<'a, B: Backend + ?Sized> Backend for &'a mut B {
     fn enable_raw_mode(&mut self) -> io::Result<()> {
         (**self).enable_raw_mode()
     }
@@ -173,6 +306,12 @@ impl<'a, B: Backend> Backend for &'a mut B {
     fn show_cursor(&mut self) -> io::Result<()> {
         (**self).show_cursor()
     }
+    fn begin_frame(&mut self) -> io::Result<()> {
+        (**self).begin_frame()
+    }
+    fn end_frame(&mut self) -> io::Result<()> {
+        (**self).end_frame()
+    }
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
         (**self).get_cursor_pos()
     }
@@ -197,6 +336,21 @@ impl<'a, B: Backend> Backend for &'a mut B {
     fn write_styled(&mut self, styled: &Styled<dyn Display + '_>) -> io::Result<()> {
         (**self).write_styled(styled)
     }
+    fn write_styled_spans(&mut self, spans: &[StyledSpan<'_>]) -> io::Result<()> {
+        (**self).write_styled_spans(spans)
+    }
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        (**self).set_title(title)
+    }
+    fn save_title(&mut self) -> io::Result<()> {
+        (**self).save_title()
+    }
+    fn restore_title(&mut self) -> io::Result<()> {
+        (**self).restore_title()
+    }
+    fn sleep(&mut self, duration: Duration) -> io::Result<()> {
+        (**self).sleep(duration)
+    }
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
         (**self).clear(clear_type)
     }
@@ -204,3 +358,158 @@ impl<'a, B: Backend> Backend for &'a mut B {
         (**self).size()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Stylize;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum StyleOp {
+        Fg(Color),
+        Bg(Color),
+        Attributes(Attributes),
+    }
+
+    /// Records all the style changes made on the backend
+    struct StyleOpsBackend {
+        backend: TestBackend,
+        ops: Vec<StyleOp>,
+    }
+
+    impl io::Write for StyleOpsBackend {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.backend.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.backend.flush()
+        }
+    }
+
+    impl Backend for StyleOpsBackend {
+        fn enable_raw_mode(&mut self) -> io::Result<()> {
+            self.backend.enable_raw_mode()
+        }
+        fn disable_raw_mode(&mut self) -> io::Result<()> {
+            self.backend.disable_raw_mode()
+        }
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.backend.hide_cursor()
+        }
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.backend.show_cursor()
+        }
+        fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+            self.backend.get_cursor_pos()
+        }
+        fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+            self.backend.move_cursor_to(x, y)
+        }
+        fn scroll(&mut self, dist: i16) -> io::Result<()> {
+            self.backend.scroll(dist)
+        }
+        fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+            self.ops.push(StyleOp::Attributes(attributes));
+            self.backend.set_attributes(attributes)
+        }
+        fn set_fg(&mut self, color: Color) -> io::Result<()> {
+            self.ops.push(StyleOp::Fg(color));
+            self.backend.set_fg(color)
+        }
+        fn set_bg(&mut self, color: Color) -> io::Result<()> {
+            self.ops.push(StyleOp::Bg(color));
+            self.backend.set_bg(color)
+        }
+        fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+            self.backend.clear(clear_type)
+        }
+        fn size(&self) -> io::Result<Size> {
+            self.backend.size()
+        }
+    }
+
+    #[test]
+    fn test_write_styled_spans() {
+        let size = (50, 5).into();
+        let spans = [
+            "? ".light_green(),
+            "name".into(),
+            " › ".dark_grey(),
+            "John".cyan().bold(),
+            " ".cyan().bold(),
+            "Doe".cyan().bold(),
+            "!".cyan(),
+            " ".into(),
+            "done".on_green(),
+        ];
+
+        let mut backend = StyleOpsBackend {
+            backend: TestBackend::new(size),
+            ops: Vec::new(),
+        };
+        backend.write_styled_spans(&spans).unwrap();
+
+        assert_eq!(
+            backend.ops,
+            [
+                StyleOp::Fg(Color::LightGreen),
+                StyleOp::Fg(Color::Reset),
+                StyleOp::Fg(Color::DarkGrey),
+                StyleOp::Fg(Color::Cyan),
+                StyleOp::Attributes(Attributes::BOLD),
+                StyleOp::Attributes(Attributes::empty()),
+                StyleOp::Fg(Color::Reset),
+                StyleOp::Bg(Color::Green),
+                StyleOp::Bg(Color::Reset),
+            ]
+        );
+
+        let mut expected = TestBackend::new(size);
+        for span in &spans {
+            expected.write_styled(span).unwrap();
+        }
+        backend.backend.assert_eq(&expected);
+
+        backend.ops.clear();
+        backend.write_styled_spans(&[]).unwrap();
+        assert!(backend.ops.is_empty());
+    }
+
+    #[test]
+    fn test_checked_size() {
+        let size = (30, 8).into();
+        assert_eq!(checked_size(&TestBackend::new(size)).unwrap(), size);
+
+        // a terminal without any space, as reported when the output is not a terminal
+        assert!(checked_size(&TestBackend::new((0, 0).into())).is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    fn test_size_with_fallback() {
+        fn failing_ioctl() -> io::Result<Size> {
+            Err(io::ErrorKind::Other.into())
+        }
+
+        // All cases are in a single test as the environment is shared by all the tests
+        std::env::set_var("COLUMNS", "100");
+        std::env::set_var("LINES", "30");
+
+        // the terminal takes precedence over the environment
+        assert_eq!(size_with_fallback(|| Ok((40, 10).into())), (40, 10).into());
+
+        assert_eq!(size_with_fallback(failing_ioctl), (100, 30).into());
+        assert_eq!(size_with_fallback(|| Ok((0, 0).into())), (100, 30).into());
+
+        // each dimension falls back to the default separately
+        std::env::set_var("LINES", "0");
+        assert_eq!(size_with_fallback(failing_ioctl), (100, 24).into());
+
+        std::env::set_var("COLUMNS", "wide");
+        std::env::remove_var("LINES");
+        assert_eq!(size_with_fallback(failing_ioctl), DEFAULT_SIZE);
+
+        std::env::remove_var("COLUMNS");
+    }
+}
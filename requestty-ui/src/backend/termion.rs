@@ -12,6 +12,7 @@ use termion::{
 };
 
 use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use crate::style::ColorSupport;
 
 enum Terminal<W: Write> {
     Raw(RawTerminal<W>),
@@ -47,16 +48,27 @@ impl<W: Write> DerefMut for Terminal<W> {
 pub struct TermionBackend<W: Write> {
     attributes: Attributes,
     buffer: Terminal<W>,
+    color_support: ColorSupport,
 }
 
 impl<W: Write> TermionBackend<W> {
     /// Creates a new [`TermionBackend`]
+    ///
+    /// The colors that are set are downgraded to the [detected](ColorSupport::detect) color
+    /// support.
     pub fn new(buffer: W) -> TermionBackend<W> {
         TermionBackend {
             buffer: Terminal::Normal(buffer),
             attributes: Attributes::empty(),
+            color_support: ColorSupport::detect(),
         }
     }
+
+    /// Sets the colors supported by the terminal instead of detecting them.
+    pub fn with_color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = color_support;
+        self
+    }
 }
 
 impl<W: Write> Write for TermionBackend<W> {
@@ -150,11 +162,31 @@ impl<W: Write> Backend for TermionBackend<W> {
     }
 
     fn set_fg(&mut self, color: Color) -> io::Result<()> {
-        write!(self.buffer, "{}", Fg(color))
+        match self.color_support.downgrade(color) {
+            Some(color) => write!(self.buffer, "{}", Fg(color)),
+            None => Ok(()),
+        }
     }
 
     fn set_bg(&mut self, color: Color) -> io::Result<()> {
-        write!(self.buffer, "{}", Bg(color))
+        match self.color_support.downgrade(color) {
+            Some(color) => write!(self.buffer, "{}", Bg(color)),
+            None => Ok(()),
+        }
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        write!(self.buffer, "\x1B]0;{}\x07", title)
+    }
+
+    fn save_title(&mut self) -> io::Result<()> {
+        // Pushes the title onto xterm's title stack
+        self.buffer.write_all(b"\x1B[22;0t")
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        // Pops the title from xterm's title stack
+        self.buffer.write_all(b"\x1B[23;0t")
     }
 
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
@@ -174,7 +206,9 @@ impl<W: Write> Backend for TermionBackend<W> {
     }
 
     fn size(&self) -> io::Result<Size> {
-        termion::terminal_size().map(Into::into)
+        Ok(super::size_with_fallback(|| {
+            termion::terminal_size().map(Into::into)
+        }))
     }
 }
 
@@ -1,6 +1,7 @@
 use std::{
     io::{self, Write},
     ops,
+    time::Duration,
 };
 
 use super::{Backend, ClearType, MoveDirection, Size};
@@ -63,6 +64,12 @@ pub struct TestBackend {
     current_bg: Color,
     current_attributes: Attributes,
     viewport_start: usize,
+    frames: usize,
+    title: Option<String>,
+    /// The titles saved by `save_title`, most recent last.
+    saved_titles: Vec<Option<String>>,
+    /// The total time of the calls to `sleep`, which does not actually wait.
+    slept: Duration,
 }
 
 impl PartialEq for TestBackend {
@@ -98,6 +105,10 @@ impl TestBackend {
             current_bg: Color::Reset,
             current_attributes: Attributes::empty(),
             viewport_start: 0,
+            frames: 0,
+            title: None,
+            saved_titles: Vec::new(),
+            slept: Duration::from_secs(0),
         };
 
         this.move_x(layout.line_offset + layout.offset_x);
@@ -136,6 +147,39 @@ impl TestBackend {
         backend
     }
 
+    /// Returns the text shown on the screen, without any styling or the cursor.
+    ///
+    /// Every row of the screen is a line, with cells that have not been written to shown as
+    /// spaces. The trailing whitespace of every line and the trailing empty lines are removed.
+    pub fn to_plain_string(&self) -> String {
+        let lines: Vec<String> = self
+            .viewport()
+            .chunks(self.size.width.max(1) as usize)
+            .map(|row| {
+                let line: String = row.iter().map(|cell| cell.value.unwrap_or(' ')).collect();
+                line.trim_end().to_owned()
+            })
+            .collect();
+
+        lines.join("\n").trim_end_matches('\n').to_owned()
+    }
+
+    /// The number of frames which have [ended](super::Backend::end_frame).
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// The title of the terminal window set with [`set_title`](super::Backend::set_title), if any.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// The total duration the backend was asked to [`sleep`](super::Backend::sleep) for. The
+    /// `TestBackend` does not actually wait.
+    pub fn slept(&self) -> Duration {
+        self.slept
+    }
+
     /// Clears all the cells and moves the cursor to the offsets given by the layout.
     pub fn reset_with_layout(&mut self, layout: Layout) {
         self.clear_range(..);
@@ -284,6 +328,11 @@ impl Write for TestBackend {
 }
 
 impl super::Backend for TestBackend {
+    fn end_frame(&mut self) -> io::Result<()> {
+        self.frames += 1;
+        Ok(())
+    }
+
     fn enable_raw_mode(&mut self) -> io::Result<()> {
         self.raw = true;
         Ok(())
@@ -364,6 +413,28 @@ impl super::Backend for TestBackend {
         Ok(())
     }
 
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.title = Some(title.to_owned());
+        Ok(())
+    }
+
+    fn save_title(&mut self) -> io::Result<()> {
+        self.saved_titles.push(self.title.clone());
+        Ok(())
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        if let Some(title) = self.saved_titles.pop() {
+            self.title = title;
+        }
+        Ok(())
+    }
+
+    fn sleep(&mut self, duration: Duration) -> io::Result<()> {
+        self.slept += duration;
+        Ok(())
+    }
+
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
         match clear_type {
             ClearType::All => self.clear_range(..),
@@ -14,13 +14,16 @@ pub enum ErrorKind {
     Eof,
     /// The user aborted the question with `Esc`
     Aborted,
+    /// The user asked to go back to the previous question with `Shift+Tab`. See
+    /// [`Input::back_on_back_tab`](crate::Input::back_on_back_tab).
+    Back,
 }
 
 impl std::error::Error for ErrorKind {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             ErrorKind::IoError(e) => Some(e),
-            ErrorKind::Interrupted | ErrorKind::Eof | ErrorKind::Aborted => None,
+            ErrorKind::Interrupted | ErrorKind::Eof | ErrorKind::Aborted | ErrorKind::Back => None,
         }
     }
 }
@@ -32,6 +35,7 @@ impl fmt::Display for ErrorKind {
             ErrorKind::Interrupted => write!(fmt, "CTRL+C"),
             ErrorKind::Aborted => write!(fmt, "ESC"),
             ErrorKind::Eof => write!(fmt, "EOF"),
+            ErrorKind::Back => write!(fmt, "SHIFT+TAB"),
         }
     }
 }
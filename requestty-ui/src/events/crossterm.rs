@@ -1,4 +1,7 @@
-use std::convert::{TryFrom, TryInto};
+use std::{
+    convert::{TryFrom, TryInto},
+    time::{Duration, Instant},
+};
 
 use crossterm::event;
 
@@ -27,6 +30,23 @@ impl EventIterator for CrosstermEvents {
             }
         }
     }
+
+    fn poll_event(&mut self, timeout: Duration) -> std::io::Result<Option<super::KeyEvent>> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            if !event::poll(timeout)? {
+                return Ok(None);
+            }
+
+            if let event::Event::Key(k) = event::read()? {
+                if let Ok(k) = k.try_into() {
+                    return Ok(Some(k));
+                }
+            }
+        }
+    }
 }
 
 impl TryFrom<event::KeyEvent> for super::KeyEvent {
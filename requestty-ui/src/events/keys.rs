@@ -10,8 +10,26 @@ bitflags::bitflags! {
     }
 }
 
+// bitflags 1 has no serde support, so the modifiers are (de)serialized as their bits
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyModifiers {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeyModifiers {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u8::deserialize(deserializer)?;
+        KeyModifiers::from_bits(bits)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid key modifiers: {:#b}", bits)))
+    }
+}
+
 /// Represents a key event.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyEvent {
     /// The key itself.
     pub code: KeyCode,
@@ -37,6 +55,7 @@ impl From<KeyCode> for KeyEvent {
 
 /// Represents a key.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyCode {
     /// Backspace key.
     Backspace,
@@ -1,6 +1,6 @@
 //! A module for handling key events
 
-use std::io;
+use std::{io, time::Duration};
 
 #[cfg(feature = "crossterm")]
 mod crossterm;
@@ -15,29 +15,160 @@ pub use self::termion::TermionEvents;
 
 mod keys;
 mod movement;
+mod piped;
 
 pub use keys::{KeyCode, KeyEvent, KeyModifiers};
 pub use movement::Movement;
+pub use piped::PipedEvents;
 
 /// Gets the default [`EventIterator`] based on the features enabled.
+///
+/// The events are always read from the terminal. To answer prompts from lines of stdin when it is
+/// piped, use [`get_events_allowing_piped`].
 #[cfg(any(feature = "crossterm", feature = "termion"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
 pub fn get_events() -> impl EventIterator {
+    get_events_allowing_piped(false)
+}
+
+/// Gets the default [`EventIterator`] based on the features enabled, which reads lines of stdin
+/// with [`PipedEvents`] if `allow_piped` is `true` and stdin is not a terminal.
+///
+/// Only prompts which take text can be answered from piped stdin. Any other prompt, such as a
+/// select or a confirm, fails with [`Eof`](crate::ErrorKind::Eof). If `allow_piped` is `false`,
+/// this is the same as [`get_events`].
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+pub fn get_events_allowing_piped(allow_piped: bool) -> impl EventIterator {
     #[cfg(feature = "crossterm")]
-    return CrosstermEvents::new();
+    let (events, is_tty) = (
+        CrosstermEvents::new(),
+        ::crossterm::tty::IsTty::is_tty(&io::stdin()),
+    );
 
     // XXX: Only works when crossterm and termion are the only two available backends
     //
     // Instead of directly checking for termion, we check for not crossterm so that compiling
     // (documentation) with both features enabled will not error
     #[cfg(not(feature = "crossterm"))]
-    return TermionEvents::new();
+    let (events, is_tty) = (TermionEvents::new(), ::termion::is_tty(&io::stdin()));
+
+    if is_tty || !allow_piped {
+        DefaultEvents::Interactive(events)
+    } else {
+        DefaultEvents::Piped(PipedEvents::new(io::BufReader::new(io::stdin())))
+    }
+}
+
+/// The events returned by [`get_events_allowing_piped`], which depend on whether stdin is a
+/// terminal.
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+enum DefaultEvents<E> {
+    Interactive(E),
+    Piped(PipedEvents<io::BufReader<io::Stdin>>),
+}
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+impl<E: EventIterator> EventIterator for DefaultEvents<E> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        match self {
+            DefaultEvents::Interactive(events) => events.next_event(),
+            DefaultEvents::Piped(events) => events.next_event(),
+        }
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        match self {
+            DefaultEvents::Interactive(events) => events.poll_event(timeout),
+            DefaultEvents::Piped(events) => events.poll_event(timeout),
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        matches!(self, DefaultEvents::Interactive(_))
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        match self {
+            DefaultEvents::Interactive(events) => events.next_line(),
+            DefaultEvents::Piped(events) => events.next_line(),
+        }
+    }
+}
+
+/// A hook which decodes the raw bytes of an event read from the terminal into a [`KeyEvent`].
+///
+/// It can be used to support terminals which send sequences that are not otherwise understood,
+/// such as those of the kitty keyboard protocol or `modifyOtherKeys`, by mapping them to keys the
+/// prompts understand. It is given the bytes of a single event, and returns `None` to let them be
+/// decoded as usual.
+///
+/// It is implemented for all closures with the same signature as [`decode`](KeyDecoder::decode).
+/// Currently, only `TermionEvents` can use a decoder, since `crossterm` does not expose the raw
+/// bytes it reads.
+pub trait KeyDecoder {
+    /// Decode the raw bytes of an event, or return `None` to decode them as usual.
+    fn decode(&mut self, raw: &[u8]) -> Option<KeyEvent>;
+}
+
+impl<F: FnMut(&[u8]) -> Option<KeyEvent>> KeyDecoder for F {
+    fn decode(&mut self, raw: &[u8]) -> Option<KeyEvent> {
+        self(raw)
+    }
 }
 
 /// A trait to represent a source of [`KeyEvent`]s.
 pub trait EventIterator {
     /// Get the next event
     fn next_event(&mut self) -> io::Result<KeyEvent>;
+
+    /// Get the next event if one is received within the timeout, otherwise `None`.
+    ///
+    /// By default, the timeout is ignored and it waits for the [next event]. This means that
+    /// [refresh requests] are only handled after the next key, and a [countdown] never runs. Only
+    /// `CrosstermEvents` implements it, `TermionEvents` and [`PipedEvents`] use the default.
+    ///
+    /// [next event]: EventIterator::next_event
+    /// [refresh requests]: crate::Input::with_refresh
+    /// [countdown]: crate::Input::countdown
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        let _ = timeout;
+        self.next_event().map(Some)
+    }
+
+    /// Whether the events come from a user, which is the default. If they do not, prompts which
+    /// take text answer with [`next_line`] instead of reading events.
+    ///
+    /// [`next_line`]: EventIterator::next_line
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    /// Get the next line of text, without the line ending, or `None` if there are no more lines.
+    /// It is only called if the events are not [interactive].
+    ///
+    /// [interactive]: EventIterator::is_interactive
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+impl<E: EventIterator + ?Sized> EventIterator for &mut E {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        (**self).next_event()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        (**self).poll_event(timeout)
+    }
+
+    fn is_interactive(&self) -> bool {
+        (**self).is_interactive()
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        (**self).next_line()
+    }
 }
 
 /// A simple wrapper around a [`KeyEvent`] iterator that can be used in tests.
@@ -0,0 +1,63 @@
+use std::io::{self, BufRead};
+
+use super::{EventIterator, KeyCode, KeyEvent};
+
+/// An [`EventIterator`] over the lines of a non-interactive input, such as a pipe.
+///
+/// Prompts which take text, such as an input or a number, are answered with the next line instead
+/// of reading keys. Other prompts cannot be answered, and receive a [`KeyCode::Null`], which
+/// stops them with an [`Eof`] error.
+///
+/// [`get_events_allowing_piped`] returns this over stdin if it is not a terminal.
+///
+/// # Example
+///
+/// ```
+/// use requestty_ui::events::{EventIterator, PipedEvents};
+///
+/// let mut events = PipedEvents::new("John\n".as_bytes());
+/// assert!(!events.is_interactive());
+/// assert_eq!(events.next_line().unwrap(), Some("John".to_owned()));
+/// assert_eq!(events.next_line().unwrap(), None);
+/// ```
+///
+/// [`Eof`]: crate::ErrorKind::Eof
+/// [`get_events_allowing_piped`]: super::get_events_allowing_piped
+#[derive(Debug)]
+pub struct PipedEvents<R> {
+    reader: R,
+}
+
+impl<R: BufRead> PipedEvents<R> {
+    /// Creates a new `PipedEvents` which reads lines from `reader`
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+}
+
+impl<R: BufRead> EventIterator for PipedEvents<R> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        Ok(KeyCode::Null.into())
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+
+        Ok(Some(line))
+    }
+}
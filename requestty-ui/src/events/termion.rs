@@ -1,20 +1,30 @@
 use std::{
     convert::{TryFrom, TryInto},
     fmt,
-    io::{self, stdin, Stdin},
+    io::{self, stdin, Read, Stdin},
 };
 
 use termion::{
     event,
-    input::{self, TermRead},
+    input::{self, TermReadEventsAndRaw},
 };
 
-use super::EventIterator;
+use super::{EventIterator, KeyDecoder};
 
 /// An iterator over the input keys using the `termion` crate
+///
+/// It reads from stdin by default, but can read from any [`Read`] with
+/// [`from_reader`](TermionEvents::from_reader).
+///
+/// It does not support [polling](EventIterator::poll_event) with a timeout, so [refresh requests]
+/// and [countdowns] are only handled after the next key.
+///
+/// [refresh requests]: crate::Input::with_refresh
+/// [countdowns]: crate::Input::countdown
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
-pub struct TermionEvents {
-    events: input::Keys<Stdin>,
+pub struct TermionEvents<R = Stdin> {
+    events: input::EventsAndRaw<R>,
+    decoder: Option<Box<dyn KeyDecoder>>,
 }
 
 struct Dash;
@@ -25,10 +35,11 @@ impl fmt::Debug for Dash {
     }
 }
 
-impl fmt::Debug for TermionEvents {
+impl<R> fmt::Debug for TermionEvents<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TermionEvents")
             .field("events", &Dash)
+            .field("decoder", &self.decoder.as_ref().map(|_| Dash))
             .finish()
     }
 }
@@ -36,10 +47,44 @@ impl fmt::Debug for TermionEvents {
 impl TermionEvents {
     /// Creates a new `TermionEvents` using stdin
     pub fn new() -> Self {
+        Self::from_reader(stdin())
+    }
+}
+
+impl<R: Read> TermionEvents<R> {
+    /// Creates a new `TermionEvents` which reads the input from `reader` instead of stdin
+    pub fn from_reader(reader: R) -> Self {
         Self {
-            events: stdin().keys(),
+            events: reader.events_and_raw(),
+            decoder: None,
         }
     }
+
+    /// Decode the raw bytes of every event with the given [`KeyDecoder`] before `termion` does.
+    ///
+    /// This is useful for terminals which send sequences `termion` does not understand, such as
+    /// the kitty keyboard protocol. Events which the decoder returns `None` for are decoded by
+    /// `termion` as usual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use requestty_ui::events::{EventIterator, KeyCode, KeyEvent, TermionEvents};
+    ///
+    /// // `Shift+Enter` in the kitty keyboard protocol
+    /// let mut events = TermionEvents::from_reader(&b"\x1b[13;2u"[..]).with_decoder(
+    ///     |raw: &[u8]| match raw {
+    ///         b"\x1b[13;2u" => Some(KeyEvent::from(KeyCode::Enter)),
+    ///         _ => None,
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(events.next_event().unwrap(), KeyCode::Enter.into());
+    /// ```
+    pub fn with_decoder<D: KeyDecoder + 'static>(mut self, decoder: D) -> Self {
+        self.decoder = Some(Box::new(decoder));
+        self
+    }
 }
 
 impl Default for TermionEvents {
@@ -48,13 +93,25 @@ impl Default for TermionEvents {
     }
 }
 
-impl EventIterator for TermionEvents {
+impl<R: Read> EventIterator for TermionEvents<R> {
     fn next_event(&mut self) -> io::Result<super::KeyEvent> {
-        let e = self
-            .events
-            .next()
-            .expect("TermionEvents ran out of user input!?")?;
-        e.try_into()
+        loop {
+            let (e, raw) = self
+                .events
+                .next()
+                .expect("TermionEvents ran out of user input!?")?;
+
+            if let Some(ref mut decoder) = self.decoder {
+                if let Some(key) = decoder.decode(&raw) {
+                    return Ok(key);
+                }
+            }
+
+            // Mouse events and unsupported sequences are ignored
+            if let event::Event::Key(e) = e {
+                return e.try_into();
+            }
+        }
     }
 }
 
@@ -146,3 +203,35 @@ fn parse_char(mut c: char, mut modifiers: super::KeyModifiers) -> io::Result<sup
 
     Ok(super::KeyEvent::new(code, modifiers))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn test_decoder() {
+        // `Ctrl+Enter` in the kitty keyboard protocol, followed by regular keys
+        let input = b"\x1b[13;5ua\x1b[A";
+
+        // Only the sequence decoded by the decoder is changed
+        let mut events =
+            TermionEvents::from_reader(&input[..]).with_decoder(|raw: &[u8]| match raw {
+                b"\x1b[13;5u" => Some(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL)),
+                _ => None,
+            });
+
+        assert_eq!(
+            events.next_event().unwrap(),
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL)
+        );
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('a').into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Up.into());
+
+        // Without a decoder, the unsupported sequence is skipped
+        let mut events = TermionEvents::from_reader(&input[..]);
+
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('a').into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Up.into());
+    }
+}
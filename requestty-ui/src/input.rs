@@ -1,21 +1,42 @@
 use std::{
     io,
     ops::{Deref, DerefMut},
+    sync::mpsc::{Receiver, TryRecvError},
+    time::Duration,
 };
 
 use super::Widget;
 use crate::{
     backend::{Backend, ClearType, MoveDirection, Size},
     error,
-    events::{EventIterator, KeyCode, KeyModifiers},
+    events::{EventIterator, KeyCode, KeyEvent, KeyModifiers},
     layout::Layout,
-    style::Stylize,
+    style::{Styled, Stylize},
+    widgets::Text,
 };
 
+/// Emits a `tracing` event with the given name at the `DEBUG` level. It expands to nothing if
+/// the `tracing` feature is not enabled.
+macro_rules! trace_event {
+    ($name:literal $(, $($fields:tt)*)?) => {{
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            name: $name,
+            target: "requestty",
+            tracing::Level::DEBUG,
+            { $($($fields)*)? }
+        );
+    }};
+}
+
+/// How long to wait for a key before checking for a refresh request again.
+const REFRESH_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 /// The state of a prompt on validation.
 ///
 /// See [`Prompt::validate`]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Validation {
     /// If the prompt is ready to finish.
     Finish,
@@ -24,6 +45,18 @@ pub enum Validation {
     /// Unlike returning an Err, this will not show an error and is a way for the prompt to progress
     /// its internal state machine.
     Continue,
+    /// Same as [`Validation::Continue`], but a message is shown below the prompt.
+    ///
+    /// The message is rendered like an error, but without the cross symbol. It is only shown until
+    /// the prompt is rendered again, which is usually on the next key.
+    ContinueWith(Text<String>),
+    /// The state is valid, but the user must confirm it by submitting again.
+    ///
+    /// The message is shown below the prompt like [`Validation::ContinueWith`], and the prompt
+    /// finishes if the submit key is pressed again right after. Any other key resets this, so the
+    /// prompt is validated again on the next submit. The message should tell the user to submit
+    /// again to confirm.
+    Confirm(Text<String>),
 }
 
 /// What to do after receiving `Esc`
@@ -39,6 +72,30 @@ pub enum OnEsc {
     Ignore,
 }
 
+/// How a prompt was finished.
+///
+/// See [`Input::run_with_outcome`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The user entered an answer.
+    Submitted,
+    /// The user did not enter an answer, and the default was accepted.
+    ///
+    /// See [`Prompt::finishes_with_default`]
+    DefaultAccepted,
+}
+
+/// Where to place the prompt when it is narrower than the terminal.
+///
+/// See [`Input::max_content_width`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    /// Place the prompt at the left edge of the terminal. This is the default behaviour.
+    Left,
+    /// Place the prompt in the middle of the terminal.
+    Center,
+}
+
 /// This trait should be implemented by all 'root' widgets.
 ///
 /// It provides the functionality required only by the main controlling widget. For the trait
@@ -47,6 +104,11 @@ pub trait Prompt: Widget {
     /// The error type returned by validate. It can be any widget and the [render cycle] is guaranteed
     /// to be called only once.
     ///
+    /// It is rendered below the prompt after a red cross, and is given the backend and a layout
+    /// which starts right after the cross. This means that it is not limited to a single line of
+    /// text, and can draw richer feedback, such as a styled diff or a table, as long as its
+    /// [`height`](Widget::height) matches what it renders.
+    ///
     /// [render cycle]: widgets/trait.Widget.html#render-cycle
     type ValidateErr: Widget;
 
@@ -60,6 +122,48 @@ pub trait Prompt: Widget {
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
         Ok(Validation::Finish)
     }
+
+    /// Check whether the prompt state is valid after it was changed by a key. It is only called if
+    /// [`Input::validate_on_change`] is set.
+    ///
+    /// An error is shown below the prompt until the next key, but unlike [`validate`], it does not
+    /// prevent the prompt from being submitted.
+    ///
+    /// [`validate`]: Prompt::validate
+    fn validate_on_change(&mut self) -> Result<(), Self::ValidateErr> {
+        Ok(())
+    }
+
+    /// Whether [`finish`] will return the default because the user did not enter an answer. It is
+    /// only called once validation returns [`Validation::Finish`], right before [`finish`].
+    ///
+    /// This is used to find the [`Outcome`] returned by [`Input::run_with_outcome`].
+    ///
+    /// [`finish`]: Prompt::finish
+    fn finishes_with_default(&self) -> bool {
+        false
+    }
+
+    /// Called with the time left before the prompt is submitted on its own, if a
+    /// [`countdown`](Input::countdown) is set.
+    ///
+    /// It is called before the prompt is first rendered, and then whenever the time left changes
+    /// by a second or reaches zero, right before the prompt is submitted. It is called with `None`
+    /// once the countdown is cancelled by a key. The prompt can use it to show the time left, and
+    /// to decide what to answer once it reaches zero.
+    fn countdown(&mut self, remaining: Option<Duration>) {
+        let _ = remaining;
+    }
+
+    /// Whether the cursor is shown in the current state of the prompt. It is checked every time the
+    /// prompt is rendered, so a prompt can show the cursor only while it is needed, for example
+    /// while text is typed.
+    ///
+    /// The cursor is never shown if [`Input::hide_cursor`] is set.
+    fn shows_cursor(&self) -> bool {
+        true
+    }
+
     /// The value to return from [`Input::run`]. This will only be called once validation returns
     /// [`Validation::Finish`]
     fn finish(self) -> Self::Output;
@@ -76,10 +180,24 @@ pub trait Prompt: Widget {
 pub struct Input<P, B: Backend> {
     prompt: P,
     on_esc: OnEsc,
+    submit_key: KeyEvent,
     backend: TerminalState<B>,
     base_row: u16,
     size: Size,
     render_overflow: bool,
+    validate_on_change: bool,
+    ensure_newline: bool,
+    invalid_hint: Option<String>,
+    interrupt_confirm: Option<String>,
+    submit_on_tab: bool,
+    back_on_back_tab: bool,
+    refresh: Option<Receiver<()>>,
+    /// The time left before the prompt is submitted on its own, if any.
+    countdown: Option<Duration>,
+    /// The minimum time the final state of the prompt is shown for after it is submitted.
+    min_display: Duration,
+    max_content_width: Option<u16>,
+    align: Align,
 }
 
 impl<P, B: Backend> Input<P, B> {
@@ -92,10 +210,22 @@ impl<P, B: Backend> Input<P, B> {
         Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            submit_key: KeyCode::Enter.into(),
             backend: TerminalState::new(backend, false),
             base_row: 0,
             size: Size::default(),
             render_overflow: false,
+            validate_on_change: false,
+            ensure_newline: false,
+            invalid_hint: None,
+            interrupt_confirm: None,
+            submit_on_tab: false,
+            back_on_back_tab: false,
+            refresh: None,
+            countdown: None,
+            min_display: Duration::from_secs(0),
+            max_content_width: None,
+            align: Align::Left,
         }
     }
 
@@ -105,6 +235,29 @@ impl<P, B: Backend> Input<P, B> {
         self
     }
 
+    /// Assume that raw mode and the visibility of the cursor are managed by the caller, such as
+    /// another TUI framework the prompt is embedded in. This won't do anything until it is
+    /// [run](Input::run).
+    ///
+    /// Raw mode is then neither enabled nor disabled, and the cursor is neither hidden nor shown,
+    /// even if [`hide_cursor`] is set. The cursor is still moved to where the prompt needs it,
+    /// unless [`hide_cursor`] is set. Raw mode must be enabled while the prompt runs for the keys
+    /// to be read correctly.
+    ///
+    /// [`hide_cursor`]: Input::hide_cursor
+    pub fn external_terminal_state(mut self) -> Self {
+        self.backend.external = true;
+        self
+    }
+
+    /// Whether to start the prompt on a new line if the cursor is not at the start of a line.
+    /// Otherwise, the prompt overwrites the output on the line which did not end with a newline.
+    /// This won't do anything until it is [run](Input::run).
+    pub fn ensure_newline(mut self, ensure_newline: bool) -> Self {
+        self.ensure_newline = ensure_newline;
+        self
+    }
+
     /// What to do after receiving a `Esc`.
     ///
     /// For [`OnEsc::Terminate`] - an [`Error::Aborted`](error::ErrorKind::Aborted) will be returned.
@@ -116,31 +269,195 @@ impl<P, B: Backend> Input<P, B> {
         self.on_esc = on_esc;
         self
     }
+
+    /// The key which submits the prompt, calling [`validate`](Prompt::validate). Defaults to
+    /// `Enter`.
+    ///
+    /// The key matches if its code is the same and at least its modifiers are pressed, so the
+    /// default matches `Enter` regardless of the modifiers. `Enter` is passed to the prompt to
+    /// handle like any other key if it is not the submit key. `Ctrl+C`, `Esc` (if [`on_esc`] is
+    /// not [`OnEsc::Ignore`]) and `Null` are always handled first and cannot be used to submit.
+    ///
+    /// This is useful for prompts where `Enter` is used to insert a new line.
+    ///
+    /// [`on_esc`]: Input::on_esc
+    pub fn submit_key(mut self, submit_key: KeyEvent) -> Self {
+        self.submit_key = submit_key;
+        self
+    }
+
+    /// Call [`Prompt::validate_on_change`] after every key which changes the prompt, and show the
+    /// error (if any) below the prompt. The error is cleared on the next key.
+    ///
+    /// This does not change what happens when the prompt is submitted.
+    pub fn validate_on_change(mut self, validate_on_change: bool) -> Self {
+        self.validate_on_change = validate_on_change;
+        self
+    }
+
+    /// A hint to show below the prompt when the user types a character which the prompt does not
+    /// handle, such as a letter in a prompt for a number. The hint is cleared on the next key
+    /// which the prompt handles.
+    ///
+    /// If it is `None`, which is the default, nothing is shown.
+    pub fn on_invalid_hint(mut self, invalid_hint: Option<String>) -> Self {
+        self.invalid_hint = invalid_hint;
+        self
+    }
+
+    /// A question to ask below the prompt when `Ctrl+C` is pressed, instead of immediately
+    /// returning an [`Error::Interrupted`](error::ErrorKind::Interrupted). `(y/N)` is shown after
+    /// the question.
+    ///
+    /// Pressing `y` or `Ctrl+C` again returns the error. Any other key dismisses the question,
+    /// and the prompt continues where it was.
+    ///
+    /// If it is `None`, which is the default, `Ctrl+C` returns the error immediately.
+    pub fn confirm_on_interrupt(mut self, interrupt_confirm: Option<String>) -> Self {
+        self.interrupt_confirm = interrupt_confirm;
+        self
+    }
+
+    /// Whether `Tab` should also submit the prompt. It is only used as a submit key if the prompt
+    /// doesn't [handle](Widget::handle_key) it itself, for example to auto complete.
+    ///
+    /// It is `false` by default.
+    pub fn submit_on_tab(mut self, submit_on_tab: bool) -> Self {
+        self.submit_on_tab = submit_on_tab;
+        self
+    }
+
+    /// Whether `Shift+Tab` should clear the prompt and return an
+    /// [`Error::Back`](error::ErrorKind::Back), so that the previous question can be asked again.
+    ///
+    /// It is `false` by default.
+    pub fn back_on_back_tab(mut self, back_on_back_tab: bool) -> Self {
+        self.back_on_back_tab = back_on_back_tab;
+        self
+    }
+
+    /// Set the title of the terminal window while the prompt is shown.
+    ///
+    /// The previous title is [saved](Backend::save_title) before it is changed, and
+    /// [restored](Backend::restore_title) once the prompt finishes. Terminals which cannot restore
+    /// the title keep the one given here.
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.backend.title = Some(title.into());
+        self
+    }
+
+    /// Re-render the prompt whenever `()` is sent on the channel, without waiting for a key. This
+    /// is useful for prompts which show state updated from outside, such as a spinner or a clock.
+    ///
+    /// While running, the events are [polled](EventIterator::poll_event) with a short timeout so
+    /// that refresh requests can be checked for in between. Multiple requests received together
+    /// only cause a single re-render. Re-rendering clears any error or message shown below the
+    /// prompt. Once all the senders are dropped, the events are read without a timeout again.
+    ///
+    /// Only events which support polling, such as `CrosstermEvents`, can be interrupted by a
+    /// refresh request. `TermionEvents` and [`PipedEvents`] wait for the next key instead, so with
+    /// them the requests received in the meantime only cause a re-render after the next key.
+    ///
+    /// [`PipedEvents`]: crate::events::PipedEvents
+    pub fn with_refresh(mut self, refresh: Receiver<()>) -> Self {
+        self.refresh = Some(refresh);
+        self
+    }
+
+    /// Submit the prompt on its own, as if the submit key was pressed, once `duration` has passed
+    /// without a key being pressed. Any key cancels the countdown, and is then handled as usual.
+    ///
+    /// The prompt is told the time left through [`Prompt::countdown`], and re-rendered every
+    /// second. The time is measured by [polling](EventIterator::poll_event) the events, so the
+    /// countdown only runs with events which support it, such as `CrosstermEvents`.
+    /// `TermionEvents` and [`PipedEvents`] wait for the next key instead, so the countdown is never
+    /// started with them.
+    ///
+    /// [`PipedEvents`]: crate::events::PipedEvents
+    pub fn countdown(mut self, duration: Duration) -> Self {
+        self.countdown = Some(duration);
+        self
+    }
+
+    /// The minimum time the prompt is shown for once it is submitted, before it is cleared and
+    /// [`run`](Input::run) returns. This keeps the answer on the screen in fast, scripted flows,
+    /// such as demos.
+    ///
+    /// The time is waited for with [`Backend::sleep`]. It is zero by default, which does not wait
+    /// at all.
+    pub fn min_display(mut self, min_display: Duration) -> Self {
+        self.min_display = min_display;
+        self
+    }
+
+    /// The maximum width the prompt is rendered in. If the terminal is wider than this, the prompt
+    /// is [aligned](Input::align) within it.
+    ///
+    /// Widgets which ignore [`Layout::offset_x`], such as a [`StringInput`], still wrap at the
+    /// edge of the terminal.
+    ///
+    /// [`StringInput`]: crate::widgets::StringInput
+    pub fn max_content_width(mut self, max_content_width: u16) -> Self {
+        self.max_content_width = Some(max_content_width);
+        self
+    }
+
+    /// Where to place the prompt if the terminal is wider than the [`max_content_width`]. Defaults
+    /// to [`Align::Left`].
+    ///
+    /// [`max_content_width`]: Input::max_content_width
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    fn is_submit_key(&self, key: KeyEvent) -> bool {
+        key.code == self.submit_key.code && key.modifiers.contains(self.submit_key.modifiers)
+    }
 }
 
 impl<P: Prompt, B: Backend> Input<P, B> {
     fn layout(&self) -> Layout {
-        Layout::new(0, self.size).with_offset(0, self.base_row)
+        let width = match self.max_content_width {
+            Some(max_content_width) => self.size.width.min(max_content_width.max(1)),
+            None => self.size.width,
+        };
+
+        let offset_x = match self.align {
+            Align::Left => 0,
+            Align::Center => (self.size.width - width) / 2,
+        };
+
+        let mut layout = Layout::new(0, self.size).with_offset(offset_x, self.base_row);
+        layout.width = offset_x + width;
+        layout
     }
 
-    fn update_size(&mut self) -> io::Result<()> {
-        self.size = self.backend.size()?;
-        if self.size.area() == 0 {
-            Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!(
-                    "Invalid terminal {:?}. Both width and height must be larger than 0",
-                    self.size
-                ),
-            ))
-        } else {
-            Ok(())
+    /// Moves the cursor to the start of the prompt after it has been [cleared](Input::clear).
+    fn goto_start(&mut self) -> io::Result<()> {
+        let offset_x = self.layout().offset_x;
+        if offset_x > 0 {
+            self.backend.move_cursor(MoveDirection::Column(offset_x))?;
         }
+        Ok(())
+    }
+
+    fn update_size(&mut self) -> io::Result<()> {
+        self.size = crate::backend::checked_size(&*self.backend)?;
+        Ok(())
     }
 
     fn init(&mut self) -> io::Result<()> {
         self.backend.init()?;
-        self.base_row = self.backend.get_cursor_pos()?.1;
+        let (x, y) = self.backend.get_cursor_pos()?;
+        self.base_row = y;
+
+        if self.ensure_newline && x != 0 {
+            // The carriage return is required since raw mode is enabled
+            self.backend.write_all(b"\r\n")?;
+            self.base_row = self.backend.get_cursor_pos()?.1;
+        }
+
         self.render()
     }
 
@@ -160,10 +477,21 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        if !self.backend.hide_cursor {
+        if self.backend.hide_cursor {
+            return Ok(());
+        }
+
+        if !self.prompt.shows_cursor() {
+            if !self.backend.external && !self.backend.cursor_hidden {
+                self.backend.cursor_hidden = true;
+                self.backend.hide_cursor()?;
+            }
+        } else {
             let (x, y) = self.prompt.cursor_pos(self.layout());
 
-            if self.render_overflow && y >= self.size.height - 1 {
+            if self.backend.external {
+                // The visibility of the cursor is managed by the caller
+            } else if self.render_overflow && y >= self.size.height - 1 {
                 // If the height of the prompt exceeds the height of the terminal a cut-off message
                 // is displayed at the bottom. If the cursor is positioned on this cut-off, then we
                 // hide it.
@@ -179,7 +507,19 @@ impl<P: Prompt, B: Backend> Input<P, B> {
 
             self.backend.move_cursor_to(x, y)?;
         }
-        self.backend.flush()
+        Ok(())
+    }
+
+    /// Runs `f` within a frame of the backend. The frame is ended even if `f` fails, so that any
+    /// output after it, like clearing the prompt on exit, is not held back.
+    fn in_frame<F>(&mut self, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Self) -> io::Result<()>,
+    {
+        self.backend.begin_frame()?;
+        let res = f(self);
+        let end = self.backend.end_frame();
+        res.and(end)
     }
 
     fn render_cutoff_msg(&mut self) -> io::Result<()> {
@@ -194,10 +534,15 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn render(&mut self) -> io::Result<()> {
+        self.in_frame(Self::render_frame)
+    }
+
+    fn render_frame(&mut self) -> io::Result<()> {
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
         self.base_row = self.adjust_scrollback(height)?;
         self.clear()?;
+        self.goto_start()?;
 
         self.prompt.render(&mut self.layout(), &mut *self.backend)?;
         self.render_overflow = height > self.size.height;
@@ -220,16 +565,32 @@ impl<P: Prompt, B: Backend> Input<P, B> {
         self.backend.move_cursor_to(0, self.base_row + height)
     }
 
-    fn print_error(&mut self, mut e: P::ValidateErr) -> io::Result<()> {
+    fn print_error(&mut self, e: P::ValidateErr) -> io::Result<()> {
+        self.print_below(e, Some(crate::symbols::current().cross.red()))
+    }
+
+    /// Renders the prompt followed by the given widget. If a symbol is given, it is written before
+    /// the widget, otherwise the space is left blank.
+    fn print_below<W: Widget>(&mut self, e: W, symbol: Option<Styled<char>>) -> io::Result<()> {
+        self.in_frame(|input| input.print_below_frame(e, symbol))
+    }
+
+    fn print_below_frame<W: Widget>(
+        &mut self,
+        mut e: W,
+        symbol: Option<Styled<char>>,
+    ) -> io::Result<()> {
         self.update_size()?;
         let height = self.prompt.height(&mut self.layout());
         self.base_row = self.adjust_scrollback(height + 1)?;
         self.clear()?;
+        self.goto_start()?;
         self.prompt.render(&mut self.layout(), &mut *self.backend)?;
 
         self.goto_last_line(height)?;
 
-        let mut layout = Layout::new(2, self.size).with_offset(0, self.base_row + height);
+        let mut layout = self.layout().with_line_offset(2);
+        layout.offset_y += height;
         let err_height = e.height(&mut layout.clone());
         self.base_row = self.adjust_scrollback(height + err_height)?;
 
@@ -242,9 +603,15 @@ impl<P: Prompt, B: Backend> Input<P, B> {
                 .move_cursor_to(0, self.size.height - err_height)?;
         }
 
-        self.backend
-            .write_styled(&crate::symbols::current().cross.red())?;
-        self.backend.write_all(b" ")?;
+        self.goto_start()?;
+
+        match symbol {
+            Some(symbol) => {
+                self.backend.write_styled(&symbol)?;
+                self.backend.write_all(b" ")?;
+            }
+            None => self.backend.write_all(b"  ")?,
+        }
 
         e.render(&mut layout, &mut *self.backend)?;
 
@@ -258,46 +625,222 @@ impl<P: Prompt, B: Backend> Input<P, B> {
         self.backend.reset()
     }
 
+    /// Gets the next event, re-rendering the prompt if a refresh is requested while waiting for it.
+    /// It returns `None` if the poll timed out.
+    fn next_event<E: EventIterator>(&mut self, events: &mut E) -> io::Result<Option<KeyEvent>> {
+        let mut should_render = false;
+
+        if let Some(ref refresh) = self.refresh {
+            loop {
+                match refresh.try_recv() {
+                    Ok(()) => should_render = true,
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        self.refresh = None;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if should_render {
+            trace_event!("prompt.refresh");
+            self.render()?;
+        }
+
+        let timeout = match (self.countdown, self.refresh.is_some()) {
+            (Some(remaining), refresh) => {
+                // Wait until the number of whole seconds left changes
+                let mut tick = remaining - Duration::from_secs(remaining.as_secs());
+                if tick == Duration::from_secs(0) {
+                    tick = remaining.min(Duration::from_secs(1));
+                }
+
+                if refresh {
+                    tick.min(REFRESH_POLL_INTERVAL)
+                } else {
+                    tick
+                }
+            }
+            (None, true) => REFRESH_POLL_INTERVAL,
+            (None, false) => return events.next_event().map(Some),
+        };
+
+        let e = events.poll_event(timeout)?;
+
+        if let Some(remaining) = self.countdown {
+            if e.is_some() {
+                trace_event!("prompt.countdown", result = "cancelled");
+                self.countdown = None;
+                self.prompt.countdown(None);
+                self.render()?;
+            } else {
+                // The poll only times out once the whole timeout has passed
+                let remaining = remaining.saturating_sub(timeout);
+                self.countdown = Some(remaining);
+                self.prompt.countdown(Some(remaining));
+
+                // The prompt is about to be submitted, so it is not rendered again
+                if remaining > Duration::from_secs(0) && remaining.subsec_nanos() == 0 {
+                    self.render()?;
+                }
+            }
+        }
+
+        Ok(e)
+    }
+
+    /// Asks the [interrupt confirmation](Input::confirm_on_interrupt), if any, and returns whether
+    /// the prompt should be interrupted.
+    fn confirm_interrupt<E: EventIterator>(&mut self, events: &mut E) -> io::Result<bool> {
+        let question = match self.interrupt_confirm {
+            Some(ref question) => format!("{} (y/N)", question),
+            None => return Ok(true),
+        };
+
+        trace_event!("prompt.confirm_interrupt");
+        self.print_below(Text::new(question), Some('?'.yellow()))?;
+
+        let e = events.next_event()?;
+        let interrupt = match e.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => true,
+            KeyCode::Char('c') => e.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+
+        if !interrupt {
+            self.render()?;
+        }
+
+        Ok(interrupt)
+    }
+
     /// Display the prompt and process events until the user presses `Enter`.
     ///
-    /// After the user presses `Enter`, [`validate`](Prompt::validate) will be called.
-    pub fn run<E>(mut self, events: &mut E) -> error::Result<Option<P::Output>>
+    /// After the user presses `Enter`, [`validate`](Prompt::validate) will be called. A different
+    /// key can be used instead of `Enter` with [`submit_key`](Input::submit_key).
+    pub fn run<E>(self, events: &mut E) -> error::Result<Option<P::Output>>
+    where
+        E: EventIterator,
+    {
+        self.run_with_outcome(events)
+            .map(|res| res.map(|(output, _)| output))
+    }
+
+    /// Same as [`run`](Input::run), but also returns whether the user entered an answer or
+    /// accepted the default.
+    ///
+    /// See [`Prompt::finishes_with_default`]
+    pub fn run_with_outcome<E>(
+        mut self,
+        events: &mut E,
+    ) -> error::Result<Option<(P::Output, Outcome)>>
     where
         E: EventIterator,
     {
+        if let Some(remaining) = self.countdown {
+            self.prompt.countdown(Some(remaining));
+        }
+
         self.init()?;
+        trace_event!("prompt.start");
+
+        // Whether the last submit returned `Validation::Confirm`, so that submitting again
+        // finishes the prompt
+        let mut confirm_armed = false;
 
         loop {
-            let e = events.next_event()?;
+            let e = match self.next_event(events)? {
+                Some(e) => e,
+                None if self.countdown == Some(Duration::from_secs(0)) => {
+                    trace_event!("prompt.countdown", result = "finished");
+                    self.countdown = None;
+                    self.submit_key
+                }
+                None => continue,
+            };
+            // The key itself is not logged, since it could be part of a password
+            trace_event!("prompt.key");
+
+            let was_armed = std::mem::take(&mut confirm_armed);
+
+            // Tab submits the prompt, unless the prompt uses it itself
+            let tab_submits = self.submit_on_tab && e.code == KeyCode::Tab;
+            let tab_handled = tab_submits && self.prompt.handle_key(e);
 
             let key_handled = match e.code {
                 KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if !self.confirm_interrupt(events)? {
+                        continue;
+                    }
+
+                    trace_event!("prompt.error", error = "interrupted");
                     self.exit()?;
                     return Err(error::ErrorKind::Interrupted);
                 }
                 KeyCode::Null => {
+                    trace_event!("prompt.error", error = "eof");
                     self.exit()?;
                     return Err(error::ErrorKind::Eof);
                 }
                 KeyCode::Esc if self.on_esc == OnEsc::Terminate => {
+                    trace_event!("prompt.error", error = "aborted");
                     self.exit()?;
                     return Err(error::ErrorKind::Aborted);
                 }
                 KeyCode::Esc if self.on_esc == OnEsc::SkipQuestion => {
+                    trace_event!("prompt.skip");
                     self.clear()?;
                     self.backend.reset()?;
 
                     return Ok(None);
                 }
-                KeyCode::Enter => match self.prompt.validate() {
-                    Ok(Validation::Finish) => {
+                KeyCode::BackTab if self.back_on_back_tab => {
+                    trace_event!("prompt.back");
+                    self.clear()?;
+                    self.backend.reset()?;
+
+                    return Err(error::ErrorKind::Back);
+                }
+                _ if tab_handled => true,
+                _ if tab_submits || self.is_submit_key(e) => match self.prompt.validate() {
+                    Ok(Validation::Confirm(message)) if !was_armed => {
+                        trace_event!("prompt.validate", result = "confirm");
+                        confirm_armed = true;
+                        self.print_below(message, None)?;
+
+                        continue;
+                    }
+                    Ok(Validation::Finish) | Ok(Validation::Confirm(_)) => {
+                        trace_event!("prompt.validate", result = "finish");
+                        if self.min_display > Duration::from_secs(0) {
+                            self.backend.sleep(self.min_display)?;
+                        }
+
                         self.clear()?;
                         self.backend.reset()?;
 
-                        return Ok(Some(self.prompt.finish()));
+                        let outcome = if self.prompt.finishes_with_default() {
+                            Outcome::DefaultAccepted
+                        } else {
+                            Outcome::Submitted
+                        };
+
+                        trace_event!("prompt.finish", outcome = ?outcome);
+                        return Ok(Some((self.prompt.finish(), outcome)));
+                    }
+                    Ok(Validation::Continue) => {
+                        trace_event!("prompt.validate", result = "continue");
+                        true
+                    }
+                    Ok(Validation::ContinueWith(message)) => {
+                        trace_event!("prompt.validate", result = "continue_with");
+                        self.print_below(message, None)?;
+
+                        continue;
                     }
-                    Ok(Validation::Continue) => true,
                     Err(e) => {
+                        trace_event!("prompt.validate", result = "error");
                         self.print_error(e)?;
 
                         continue;
@@ -307,7 +850,23 @@ impl<P: Prompt, B: Backend> Input<P, B> {
             };
 
             if key_handled {
+                if self.validate_on_change {
+                    if let Err(e) = self.prompt.validate_on_change() {
+                        trace_event!("prompt.validate_on_change", result = "error");
+                        self.print_error(e)?;
+
+                        continue;
+                    }
+                }
+
                 self.render()?;
+            } else if let (Some(hint), KeyCode::Char(_)) = (&self.invalid_hint, e.code) {
+                // Only characters typed as text are invalid, other keys are just ignored
+                if (e.modifiers - KeyModifiers::SHIFT).is_empty() {
+                    trace_event!("prompt.invalid_key");
+                    let hint = Text::new(hint.clone());
+                    self.print_below(hint, None)?;
+                }
             }
         }
     }
@@ -318,7 +877,12 @@ struct TerminalState<B: Backend> {
     backend: B,
     hide_cursor: bool,
     cursor_hidden: bool,
+    /// The title of the terminal window while the prompt is shown, if any.
+    title: Option<String>,
+    title_set: bool,
     enabled: bool,
+    /// Whether raw mode and the visibility of the cursor are managed outside the `Input`.
+    external: bool,
 }
 
 impl<B: Backend> TerminalState<B> {
@@ -328,16 +892,30 @@ impl<B: Backend> TerminalState<B> {
             enabled: false,
             hide_cursor,
             cursor_hidden: false,
+            title: None,
+            title_set: false,
+            external: false,
         }
     }
 
     fn init(&mut self) -> io::Result<()> {
         self.enabled = true;
-        if self.hide_cursor && !self.cursor_hidden {
+        if self.hide_cursor && !self.cursor_hidden && !self.external {
             self.backend.hide_cursor()?;
             self.cursor_hidden = true;
         }
-        self.backend.enable_raw_mode()
+        if let Some(ref title) = self.title {
+            if !self.title_set {
+                self.backend.save_title()?;
+                self.backend.set_title(title)?;
+                self.title_set = true;
+            }
+        }
+        if self.external {
+            Ok(())
+        } else {
+            self.backend.enable_raw_mode()
+        }
     }
 
     fn reset(&mut self) -> io::Result<()> {
@@ -346,7 +924,15 @@ impl<B: Backend> TerminalState<B> {
             self.backend.show_cursor()?;
             self.cursor_hidden = false;
         }
-        self.backend.disable_raw_mode()
+        if self.title_set {
+            self.backend.restore_title()?;
+            self.title_set = false;
+        }
+        if self.external {
+            Ok(())
+        } else {
+            self.backend.disable_raw_mode()
+        }
     }
 }
 
@@ -375,7 +961,11 @@ impl<B: Backend> DerefMut for TerminalState<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{backend::TestBackend, events::TestEvents};
+    use crate::{
+        backend::TestBackend,
+        events::TestEvents,
+        style::{Attributes, Color},
+    };
 
     #[derive(Debug, Default, Clone, Copy)]
     struct TestPrompt {
@@ -426,38 +1016,262 @@ mod tests {
         crate::assert_backend_snapshot!(*backend);
     }
 
+    /// Records the calls which change raw mode and the visibility of the cursor
+    struct TerminalCallsBackend {
+        backend: TestBackend,
+        calls: Vec<&'static str>,
+    }
+
+    impl io::Write for TerminalCallsBackend {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.backend.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.backend.flush()
+        }
+    }
+
+    impl Backend for TerminalCallsBackend {
+        fn enable_raw_mode(&mut self) -> io::Result<()> {
+            self.calls.push("enable_raw_mode");
+            self.backend.enable_raw_mode()
+        }
+        fn disable_raw_mode(&mut self) -> io::Result<()> {
+            self.calls.push("disable_raw_mode");
+            self.backend.disable_raw_mode()
+        }
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.calls.push("hide_cursor");
+            self.backend.hide_cursor()
+        }
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.calls.push("show_cursor");
+            self.backend.show_cursor()
+        }
+        fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+            self.backend.get_cursor_pos()
+        }
+        fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+            self.backend.move_cursor_to(x, y)
+        }
+        fn scroll(&mut self, dist: i16) -> io::Result<()> {
+            self.backend.scroll(dist)
+        }
+        fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+            self.backend.set_attributes(attributes)
+        }
+        fn set_fg(&mut self, color: Color) -> io::Result<()> {
+            self.backend.set_fg(color)
+        }
+        fn set_bg(&mut self, color: Color) -> io::Result<()> {
+            self.backend.set_bg(color)
+        }
+        fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+            self.backend.clear(clear_type)
+        }
+        fn size(&self) -> io::Result<Size> {
+            self.backend.size()
+        }
+    }
+
     #[test]
-    fn test_adjust_scrollback() {
-        let prompt = TestPrompt::default();
-        let size = (100, 20).into();
+    fn test_external_terminal_state() {
+        for &external in &[false, true] {
+            let mut backend = TerminalCallsBackend {
+                backend: TestBackend::new((100, 20).into()),
+                calls: Vec::new(),
+            };
 
-        let mut backend = TestBackend::new(size);
-        backend.move_cursor_to(0, 14).unwrap();
+            let mut input = Input::new(TestPrompt { height: 1 }, &mut backend).hide_cursor();
+            if external {
+                input = input.external_terminal_state();
+            }
+            input
+                .run(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+                .unwrap();
+
+            // Dropped without being reset, such as after a panic
+            let mut input = Input::new(TestPrompt { height: 1 }, &mut backend).hide_cursor();
+            if external {
+                input = input.external_terminal_state();
+            }
+            input.init().unwrap();
+            drop(input);
+
+            if external {
+                assert!(backend.calls.is_empty(), "{:?}", backend.calls);
+            } else {
+                assert_eq!(
+                    backend.calls,
+                    [
+                        "hide_cursor",
+                        "enable_raw_mode",
+                        "show_cursor",
+                        "disable_raw_mode"
+                    ]
+                    .repeat(2)
+                );
+            }
+        }
+    }
 
-        assert_eq!(
-            Input {
-                prompt,
-                on_esc: OnEsc::Ignore,
-                backend: TerminalState::new(&mut backend, false),
-                base_row: 14,
-                size,
-                render_overflow: false,
+    #[test]
+    fn test_shows_cursor() {
+        /// Shows the cursor after every other space
+        struct CursorPrompt {
+            shown: bool,
+        }
+
+        impl Widget for CursorPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Ok(())
             }
-            .adjust_scrollback(3)
-            .unwrap(),
-            14
-        );
 
-        crate::assert_backend_snapshot!(backend);
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
 
-        assert_eq!(
-            Input {
-                prompt,
-                on_esc: OnEsc::Ignore,
-                backend: TerminalState::new(&mut backend, false),
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, key: crate::events::KeyEvent) -> bool {
+                self.shown ^= key.code == KeyCode::Char(' ');
+                true
+            }
+        }
+
+        impl Prompt for CursorPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = ();
+
+            fn shows_cursor(&self) -> bool {
+                self.shown
+            }
+
+            fn finish(self) -> Self::Output {}
+        }
+
+        let mut backend = TerminalCallsBackend {
+            backend: TestBackend::new((100, 20).into()),
+            calls: Vec::new(),
+        };
+
+        Input::new(CursorPrompt { shown: false }, &mut backend)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Char(' ').into(),
+                KeyCode::Char(' ').into(),
+                KeyCode::Enter.into(),
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            backend.calls,
+            [
+                "enable_raw_mode",
+                "hide_cursor",
+                "show_cursor",
+                "hide_cursor",
+                "show_cursor",
+                "disable_raw_mode"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        let mut backend = TestBackend::new((100, 20).into());
+        backend.set_title("shell").unwrap();
+
+        let mut input = Input::new(TestPrompt::default(), &mut backend).title("Setup — Step 3");
+
+        input.backend.init().unwrap();
+        assert_eq!(input.backend.title(), Some("Setup — Step 3"));
+        input.backend.reset().unwrap();
+        drop(input);
+
+        assert_eq!(backend.title(), Some("shell"));
+    }
+
+    #[test]
+    fn test_ensure_newline() {
+        let size = (100, 20).into();
+
+        for &ensure_newline in &[false, true] {
+            let mut backend = TestBackend::new(size);
+            backend.move_cursor_to(0, 3).unwrap();
+            io::Write::write_all(&mut backend, b"Hello").unwrap();
+
+            let mut input =
+                Input::new(TestPrompt { height: 1 }, &mut backend).ensure_newline(ensure_newline);
+            input.init().unwrap();
+
+            let expected_row = if ensure_newline { 4 } else { 3 };
+            assert_eq!(input.base_row, expected_row);
+            drop(input);
+
+            crate::assert_backend_snapshot!(format!("ensure_newline-{}", ensure_newline), backend);
+        }
+    }
+
+    #[test]
+    fn test_adjust_scrollback() {
+        let prompt = TestPrompt::default();
+        let size = (100, 20).into();
+
+        let mut backend = TestBackend::new(size);
+        backend.move_cursor_to(0, 14).unwrap();
+
+        assert_eq!(
+            Input {
+                prompt,
+                on_esc: OnEsc::Ignore,
+                submit_key: KeyCode::Enter.into(),
+                backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                validate_on_change: false,
+                ensure_newline: false,
+                invalid_hint: None,
+                interrupt_confirm: None,
+                submit_on_tab: false,
+                back_on_back_tab: false,
+                refresh: None,
+                countdown: None,
+                min_display: Duration::from_secs(0),
+                max_content_width: None,
+                align: Align::Left,
+            }
+            .adjust_scrollback(3)
+            .unwrap(),
+            14
+        );
+
+        crate::assert_backend_snapshot!(backend);
+
+        assert_eq!(
+            Input {
+                prompt,
+                on_esc: OnEsc::Ignore,
+                submit_key: KeyCode::Enter.into(),
+                backend: TerminalState::new(&mut backend, false),
+                base_row: 14,
+                size,
+                render_overflow: false,
+                validate_on_change: false,
+                ensure_newline: false,
+                invalid_hint: None,
+                interrupt_confirm: None,
+                submit_on_tab: false,
+                back_on_back_tab: false,
+                refresh: None,
+                countdown: None,
+                min_display: Duration::from_secs(0),
+                max_content_width: None,
+                align: Align::Left,
             }
             .adjust_scrollback(6)
             .unwrap(),
@@ -469,10 +1283,22 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                submit_key: KeyCode::Enter.into(),
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
                 size,
                 render_overflow: false,
+                validate_on_change: false,
+                ensure_newline: false,
+                invalid_hint: None,
+                interrupt_confirm: None,
+                submit_on_tab: false,
+                back_on_back_tab: false,
+                refresh: None,
+                countdown: None,
+                min_display: Duration::from_secs(0),
+                max_content_width: None,
+                align: Align::Left,
             }
             .adjust_scrollback(10)
             .unwrap(),
@@ -491,10 +1317,22 @@ mod tests {
         assert!(Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            submit_key: KeyCode::Enter.into(),
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 5,
             render_overflow: false,
+            validate_on_change: false,
+            ensure_newline: false,
+            invalid_hint: None,
+            interrupt_confirm: None,
+            submit_on_tab: false,
+            back_on_back_tab: false,
+            refresh: None,
+            countdown: None,
+            min_display: Duration::from_secs(0),
+            max_content_width: None,
+            align: Align::Left,
         }
         .render()
         .is_ok());
@@ -511,10 +1349,22 @@ mod tests {
         let mut input = Input {
             prompt: TestPrompt::default(),
             on_esc: OnEsc::Ignore,
+            submit_key: KeyCode::Enter.into(),
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 15,
             render_overflow: false,
+            validate_on_change: false,
+            ensure_newline: false,
+            invalid_hint: None,
+            interrupt_confirm: None,
+            submit_on_tab: false,
+            back_on_back_tab: false,
+            refresh: None,
+            countdown: None,
+            min_display: Duration::from_secs(0),
+            max_content_width: None,
+            align: Align::Left,
         };
 
         assert!(input.goto_last_line(9).is_ok());
@@ -533,10 +1383,22 @@ mod tests {
         assert!(Input {
             prompt: TestPrompt { height: 5 },
             on_esc: OnEsc::Ignore,
+            submit_key: KeyCode::Enter.into(),
             backend: TerminalState::new(&mut backend, true),
             base_row: 0,
             size,
             render_overflow: false,
+            validate_on_change: false,
+            ensure_newline: false,
+            invalid_hint: None,
+            interrupt_confirm: None,
+            submit_on_tab: false,
+            back_on_back_tab: false,
+            refresh: None,
+            countdown: None,
+            min_display: Duration::from_secs(0),
+            max_content_width: None,
+            align: Align::Left,
         }
         .print_error(error)
         .is_ok());
@@ -544,6 +1406,195 @@ mod tests {
         crate::assert_backend_snapshot!(backend);
     }
 
+    #[test]
+    fn test_print_error_widget() {
+        /// A validation error which draws a styled line for each side of a diff
+        #[derive(Debug)]
+        struct DiffError;
+
+        impl Widget for DiffError {
+            fn render<B: Backend>(
+                &mut self,
+                layout: &mut Layout,
+                backend: &mut B,
+            ) -> io::Result<()> {
+                backend.write_styled(&"- expected".red())?;
+                backend.move_cursor_to(layout.offset_x + 2, layout.offset_y + 1)?;
+                backend.write_styled(&"+ found".green())?;
+
+                layout.offset_y += 2;
+                layout.line_offset = 0;
+                backend.move_cursor_to(layout.offset_x, layout.offset_y)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 2;
+                layout.line_offset = 0;
+                2
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        #[derive(Debug)]
+        struct DiffPrompt;
+
+        impl Widget for DiffPrompt {
+            fn render<B: Backend>(
+                &mut self,
+                layout: &mut Layout,
+                backend: &mut B,
+            ) -> io::Result<()> {
+                TestPrompt { height: 1 }.render(layout, backend)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                TestPrompt { height: 1 }.height(layout)
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                TestPrompt { height: 1 }.cursor_pos(layout)
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for DiffPrompt {
+            type ValidateErr = DiffError;
+
+            type Output = ();
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                Err(DiffError)
+            }
+
+            fn finish(self) -> Self::Output {}
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+
+        assert!(Input::new(DiffPrompt, &mut backend)
+            .print_error(DiffError)
+            .is_ok());
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_continue_with_message() {
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+
+        let mut input = Input {
+            prompt: TestPrompt { height: 5 },
+            on_esc: OnEsc::Ignore,
+            submit_key: KeyCode::Enter.into(),
+            backend: TerminalState::new(&mut backend, true),
+            base_row: 0,
+            size,
+            render_overflow: false,
+            validate_on_change: false,
+            ensure_newline: false,
+            invalid_hint: None,
+            interrupt_confirm: None,
+            submit_on_tab: false,
+            back_on_back_tab: false,
+            refresh: None,
+            countdown: None,
+            min_display: Duration::from_secs(0),
+            max_content_width: None,
+            align: Align::Left,
+        };
+
+        assert!(input.print_below(Text::new("message text"), None).is_ok());
+        crate::assert_backend_snapshot!(*input.backend);
+
+        // The next key which is handled re-renders the prompt, clearing the message
+        assert!(input.render().is_ok());
+        crate::assert_backend_snapshot!(*input.backend);
+    }
+
+    #[test]
+    fn test_validation_confirm() {
+        /// Always asks for a confirmation, and records how often it was validated
+        #[derive(Debug, Default)]
+        struct WarnPrompt {
+            validations: usize,
+            typed: String,
+        }
+
+        impl Widget for WarnPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        self.typed.push(c);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        }
+
+        impl Prompt for WarnPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = (usize, String);
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                self.validations += 1;
+                Ok(Validation::Confirm(Text::new(
+                    "Are you sure? Press enter again to confirm".into(),
+                )))
+            }
+
+            fn finish(self) -> Self::Output {
+                (self.validations, self.typed)
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+
+        // the first enter warns, and the second one finishes
+        let res = Input::new(WarnPrompt::default(), &mut backend)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Enter.into(),
+                KeyCode::Enter.into(),
+            ]))
+            .unwrap();
+        assert_eq!(res, Some((2, String::new())));
+
+        // typing after the warning means it has to be confirmed again
+        let res = Input::new(WarnPrompt::default(), &mut backend)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Enter.into(),
+                KeyCode::Char('a').into(),
+                KeyCode::Enter.into(),
+                KeyCode::Enter.into(),
+            ]))
+            .unwrap();
+        assert_eq!(res, Some((3, "a".into())));
+    }
+
     #[test]
     fn test_zero_size() {
         let mut backend = TestBackend::new((20, 0).into());
@@ -578,4 +1629,453 @@ mod tests {
             "Invalid terminal Size { width: 0, height: 20 }. Both width and height must be larger than 0"
         );
     }
+
+    #[test]
+    fn test_submit_key() {
+        /// Records the keys it is given
+        #[derive(Debug, Default)]
+        struct KeysPrompt(Vec<KeyEvent>);
+
+        impl Widget for KeysPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                self.0.push(key);
+                true
+            }
+        }
+
+        impl Prompt for KeysPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = Vec<KeyEvent>;
+
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        let mut backend = TestBackend::new((100, 20).into());
+
+        let keys = Input::new(KeysPrompt::default(), &mut backend)
+            .submit_key(ctrl_d)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Enter.into(),
+                KeyCode::Char('d').into(),
+                ctrl_d,
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            keys,
+            Some(vec![KeyCode::Enter.into(), KeyCode::Char('d').into()])
+        );
+
+        let res = Input::new(KeysPrompt::default(), &mut backend)
+            .submit_key(ctrl_d)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Enter.into(),
+                KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            ]));
+
+        assert!(matches!(res, Err(crate::ErrorKind::Interrupted)));
+    }
+
+    #[test]
+    fn test_refresh() {
+        use std::sync::mpsc::{self, Sender};
+
+        /// Counts the number of times it is rendered
+        #[derive(Debug, Default)]
+        struct RendersPrompt(usize);
+
+        impl Widget for RendersPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                self.0 += 1;
+                Ok(())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for RendersPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = usize;
+
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        /// Requests a refresh and times out before every key
+        struct RefreshEvents {
+            refresh: Sender<()>,
+            timed_out: bool,
+            events: TestEvents<std::vec::IntoIter<KeyEvent>>,
+        }
+
+        impl EventIterator for RefreshEvents {
+            fn next_event(&mut self) -> io::Result<KeyEvent> {
+                unreachable!("events should be polled while refreshes are requested")
+            }
+
+            fn poll_event(&mut self, _: Duration) -> io::Result<Option<KeyEvent>> {
+                self.timed_out = !self.timed_out;
+
+                if self.timed_out {
+                    // Multiple requests before a poll only cause a single render
+                    self.refresh.send(()).unwrap();
+                    self.refresh.send(()).unwrap();
+                    Ok(None)
+                } else {
+                    self.events.next_event().map(Some)
+                }
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let (tx, rx) = mpsc::channel();
+        let mut events = RefreshEvents {
+            refresh: tx,
+            timed_out: false,
+            events: TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]),
+        };
+
+        let renders = Input::new(RendersPrompt::default(), &mut backend)
+            .with_refresh(rx)
+            .run(&mut events)
+            .unwrap();
+
+        // The initial render, and one before each key
+        assert_eq!(renders, Some(3));
+
+        // Once the sender is dropped, the events are no longer polled
+        let mut backend = TestBackend::new((100, 20).into());
+        let (tx, rx) = mpsc::channel();
+        tx.send(()).unwrap();
+        drop(tx);
+
+        let renders = Input::new(RendersPrompt::default(), &mut backend)
+            .with_refresh(rx)
+            .run(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+            .unwrap();
+
+        assert_eq!(renders, Some(2));
+    }
+
+    #[test]
+    fn test_max_content_width() {
+        /// A prompt with a hint which wraps in a narrow width
+        #[derive(Debug)]
+        struct HintPrompt(crate::widgets::Prompt<&'static str, &'static str>);
+
+        impl Widget for HintPrompt {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+                self.0.render(layout, b)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                self.0.height(layout)
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                self.0.cursor_pos(layout)
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for HintPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = ();
+
+            fn finish(self) -> Self::Output {}
+        }
+
+        let size = (100, 20).into();
+
+        for &align in &[Align::Left, Align::Center] {
+            let mut backend = TestBackend::new(size);
+            let prompt = crate::widgets::Prompt::new("message")
+                .with_hint("a hint which is too long to fit on a single line of the prompt");
+
+            let mut input = Input::new(HintPrompt(prompt), &mut backend)
+                .max_content_width(40)
+                .align(align);
+
+            input.init().unwrap();
+
+            let offset_x = if align == Align::Center { 30 } else { 0 };
+            // The hint wraps to the second line, ending after `fit on a single line of the prompt) `
+            assert_eq!(input.backend.get_cursor_pos().unwrap(), (offset_x + 36, 1));
+
+            crate::assert_backend_snapshot!(
+                format!("max_content_width-{:?}", align),
+                *input.backend
+            );
+
+            input.print_error("error text").unwrap();
+            crate::assert_backend_snapshot!(
+                format!("max_content_width-{:?}-error", align),
+                *input.backend
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_content_width_wrapped_input() {
+        /// A prompt followed by text which is typed on the same line
+        struct TextPrompt {
+            prompt: crate::widgets::Prompt<&'static str>,
+            input: crate::widgets::StringInput,
+        }
+
+        impl Widget for TextPrompt {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+                self.prompt.render(layout, b)?;
+                self.input.render(layout, b)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                self.prompt.height(layout) + self.input.height(layout) - 1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                self.input
+                    .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                self.input.handle_key(key)
+            }
+        }
+
+        impl Prompt for TextPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = ();
+
+            fn finish(self) -> Self::Output {}
+        }
+
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+        let mut input = crate::widgets::StringInput::default();
+        input.set_value("a value which is too long to fit on a single line".into());
+        input.set_at(usize::MAX);
+
+        let prompt = TextPrompt {
+            prompt: crate::widgets::Prompt::new("message"),
+            input,
+        };
+        let mut input = Input::new(prompt, &mut backend)
+            .max_content_width(40)
+            .align(Align::Center);
+
+        input.init().unwrap();
+
+        // `? message › ` takes 12 columns, so the value wraps after 28 characters and the other 21
+        // start at the left edge of the content
+        assert_eq!(input.backend.get_cursor_pos().unwrap(), (30 + 21, 1));
+
+        crate::assert_backend_snapshot!(*input.backend);
+    }
+
+    #[test]
+    fn test_confirm_on_interrupt() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let size = (100, 20).into();
+
+        // `n` dismisses the question and the prompt continues
+        let mut backend = TestBackend::new(size);
+        let mut input = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .confirm_on_interrupt(Some("Discard answers?".into()));
+        input.init().unwrap();
+
+        let mut events = TestEvents::new(vec![KeyCode::Char('n').into()]);
+        assert!(!input.confirm_interrupt(&mut events).unwrap());
+        drop(events);
+
+        let res = input.run(&mut TestEvents::new(vec![
+            ctrl_c,
+            KeyCode::Char('n').into(),
+            KeyCode::Enter.into(),
+        ]));
+        assert!(matches!(res, Ok(Some(()))));
+
+        // `y` and a second `Ctrl+C` interrupt the prompt
+        for &key in &[KeyCode::Char('y').into(), ctrl_c] {
+            let mut backend = TestBackend::new(size);
+            let res = Input::new(TestPrompt { height: 1 }, &mut backend)
+                .confirm_on_interrupt(Some("Discard answers?".into()))
+                .run(&mut TestEvents::new(vec![ctrl_c, key]));
+
+            assert!(matches!(res, Err(crate::ErrorKind::Interrupted)));
+        }
+
+        let mut backend = TestBackend::new(size);
+        let mut input = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .confirm_on_interrupt(Some("Discard answers?".into()));
+        input.init().unwrap();
+
+        let mut events = TestEvents::new(vec![KeyCode::Char('y').into()]);
+        assert!(input.confirm_interrupt(&mut events).unwrap());
+        drop(input);
+
+        crate::assert_backend_snapshot!(backend);
+    }
+
+    #[test]
+    fn test_min_display() {
+        let mut backend = TestBackend::new((100, 20).into());
+
+        Input::new(TestPrompt::default(), &mut backend)
+            .run(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+            .unwrap();
+        assert_eq!(backend.slept(), Duration::from_secs(0));
+
+        Input::new(TestPrompt::default(), &mut backend)
+            .min_display(Duration::from_millis(500))
+            .run(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+            .unwrap();
+        assert_eq!(backend.slept(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_frames() {
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut input = Input::new(TestPrompt { height: 5 }, &mut backend);
+
+        input.init().unwrap();
+        assert_eq!(input.backend.frames(), 1);
+
+        input.print_error("error text").unwrap();
+        input.render().unwrap();
+        assert_eq!(input.backend.frames(), 3);
+    }
+
+    #[test]
+    fn test_frame_ended_on_error() {
+        struct FailingWidget;
+
+        impl Widget for FailingWidget {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Err(io::ErrorKind::Other.into())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, _: KeyEvent) -> bool {
+                false
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut input = Input::new(TestPrompt { height: 5 }, &mut backend);
+
+        input.init().unwrap();
+        assert!(input.print_below(FailingWidget, None).is_err());
+        assert_eq!(input.backend.frames(), 2);
+    }
+
+    #[test]
+    fn test_run_with_outcome() {
+        /// Returns the typed text, or a default if nothing was typed
+        #[derive(Debug, Default)]
+        struct DefaultPrompt(String);
+
+        impl Widget for DefaultPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        self.0.push(c);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        }
+
+        impl Prompt for DefaultPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = String;
+
+            fn finishes_with_default(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            fn finish(self) -> Self::Output {
+                if self.0.is_empty() {
+                    "default".into()
+                } else {
+                    self.0
+                }
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+
+        let res = Input::new(DefaultPrompt::default(), &mut backend)
+            .run_with_outcome(&mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Enter.into(),
+            ]))
+            .unwrap();
+        assert_eq!(res, Some(("a".into(), Outcome::Submitted)));
+
+        let res = Input::new(DefaultPrompt::default(), &mut backend)
+            .run_with_outcome(&mut TestEvents::new(Some(KeyCode::Enter.into())))
+            .unwrap();
+        assert_eq!(res, Some(("default".into(), Outcome::DefaultAccepted)));
+
+        let res = Input::new(DefaultPrompt::default(), &mut backend)
+            .on_esc(OnEsc::SkipQuestion)
+            .run_with_outcome(&mut TestEvents::new(Some(KeyCode::Esc.into())))
+            .unwrap();
+        assert_eq!(res, None);
+    }
 }
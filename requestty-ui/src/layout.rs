@@ -134,9 +134,11 @@ impl Layout {
     }
 
     /// Creates a new `Layout` that represents a region past the `cursor_pos`. `cursor_pos` is
-    /// relative to `offset_x` and `offset_y`.
+    /// relative to (0, 0), like the positions returned by [`Widget::cursor_pos`].
+    ///
+    /// [`Widget::cursor_pos`]: crate::Widget::cursor_pos
     pub fn with_cursor_pos(mut self, cursor_pos: (u16, u16)) -> Self {
-        self.line_offset = cursor_pos.0;
+        self.line_offset = cursor_pos.0.saturating_sub(self.offset_x);
         self.offset_y = cursor_pos.1;
         self
     }
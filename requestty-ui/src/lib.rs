@@ -16,6 +16,25 @@
 //! - [`termion`](https://crates.io/crates/termion)
 //!
 //! The different backends can be enabled using the features of the same name.
+//!
+//! # Tracing
+//!
+//! If the `tracing` feature is enabled, [`Input::run`] emits [`tracing`] events with the target
+//! `requestty` at the `DEBUG` level:
+//!
+//! - `prompt.start`: The prompt has been rendered for the first time.
+//! - `prompt.key`: A key has been received. The key itself is not recorded, since it could be
+//!   part of a password.
+//! - `prompt.validate`: The prompt was validated. The `result` field is one of `finish`,
+//!   `continue`, `continue_with` or `error`.
+//! - `prompt.finish`: The prompt finished successfully. The `outcome` field is the [`Outcome`].
+//! - `prompt.skip`: The prompt was skipped with `Esc`.
+//! - `prompt.error`: The prompt was exited. The `error` field is one of `interrupted`, `eof` or
+//!   `aborted`.
+//!
+//! Without the feature, none of this code is compiled.
+//!
+//! [`tracing`]: https://docs.rs/tracing
 #![deny(
     missing_docs,
     missing_debug_implementations,
@@ -25,10 +44,16 @@
 #![warn(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+pub use backend::terminal_size;
 pub use error::{ErrorKind, Result};
-pub use input::{Input, OnEsc, Prompt, Validation};
+pub use input::{Align, Input, OnEsc, Outcome, Prompt, Validation};
 pub use widgets::Widget;
 
+// Re-exported so that `requestty` measures text the same way as the widgets
+#[doc(hidden)]
+pub use textwrap;
+
 pub mod backend;
 mod char_input;
 mod error;
@@ -4,7 +4,7 @@ use crate::{
     backend::Backend,
     events,
     layout::Layout,
-    style::{Color, Stylize},
+    style::{Color, Style, Stylize},
     Widget,
 };
 
@@ -38,12 +38,45 @@ impl From<Delimiter> for Option<(char, char)> {
     }
 }
 
+/// What is rendered between the message of a [`Prompt`] and the answer when there is no hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// The [arrow](crate::symbols::SymbolSet::arrow) of the current symbol set, with a space before
+    /// it. For example, `? message › `. This is the default.
+    Arrow,
+    /// The given character right after the message. For example, `Separator::Char(':')` renders
+    /// `? message: `.
+    Char(char),
+    /// Nothing. For example, `? message `.
+    None,
+}
+
+impl Separator {
+    /// The width of the separator as rendered after the message.
+    fn width(self) -> u16 {
+        match self {
+            // ` ›`
+            Separator::Arrow => 2,
+            Separator::Char(c) => textwrap::core::display_width(c.encode_utf8(&mut [0; 4])) as u16,
+            Separator::None => 0,
+        }
+    }
+}
+
 /// A generic prompt that renders a message and an optional hint.
 #[derive(Debug, Clone)]
 pub struct Prompt<M, H = &'static str> {
     message: M,
     hint: Option<H>,
     delim: Delimiter,
+    separator: Separator,
+    trailing_space: bool,
+    hint_style: Style,
+    required: bool,
+    required_style: Style,
+    collapsing_hint: bool,
+    /// Whether a key has been handled, after which a collapsing hint is hidden.
+    touched: bool,
     message_len: u16,
     hint_len: u16,
 }
@@ -57,6 +90,13 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
             message,
             hint: None,
             delim: Delimiter::Parentheses,
+            separator: Separator::Arrow,
+            trailing_space: true,
+            hint_style: Style::new().fg(Color::DarkGrey),
+            required: false,
+            required_style: Style::new().fg(Color::Red),
+            collapsing_hint: false,
+            touched: false,
             hint_len: 0,
         }
     }
@@ -83,6 +123,71 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         self
     }
 
+    /// Sets what is rendered between the message and the answer when there is no hint
+    ///
+    /// If not set, it is [`Separator::Arrow`].
+    pub fn with_separator(mut self, separator: Separator) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets whether a space is rendered at the end of the prompt, before the answer
+    ///
+    /// If not set, it is `true`.
+    pub fn with_trailing_space(mut self, trailing_space: bool) -> Self {
+        self.trailing_space = trailing_space;
+        self
+    }
+
+    /// Sets the style of the hint
+    ///
+    /// If not set, the hint is dark grey.
+    pub fn with_hint_style(mut self, hint_style: Style) -> Self {
+        self.hint_style = hint_style;
+        self
+    }
+
+    /// Sets the style of the hint if it is `Some`
+    pub fn with_optional_hint_style(self, hint_style: Option<Style>) -> Self {
+        match hint_style {
+            Some(hint_style) => self.with_hint_style(hint_style),
+            None => self,
+        }
+    }
+
+    /// Sets whether the prompt is required
+    ///
+    /// If it is required, an asterisk is rendered right after the message.
+    pub fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the style of the required marker
+    ///
+    /// If not set, the marker is red.
+    pub fn with_required_style(mut self, required_style: Style) -> Self {
+        self.required_style = required_style;
+        self
+    }
+
+    /// Sets the style of the required marker if it is `Some`
+    pub fn with_optional_required_style(self, required_style: Option<Style>) -> Self {
+        match required_style {
+            Some(required_style) => self.with_required_style(required_style),
+            None => self,
+        }
+    }
+
+    /// Sets whether the hint is hidden once the first key is handled
+    ///
+    /// This is useful for long hints, which are only needed until the user starts interacting
+    /// with the prompt. If not set, it is `false`.
+    pub fn with_collapsing_hint(mut self, collapsing_hint: bool) -> Self {
+        self.collapsing_hint = collapsing_hint;
+        self
+    }
+
     /// Get the message
     pub fn message(&self) -> &M {
         &self.message
@@ -98,6 +203,45 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         self.delim
     }
 
+    /// Get the separator
+    pub fn separator(&self) -> Separator {
+        self.separator
+    }
+
+    /// Whether a space is rendered at the end of the prompt
+    pub fn has_trailing_space(&self) -> bool {
+        self.trailing_space
+    }
+
+    /// Get the style of the hint
+    pub fn hint_style(&self) -> Style {
+        self.hint_style
+    }
+
+    /// Whether the prompt is required
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Get the style of the required marker
+    pub fn required_style(&self) -> Style {
+        self.required_style
+    }
+
+    /// Whether the hint is hidden once the first key is handled
+    pub fn has_collapsing_hint(&self) -> bool {
+        self.collapsing_hint
+    }
+
+    /// The hint, unless it has collapsed.
+    fn visible_hint(&self) -> Option<&H> {
+        if self.collapsing_hint && self.touched {
+            None
+        } else {
+            self.hint.as_ref()
+        }
+    }
+
     /// Consume self returning the owned message
     pub fn into_message(self) -> M {
         self.message
@@ -118,9 +262,9 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         self.message_len
     }
 
-    /// The character length of the hint. It is 0 if the hint is absent
+    /// The character length of the hint. It is 0 if the hint is absent or has collapsed
     pub fn hint_len(&self) -> u16 {
-        if self.hint.is_some() {
+        if self.visible_hint().is_some() {
             match self.delim {
                 Delimiter::None => self.hint_len,
                 _ => self.hint_len + 2,
@@ -132,29 +276,92 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
 
     /// The character length of the fully rendered prompt
     pub fn width(&self) -> u16 {
-        if self.hint.is_some() {
+        // `*` after the message if it is required
+        let message_len = self.message_len + self.required as u16;
+        let trailing_space = self.trailing_space as u16;
+
+        if self.visible_hint().is_some() {
             // `? <message> <hint> `
-            2 + self.message_len + 1 + self.hint_len() + 1
+            2 + message_len + 1 + self.hint_len() + trailing_space
         } else {
             // `? <message> › `
-            2 + self.message_len + 3
+            2 + message_len + self.separator.width() + trailing_space
         }
     }
 
-    fn cursor_pos_impl(&self, layout: Layout) -> (u16, u16) {
-        let mut width = self.width();
-        let relative_pos = if width > layout.line_width() {
-            width -= layout.line_width();
+    /// The hint surrounded by the delimiters, if any.
+    fn delimited_hint(&self) -> Option<String> {
+        let hint = self.visible_hint()?.as_ref();
 
-            (width % layout.width, 1 + width / layout.width)
-        } else {
-            (layout.line_offset + width, 0)
+        Some(match self.delim.into() {
+            Some((start, end)) => format!("{}{}{}", start, hint, end),
+            None => hint.to_owned(),
+        })
+    }
+
+    /// The position of the hint, and the lines it is wrapped into if it does not fit on the line
+    /// it starts on. The position is relative to `offset_x` and `offset_y`.
+    ///
+    /// If the hint fits, `None` is returned, and the entire prompt is written as a single line.
+    fn wrapped_hint(&self, layout: Layout) -> Option<((u16, u16), Vec<String>)> {
+        let hint = self.delimited_hint()?;
+
+        // `? <message> `
+        let prefix_width = 2 + self.message_len + self.required as u16 + 1;
+        let start = advance(layout, prefix_width);
+
+        if advance(layout, self.width()).1 == start.1 {
+            return None;
+        }
+
+        // Like the `Text` widget, the hint is wrapped with the space before it on the first line
+        // as an indent, which is then removed
+        let indent = " ".repeat(start.0 as usize);
+        let mut lines: Vec<_> = textwrap::wrap(
+            &hint,
+            textwrap::Options::new(layout.available_width() as usize).initial_indent(&indent),
+        )
+        .into_iter()
+        .map(|line| line.into_owned())
+        .collect();
+        drop(lines[0].drain(..indent.len()));
+
+        Some((start, lines))
+    }
+
+    fn cursor_pos_impl(&self, layout: Layout) -> (u16, u16) {
+        let relative_pos = match self.wrapped_hint(layout) {
+            Some(((x, y), lines)) => {
+                let last = lines.len() as u16 - 1;
+                let last_x = if last == 0 { x } else { 0 };
+                let last_width = textwrap::core::display_width(&lines[last as usize]) as u16;
+
+                // The space after the hint
+                let (x, dy) = advance(
+                    layout.with_line_offset(last_x),
+                    last_width + self.trailing_space as u16,
+                );
+                (x, y + last + dy)
+            }
+            None => advance(layout, self.width()),
         };
 
         layout.offset_cursor(relative_pos)
     }
 }
 
+/// The position after writing text of the given width at the start of the layout, if the terminal
+/// wraps it onto the next lines. It is relative to `offset_x` and `offset_y`.
+fn advance(layout: Layout, mut width: u16) -> (u16, u16) {
+    if width > layout.line_width() {
+        width -= layout.line_width();
+
+        (width % layout.width, 1 + width / layout.width)
+    } else {
+        (layout.line_offset + width, 0)
+    }
+}
+
 impl<M: AsRef<str>> Prompt<M, &'static str> {
     /// The end prompt to be printed once the question is answered.
     pub fn write_finished_message<B: Backend>(
@@ -174,26 +381,49 @@ impl<M: AsRef<str>> Prompt<M, &'static str> {
         backend.write_styled(&symbol_set.middle_dot.dark_grey())?;
         backend.write_all(b" ")
     }
+
+    /// The width of the end prompt written by [`write_finished_message`].
+    ///
+    /// [`write_finished_message`]: Prompt::write_finished_message
+    pub fn finished_message_width(message: &M) -> usize {
+        // `✔ <message> · `
+        2 + textwrap::core::display_width(message.as_ref()) + 3
+    }
 }
 
 impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
     fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
         b.write_styled(&"? ".light_green())?;
         b.write_styled(&self.message.as_ref().bold())?;
-        b.write_all(b" ")?;
+        if self.required {
+            b.write_styled(&self.required_style.apply('*'))?;
+        }
 
-        b.set_fg(Color::DarkGrey)?;
+        if self.visible_hint().is_some() {
+            b.write_all(b" ")?;
+        }
 
-        match (&self.hint, self.delim.into()) {
-            (Some(hint), Some((start, end))) => write!(b, "{}{}{}", start, hint.as_ref(), end)?,
-            (Some(hint), None) => write!(b, "{}", hint.as_ref())?,
-            (None, _) => {
-                write!(b, "{}", crate::symbols::current().arrow)?;
+        if let Some(((x, y), lines)) = self.wrapped_hint(*layout) {
+            for (i, line) in lines.iter().enumerate() {
+                let x = if i == 0 { x } else { 0 };
+                b.move_cursor_to(layout.offset_x + x, layout.offset_y + y + i as u16)?;
+                b.write_styled(&self.hint_style.apply(line))?;
+            }
+        } else {
+            match (&self.delimited_hint(), self.separator) {
+                (Some(hint), _) => b.write_styled(&self.hint_style.apply(hint))?,
+                (None, Separator::Arrow) => {
+                    b.write_all(b" ")?;
+                    b.write_styled(&crate::symbols::current().arrow.dark_grey())?;
+                }
+                (None, Separator::Char(c)) => b.write_styled(&c.dark_grey())?,
+                (None, Separator::None) => {}
             }
         }
 
-        b.set_fg(Color::Reset)?;
-        b.write_all(b" ")?;
+        if self.trailing_space {
+            b.write_all(b" ")?;
+        }
 
         *layout = layout.with_cursor_pos(self.cursor_pos_impl(*layout));
 
@@ -214,8 +444,13 @@ impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
         self.cursor_pos_impl(layout)
     }
 
+    /// Collapses the hint if it is [collapsing], returning whether it needs to be re-rendered.
+    ///
+    /// [collapsing]: Prompt::with_collapsing_hint
     fn handle_key(&mut self, _: events::KeyEvent) -> bool {
-        false
+        let collapsed = self.collapsing_hint && !self.touched && self.hint.is_some();
+        self.touched = true;
+        collapsed
     }
 }
 
@@ -239,6 +474,7 @@ mod tests {
             14
         );
         assert_eq!(Prompt::new(LOREM).with_hint(UNICODE).width(), 946);
+        assert_eq!(Prompt::new("Hello").with_required(true).width(), 11);
     }
 
     #[test]
@@ -311,10 +547,67 @@ mod tests {
             LOREM,
             Some(UNICODE),
             Delimiter::None,
-            layout.with_line_offset(49).with_offset(0, 9),
+            layout.with_line_offset(70).with_offset(0, 9),
         );
     }
 
+    #[test]
+    fn test_separator() {
+        let size = (100, 20).into();
+
+        // (separator, trailing space, expected width)
+        let cases = [
+            (Separator::Char(':'), true, 9),
+            (Separator::Char('»'), false, 8),
+            (Separator::None, true, 8),
+            (Separator::None, false, 7),
+        ];
+
+        for &(separator, trailing_space, width) in cases.iter() {
+            let mut layout = Layout::new(0, size);
+            let mut prompt = Prompt::new("Hello")
+                .with_separator(separator)
+                .with_trailing_space(trailing_space);
+            let mut backend = TestBackend::new_with_layout(size, layout);
+
+            assert_eq!(prompt.width(), width);
+            assert_eq!(prompt.cursor_pos(layout), (width, 0));
+
+            prompt.render(&mut layout, &mut backend).unwrap();
+            crate::assert_backend_snapshot!(backend);
+            assert_eq!(layout, Layout::new(width, size));
+        }
+
+        // The separator is not used if there is a hint
+        assert_eq!(
+            Prompt::new("Hello")
+                .with_hint("world")
+                .with_separator(Separator::Char(':'))
+                .with_trailing_space(false)
+                .width(),
+            15
+        );
+    }
+
+    #[test]
+    fn test_wrapped_hint() {
+        let size = (20, 10).into();
+        let mut layout = Layout::new(0, size);
+        let mut prompt = Prompt::new("Hello").with_hint("this is a rather long hint that wraps");
+        let mut backend = TestBackend::new_with_layout(size, layout);
+
+        // The hint is wrapped by word instead of being cut by the terminal
+        // ? Hello (this is
+        // a rather long hint
+        // that wraps)
+        assert_eq!(prompt.height(&mut layout.clone()), 3);
+        assert_eq!(prompt.cursor_pos(layout), (12, 2));
+
+        prompt.render(&mut layout, &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+        assert_eq!(layout, Layout::new(12, size).with_offset(0, 2));
+    }
+
     #[test]
     fn test_height() {
         let mut layout = Layout::new(5, (100, 20).into());
@@ -354,14 +647,43 @@ mod tests {
             Prompt::new(LOREM)
                 .with_hint(UNICODE)
                 .cursor_pos_impl(layout),
-            (51, 9)
+            (71, 9)
         );
 
         assert_eq!(
             Prompt::new(LOREM)
                 .with_hint(UNICODE)
                 .cursor_pos_impl(layout.with_offset(0, 3)),
-            (51, 12)
+            (71, 12)
         );
     }
+
+    #[test]
+    fn test_collapsing_hint() {
+        let size = (20, 10).into();
+        let layout = Layout::new(0, size);
+        let mut prompt = Prompt::new("Hello")
+            .with_hint("this is a rather long hint that wraps")
+            .with_collapsing_hint(true);
+
+        assert_eq!(prompt.height(&mut layout.clone()), 3);
+        assert_eq!(prompt.hint_len(), 39);
+
+        assert!(prompt.handle_key(events::KeyCode::Char('a').into()));
+        // It only collapses once
+        assert!(!prompt.handle_key(events::KeyCode::Char('b').into()));
+
+        assert_eq!(prompt.height(&mut layout.clone()), 1);
+        assert_eq!(prompt.hint_len(), 0);
+        assert_eq!(prompt.cursor_pos(layout), (10, 0));
+
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        prompt.render(&mut layout.clone(), &mut backend).unwrap();
+        crate::assert_backend_snapshot!(backend);
+
+        // The hint is kept without collapsing
+        let mut prompt = Prompt::new("Hello").with_hint("world");
+        assert!(!prompt.handle_key(events::KeyCode::Char('a').into()));
+        assert_eq!(prompt.hint_len(), 7);
+    }
 }
@@ -7,7 +7,7 @@ use crate::{
     backend::Backend,
     events::{KeyEvent, Movement},
     layout::{Layout, RenderRegion},
-    style::Stylize,
+    style::{Color, Stylize},
 };
 
 #[cfg(test)]
@@ -44,14 +44,36 @@ pub trait List {
 
     /// The maximum height that can be taken by the list.
     ///
-    /// If the total height exceeds the page size, the list will be scrollable.
+    /// If the total height exceeds the page size, the list will be scrollable. The page size can
+    /// change between renders, in which case the page is recomputed around the hovered element.
     fn page_size(&self) -> usize;
 
     /// Whether to wrap around when user gets to the last element.
     ///
-    /// This only applies when the list is scrollable, i.e. page size > total height.
+    /// If this is `false`, navigation stops at the first and last selectable elements. This
+    /// applies regardless of whether the list is scrollable.
     fn should_loop(&self) -> bool;
 
+    /// The minimum number of elements to keep visible above and below the hovered element while
+    /// scrolling.
+    ///
+    /// This is only followed where possible. It is capped so that the hovered element can still
+    /// be shown along with the margin on both sides within the page size, and it cannot be
+    /// followed near the ends of the list when looping is disabled. Defaults to 1.
+    fn scroll_margin(&self) -> usize {
+        1
+    }
+
+    /// The hint shown below the list while it is scrollable, given the number of elements which
+    /// are not visible.
+    ///
+    /// The hint takes a single line, and is cut short if it is wider than the terminal. If this
+    /// returns `None`, `(Move up and down to reveal more choices)` is shown. Defaults to `None`.
+    fn scroll_hint(&self, hidden: usize) -> Option<String> {
+        let _ = hidden;
+        None
+    }
+
     /// The height of the element at an index will take to render
     fn height_at(&mut self, index: usize, layout: Layout) -> u16;
 
@@ -64,10 +86,13 @@ pub trait List {
     }
 }
 
+/// The heights of the elements of the list, which are only valid for the width they were computed
+/// with.
 #[derive(Debug, Clone)]
 struct Heights {
     heights: Vec<u16>,
-    prev_layout: Layout,
+    /// The available width of the layout the heights were computed with.
+    width: u16,
 }
 
 /// A widget to select a single item from a list.
@@ -82,6 +107,8 @@ pub struct Select<L> {
     page_end: usize,
     page_start_height: u16,
     page_end_height: u16,
+    /// The page size the current page was computed with
+    init_page_size: u16,
     height: u16,
     heights: Option<Heights>,
     /// The underlying list
@@ -111,6 +138,7 @@ impl<L: List> Select<L> {
             height: u16::MAX,
             page_start_height: u16::MAX,
             page_end_height: u16::MAX,
+            init_page_size: 0,
             heights: None,
             at: first_selectable,
             page_start: 0,
@@ -191,17 +219,69 @@ impl<L: List> Select<L> {
         at
     }
 
+    /// Computes the height of every element of the list for the width of the given layout, unless
+    /// they have already been computed for the same width.
+    ///
+    /// This is done automatically when the list is rendered, and only needs to be called to
+    /// compute the heights ahead of time. Since the heights are only recomputed when the width
+    /// changes, [`invalidate_heights`] needs to be called if the elements of the list change.
+    ///
+    /// [`invalidate_heights`]: Select::invalidate_heights
+    pub fn cache_heights(&mut self, layout: Layout) {
+        self.maybe_update_heights(layout);
+    }
+
+    /// Discards the cached heights of the elements of the list, so that they are recomputed on the
+    /// next render.
+    ///
+    /// This must be called if the heights of the elements of the list may have changed, for
+    /// example if they are modified or reordered. The elements shown in the current page stay the
+    /// same.
+    pub fn invalidate_heights(&mut self) {
+        self.heights = None;
+    }
+
+    /// Updates the selectable elements after elements are added to or removed from the list, or
+    /// change whether they are selectable.
+    ///
+    /// The heights are recomputed on the next render, and the page is recomputed around the
+    /// hovered element. The index of the hovered element is kept, so [`set_at`] needs to be called
+    /// if it moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no selectable items.
+    ///
+    /// [`set_at`]: Select::set_at
+    pub fn update_list(&mut self) {
+        self.first_selectable = (0..self.list.len())
+            .position(|i| self.list.is_selectable(i))
+            .expect("there must be at least one selectable item");
+        self.last_selectable = (0..self.list.len())
+            .rposition(|i| self.list.is_selectable(i))
+            .unwrap();
+
+        self.heights = None;
+        // The page bounds may be past the end of the list, so the page is recomputed when it is
+        // next rendered
+        self.init_page_size = 0;
+    }
+
     fn maybe_update_heights(&mut self, mut layout: Layout) {
+        let width = layout.available_width();
+
         let heights = match self.heights {
-            Some(ref mut heights) if heights.prev_layout != layout => {
+            Some(ref mut heights) if heights.width != width => {
                 heights.heights.clear();
-                heights.prev_layout = layout;
+                heights.width = width;
+                // The heights of the elements in the page may have changed
+                self.init_page_size = 0;
                 &mut heights.heights
             }
             None => {
                 self.heights = Some(Heights {
                     heights: Vec::with_capacity(self.list.len()),
-                    prev_layout: layout,
+                    width,
                 });
 
                 &mut self.heights.as_mut().unwrap().heights
@@ -227,20 +307,57 @@ impl<L: List> Select<L> {
         self.height > self.page_size()
     }
 
+    /// The number of elements which are at least partially visible in the page
+    fn page_len(&self) -> usize {
+        if self.page_end < self.page_start {
+            self.list.len() - self.page_start + self.page_end + 1
+        } else {
+            self.page_end - self.page_start + 1
+        }
+    }
+
+    /// The scroll margin of the list, capped so that the margin on both sides and self.at can fit
+    /// in a page
+    fn scroll_margin(&self) -> usize {
+        // -1 since the message at the end takes one line, and another -1 for self.at
+        let max_margin = (self.list.page_size().saturating_sub(2)) / 2;
+        self.list.scroll_margin().min(max_margin)
+    }
+
     /// Checks whether the page bounds need to be adjusted
     ///
-    /// This returns true if at == page_start || at == page_end, and so even though it is visible,
-    /// the page bounds should be adjusted
+    /// This returns true if there are less than `scroll_margin` elements between at and
+    /// page_start or page_end, and so even though it is visible, the page bounds should be
+    /// adjusted
     fn at_outside_page(&self) -> bool {
-        if self.page_start < self.page_end {
-            // - a - - S - - - - - - E - a -
-            //   ^------- outside -------^
-            self.at <= self.page_start || self.at >= self.page_end
+        let margin = self.scroll_margin();
+
+        // positions are relative to page_start, and so take care of the page wrapping around
+        //
+        // - a - - S - - - - - - E - a -
+        //   ^------- outside -------^
+        //
+        // - - - - E - - - a - - S - - -
+        //       outside --^
+        let pos = if self.at >= self.page_start {
+            self.at - self.page_start
         } else {
-            // - - - - E - - - a - - S - - -
-            //       outside --^
-            self.at <= self.page_start && self.at >= self.page_end
-        }
+            self.at + self.list.len() - self.page_start
+        };
+
+        let span = if self.page_end >= self.page_start {
+            self.page_end - self.page_start
+        } else {
+            self.page_end + self.list.len() - self.page_start
+        };
+
+        // if looping is disabled, the margin cannot be kept when the page is at the ends of the
+        // list, and trying to do so will only shrink the page
+        let at_list_start = !self.list.should_loop() && self.page_start == 0;
+        let at_list_end =
+            !self.list.should_loop() && self.list.len().checked_sub(1) == Some(self.page_end);
+
+        pos > span || (pos < margin && !at_list_start) || (span - pos < margin && !at_list_end)
     }
 
     /// Gets the index at a given delta taking into account looping if enabled -- delta must be
@@ -284,9 +401,10 @@ impl<L: List> Select<L> {
 
         // -1 since the message at the end takes one line
         let max_height = self.page_size() - 1;
+        let margin = self.scroll_margin();
 
-        // This first gets an element from the direction we have moved from, then one
-        // from the opposite, and the rest again from the direction we have move from
+        // This first gets an element from the direction we have moved from, then `margin`
+        // elements from the opposite, and the rest again from the direction we have move from
         //
         // for example,
         // take that we have moved downwards (like from 2 to 3).
@@ -300,22 +418,22 @@ impl<L: List> Select<L> {
         // |  3  | <-- self.at
         // .-----.
         // |  4  | <-- iter[1] | We pick 4 over ones before 2 since it provides a
-        // '-----'               padding of one element at the end
+        // '-----'               padding of one element (the scroll margin) at the end
         //
         // note: the above example avoids things like looping, which is handled by
         // try_get_index
         let iter = self
             .try_get_index(direction)
-            .map(|i| (i, false))
+            .map(|i| (i, false, false))
             .into_iter()
-            .chain(
-                self.try_get_index(-direction)
-                    .map(|i| (i, true)) // boolean value to show this is special
-                    .into_iter(),
-            )
+            .chain((1..=margin).filter_map(|i| {
+                // boolean values to show this is special, and whether it is the last one
+                self.try_get_index(-direction * i as isize)
+                    .map(|index| (index, true, i == margin))
+            }))
             .chain(
                 (2..(max_height as isize))
-                    .filter_map(|i| self.try_get_index(direction * i).map(|i| (i, false))),
+                    .filter_map(|i| self.try_get_index(direction * i).map(|i| (i, false, false))),
             );
 
         // these variables have opposite meaning based on the direction, but they store
@@ -325,14 +443,14 @@ impl<L: List> Select<L> {
 
         let mut height = heights[self.at];
 
-        for (height_index, opposite_dir) in iter {
+        for (height_index, opposite_dir, is_last_opposite) in iter {
             if height >= max_height {
                 // There are no more elements that can be shown
                 break;
             }
 
-            let elem_height = if opposite_dir {
-                // To provide better continuity, the element in the opposite direction
+            let elem_height = if is_last_opposite {
+                // To provide better continuity, the last element in the opposite direction
                 // will have only one line shown. This prevents the cursor from jumping
                 // about when the element in the opposite direction has different height
                 // from the one rendered previously
@@ -341,10 +459,10 @@ impl<L: List> Select<L> {
                 (height + heights[height_index]).min(max_height) - height
             };
 
-            // If you see the creation of iter, this special cases the second element in
-            // the iterator as it is the _only_ one in the opposite direction
+            // If you see the creation of iter, this special cases the elements in the
+            // iterator which are in the opposite direction
             //
-            // It cannot simply be checked as being the second element, as try_get_index
+            // It cannot simply be checked by the position in the iterator, as try_get_index
             // may return None when looping is disabled
             if opposite_dir {
                 bound_b.0 = height_index;
@@ -376,7 +494,7 @@ impl<L: List> Select<L> {
 
     /// Adjust the page if required considering the direction we moved to
     fn maybe_adjust_page(&mut self, moved_to: Movement) {
-        // Check whether at is within second and second last element of the page
+        // Check whether at is at least scroll margin elements away from the page bounds
         if self.at_outside_page() {
             self.adjust_page(moved_to)
         }
@@ -391,6 +509,7 @@ impl<L: List> Select<L> {
 
         self.page_start = 0;
         self.page_start_height = heights[self.page_start];
+        self.init_page_size = self.page_size();
 
         if self.is_paginating() {
             let mut height = heights[0];
@@ -500,10 +619,11 @@ impl<L: List> super::Widget for Select<L> {
                 // We want the current self.at to be visible after the PageUp movement,
                 // and if possible we want to it to be the bottom most element visible
 
-                // We decrease self.at by 1, since adjust_page will put self.at as the
-                // second last element, so if (self.at - 1) is the second last element,
-                // self.at is the last element visible
-                self.at = self.try_get_index(-1).unwrap_or(self.at);
+                // We decrease self.at by the scroll margin, since adjust_page will leave
+                // that many elements after self.at, so if (self.at - margin) has margin
+                // elements after it, self.at is the last element visible
+                let margin = self.scroll_margin();
+                self.at = self.try_get_index(-(margin as isize)).unwrap_or(self.at);
                 self.adjust_page(Movement::Down);
 
                 if self.page_start == 0 && !self.list.should_loop() {
@@ -514,14 +634,16 @@ impl<L: List> super::Widget for Select<L> {
                     self.init_page();
                 } else {
                     // Now that the page is determined, we want to set self.at to be some
-                    // _selectable_ element which is not the top most element visible,
-                    // so we undershoot by 1
+                    // _selectable_ element which is not within the scroll margin of the top,
+                    // so we undershoot
                     self.at = self.page_start;
-                    // ...and then go forward at least one element
+                    // ...and then go forward at least scroll margin elements
                     //
-                    // note: self.at cannot directly be set to self.page_start + 1, since it
-                    // also has to be a selectable element
-                    self.at = self.next_selectable();
+                    // note: self.at cannot directly be set to self.page_start + margin, since
+                    // it also has to be a selectable element
+                    for _ in 0..margin.max(1) {
+                        self.at = self.next_selectable();
+                    }
                 }
 
                 Movement::Up
@@ -542,15 +664,16 @@ impl<L: List> super::Widget for Select<L> {
                 // We want the current self.at to be visible after the PageDown movement,
                 // and if possible we want to it to be the top most element visible
 
-                // We increase self.at by 1, since adjust_page will put self.at as the
-                // second element, so if (self.at + 1) is the second last element,
-                // self.at is the last element visible
-                self.at = self.try_get_index(1).unwrap_or(self.at);
+                // We increase self.at by the scroll margin, since adjust_page will leave
+                // that many elements before self.at, so if (self.at + margin) has margin
+                // elements before it, self.at is the first element visible
+                let margin = self.scroll_margin();
+                self.at = self.try_get_index(margin as isize).unwrap_or(self.at);
                 self.adjust_page(Movement::Up);
 
                 // Now that the page is determined, we want to set self.at to be some
-                // _selectable_ element which is not the bottom most element visible,
-                // so we overshoot by 1...
+                // _selectable_ element which is not within the scroll margin of the bottom,
+                // so we overshoot...
                 self.at = self.page_end;
 
                 if self.page_end + 1 == self.list.len() && !self.list.should_loop() {
@@ -559,11 +682,13 @@ impl<L: List> super::Widget for Select<L> {
                     self.adjust_page(Movement::Down);
                     self.at = self.last_selectable;
                 } else {
-                    // ...and then go back to at least one element
+                    // ...and then go back at least scroll margin elements
                     //
-                    // note: self.at cannot directly be set to self.page_end - 1, since it
-                    // also has to be a selectable element
-                    self.at = self.prev_selectable();
+                    // note: self.at cannot directly be set to self.page_end - margin, since
+                    // it also has to be a selectable element
+                    for _ in 0..margin.max(1) {
+                        self.at = self.prev_selectable();
+                    }
                 }
 
                 Movement::Down
@@ -591,9 +716,18 @@ impl<L: List> super::Widget for Select<L> {
     fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
         self.maybe_update_heights(*layout);
 
-        // this is the first render, so we need to set page_end
-        if self.page_end == usize::MAX {
+        // this is the first render, so we need to set page_end. If everything fits in a single
+        // page, the page is also recomputed as the heights of the elements may have changed since
+        // it was last computed, which would leave the list cut short or with blank lines
+        if self.page_end == usize::MAX || !self.is_paginating() {
+            self.init_page();
+        } else if self.init_page_size != self.page_size() {
+            // The page size has changed since the page was computed, so it is recomputed around
+            // the hovered element
             self.init_page();
+            if self.is_paginating() && self.at < self.list.len() {
+                self.maybe_adjust_page(Movement::Down);
+            }
         }
 
         if layout.line_offset != 0 {
@@ -614,10 +748,20 @@ impl<L: List> super::Widget for Select<L> {
 
         if self.is_paginating() {
             // This is the message at the end that other places refer to
-            b.write_styled(&"(Move up and down to reveal more choices)".dark_grey())?;
-            layout.offset_y += 1;
+            match self.list.scroll_hint(self.list.len() - self.page_len()) {
+                Some(hint) => {
+                    b.set_fg(Color::DarkGrey)?;
+                    // The `str` widget cuts the hint short so that it stays on a single line
+                    super::Widget::render(&mut hint.as_str(), layout, b)?;
+                    b.set_fg(Color::Reset)?;
+                }
+                None => {
+                    b.write_styled(&"(Move up and down to reveal more choices)".dark_grey())?;
+                    layout.offset_y += 1;
 
-            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+                    b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+                }
+            }
         }
 
         Ok(())
@@ -11,6 +11,9 @@ struct List<T> {
     selectable: Vec<bool>,
     page_size: usize,
     should_loop: bool,
+    scroll_margin: usize,
+    /// The number of times `height_at` has been called.
+    height_calls: usize,
 }
 
 impl<T> List<T> {
@@ -20,6 +23,8 @@ impl<T> List<T> {
             selectable: Vec::new(),
             page_size: 15,
             should_loop: true,
+            scroll_margin: 1,
+            height_calls: 0,
         }
     }
 
@@ -33,6 +38,11 @@ impl<T> List<T> {
         self
     }
 
+    fn with_scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.scroll_margin = scroll_margin;
+        self
+    }
+
     fn with_selectable(mut self, selectable: Vec<bool>) -> Self {
         assert_eq!(selectable.len(), self.vec.len());
         self.selectable = selectable;
@@ -70,7 +80,12 @@ impl<T: Widget> super::List for List<T> {
         self.should_loop
     }
 
+    fn scroll_margin(&self) -> usize {
+        self.scroll_margin
+    }
+
     fn height_at(&mut self, index: usize, mut layout: Layout) -> u16 {
+        self.height_calls += 1;
         self.vec[index].height(&mut layout)
     }
 
@@ -184,6 +199,76 @@ fn test_update_heights() {
     assert!(heights[1..9].iter().all(|&h| h == 2));
 }
 
+#[test]
+fn test_cache_heights() {
+    let size = (100, 20).into();
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(List::new(multi_line_list(10)));
+    select.cache_heights(Layout::new(0, size));
+    assert_eq!(select.list.height_calls, 10);
+
+    // Neither the offsets nor moving between elements change the width, so the heights are
+    // computed only once across renders
+    for &(line_offset, offset_y) in [(0, 0), (10, 0), (0, 5), (10, 5)].iter() {
+        let layout = Layout::new(line_offset, size).with_offset(0, offset_y);
+        select.height(&mut layout.clone());
+        select.render(&mut layout.clone(), &mut backend).unwrap();
+        select.handle_key(KeyCode::Down.into());
+    }
+    assert_eq!(select.list.height_calls, 10);
+
+    // The heights are recomputed when the width changes
+    let mut layout = Layout::new(0, (50, 20).into());
+    let height = select.height(&mut layout.clone());
+    select.render(&mut layout, &mut backend).unwrap();
+    assert_eq!(select.list.height_calls, 20);
+    assert_eq!(select.heights.as_ref().unwrap().heights[1], 3);
+    assert_eq!(height, select.page_size());
+
+    // The same width with a different offset is still cached
+    select.height(&mut Layout::new(0, (60, 20).into()).with_offset(10, 0));
+    assert_eq!(select.list.height_calls, 20);
+
+    select.invalidate_heights();
+    select.height(&mut Layout::new(0, (50, 20).into()));
+    assert_eq!(select.list.height_calls, 30);
+}
+
+#[test]
+fn test_update_list() {
+    let size = (100, 20).into();
+    let mut backend = TestBackend::new(size);
+
+    let mut select = Select::new(
+        List::new(single_line_vec(20))
+            .with_page_size(10)
+            .with_should_loop(false),
+    );
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    select.handle_key(KeyCode::End.into());
+    assert_eq!(select.page_end, 19);
+
+    // Only the first 10 elements are left, and the last one is not selectable
+    select.list.vec.truncate(10);
+    select.list.selectable = (0..10).map(|i| i != 9).collect();
+    select.set_at(5);
+    select.update_list();
+
+    assert_eq!(select.last_selectable, 8);
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    assert_eq!(select.list.height_calls, 30);
+    assert_eq!((select.page_start, select.page_end), (0, 9));
+    assert!(!select.is_paginating());
+
+    select.handle_key(KeyCode::End.into());
+    assert_eq!(select.get_at(), 8);
+}
+
 #[test]
 fn test_at_outside_page() {
     let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
@@ -527,6 +612,143 @@ fn test_handle_key() {
     assert_eq!(select.page_end_height, 5);
 }
 
+#[test]
+fn test_should_loop() {
+    fn test(list: List<impl Widget>, first: usize, last: usize) {
+        let layout = Layout::new(0, (100, 20).into());
+
+        let mut select = Select::new(list.with_should_loop(false));
+        select.maybe_update_heights(layout);
+        select.init_page();
+
+        assert_eq!(select.get_at(), first);
+        assert!(!select.handle_key(KeyCode::Up.into()));
+        assert_eq!(select.get_at(), first);
+
+        assert!(select.handle_key(KeyCode::End.into()));
+        assert_eq!(select.get_at(), last);
+        assert!(!select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), last);
+
+        assert!(select.handle_key(KeyCode::Up.into()));
+        assert!(select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), last);
+        assert!(!select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), last);
+    }
+
+    // Fits in a single page
+    test(List::new(single_line_vec(5)), 0, 4);
+    test(
+        List::new(single_line_vec(5)).with_selectable(vec![false, true, true, true, false]),
+        1,
+        3,
+    );
+
+    // Scrollable
+    test(List::new(single_line_vec(20)), 0, 19);
+    test(
+        List::new(multi_line_list(10)).with_selectable(vec![
+            false, true, true, true, false, true, false, true, true, true,
+        ]),
+        1,
+        9,
+    );
+}
+
+#[test]
+fn test_scroll_margin() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    let mut select = Select::new(
+        List::new(single_line_vec(30))
+            .with_page_size(10)
+            .with_should_loop(false)
+            .with_scroll_margin(3),
+    );
+    select.maybe_update_heights(layout);
+    select.init_page();
+
+    assert_eq!(select.page_start, 0);
+    assert_eq!(select.page_end, 8);
+
+    // at the start of the list, the margin above cannot be kept
+    for _ in 0..5 {
+        assert!(select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.page_start, 0);
+        assert_eq!(select.page_end, 8);
+    }
+
+    // in the middle, there are always 3 items below at
+    for at in 6..27 {
+        assert!(select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), at);
+        assert_eq!(select.page_end, at + 3);
+        assert_eq!(select.page_start, at - 5);
+        assert_eq!(select.page_end_height, 1);
+    }
+
+    // at the end of the list, the margin below cannot be kept
+    for at in 27..30 {
+        assert!(select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), at);
+        assert_eq!(select.page_end, 29);
+    }
+    assert!(!select.handle_key(KeyCode::Down.into()));
+
+    // moving up keeps at where it is until the margin above is reached
+    for at in (24..29).rev() {
+        assert!(select.handle_key(KeyCode::Up.into()));
+        assert_eq!(select.get_at(), at);
+        assert_eq!(select.page_start, 21);
+        assert_eq!(select.page_end, 29);
+    }
+
+    // in the middle, there are always 3 items above at
+    for at in (3..24).rev() {
+        assert!(select.handle_key(KeyCode::Up.into()));
+        assert_eq!(select.get_at(), at);
+        assert_eq!(select.page_start, at - 3);
+        assert_eq!(select.page_end, at + 5);
+        assert_eq!(select.page_start_height, 1);
+    }
+
+    // the previously hovered element becomes the first visible one
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 8);
+    assert_eq!(select.page_start, 3);
+    assert_eq!(select.page_end, 11);
+
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 13);
+    assert_eq!(select.page_start, 8);
+    assert_eq!(select.page_end, 16);
+
+    // the previously hovered element becomes the last visible one
+    assert!(select.handle_key(KeyCode::PageUp.into()));
+    assert_eq!(select.get_at(), 8);
+    assert_eq!(select.page_start, 5);
+    assert_eq!(select.page_end, 13);
+
+    // the margin is capped so that it can be kept on both sides
+    let mut select = Select::new(
+        List::new(single_line_vec(30))
+            .with_page_size(10)
+            .with_scroll_margin(100),
+    );
+    select.maybe_update_heights(layout);
+    select.init_page();
+    assert_eq!(select.scroll_margin(), 4);
+
+    for at in 1..15 {
+        assert!(select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), at);
+        assert!(select.page_end >= at + 4);
+    }
+    assert_eq!(select.page_start, 10);
+    assert_eq!(select.page_end, 18);
+}
+
 #[test]
 fn test_render() {
     let size = (100, 20).into();
@@ -592,3 +814,54 @@ fn test_render() {
     crate::assert_backend_snapshot!(backend);
     assert_eq!(layout, base_layout.with_offset(20, 31));
 }
+
+#[test]
+fn test_page_size_larger_than_len() {
+    let size = (100, 20).into();
+    let base_layout = Layout::new(0, size);
+
+    let mut select = Select::new(List::new(single_line_vec(3)).with_page_size(15));
+    assert_eq!(select.height(&mut base_layout.clone()), 3);
+    assert!(!select.is_paginating());
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new(size);
+    select.render(&mut layout, &mut backend).unwrap();
+
+    crate::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 3));
+
+    // Moving around does not scroll the list
+    for key in [KeyCode::Down, KeyCode::PageDown, KeyCode::End, KeyCode::Up] {
+        select.handle_key(key.into());
+        assert_eq!((select.page_start, select.page_end), (0, 2));
+    }
+
+    // The first element wraps in a narrow layout, and does not in a wide one
+    let mut select = Select::new(
+        List::new(vec![
+            Text::new("a list item which wraps in a narrow layout"),
+            Text::new("1 list item"),
+            Text::new("2 list item"),
+        ])
+        .with_page_size(15),
+    );
+
+    let narrow_layout = Layout::new(0, (20, 20).into());
+    assert_eq!(select.height(&mut narrow_layout.clone()), 5);
+
+    let mut layout = narrow_layout;
+    select
+        .render(&mut layout, &mut TestBackend::new((20, 20).into()))
+        .unwrap();
+    assert_eq!(layout, narrow_layout.with_offset(0, 5));
+
+    // The page is recomputed without extra lines for the old heights
+    assert_eq!(select.height(&mut base_layout.clone()), 3);
+
+    let mut layout = base_layout;
+    select
+        .render(&mut layout, &mut TestBackend::new(size))
+        .unwrap();
+    assert_eq!(layout, base_layout.with_offset(0, 3));
+}
@@ -1,7 +1,4 @@
-use std::{
-    io::{self, Write},
-    ops::Range,
-};
+use std::{io, ops::Range};
 
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -18,7 +15,14 @@ use crate::{
 ///
 /// If only a single character is required, use [`CharInput`].
 ///
+/// All positions, including the position of the 'cursor', are measured in [`char`]s and not bytes,
+/// unless stated otherwise. Use [`byte_offset_of_cursor`] to index into the [`value`]. The 'cursor'
+/// moves over a grapheme cluster at a time, and its rendered position takes the display width of
+/// characters into account.
+///
 /// [`CharInput`]: crate::widgets::CharInput
+/// [`byte_offset_of_cursor`]: StringInput::byte_offset_of_cursor
+/// [`value`]: StringInput::value
 #[derive(Debug, Clone)]
 pub struct StringInput<F = super::widgets::FilterMapChar> {
     value: String,
@@ -81,10 +85,19 @@ impl<F> StringInput<F> {
     }
 
     /// Sets the location of the 'cursor' in characters.
+    ///
+    /// If `at` is past the end of the value, the 'cursor' is placed at the end.
     pub fn set_at(&mut self, at: usize) {
         self.at = at.min(self.value_len);
     }
 
+    /// Gets the location of the 'cursor' in bytes.
+    ///
+    /// This can be used to index into the [`value`](StringInput::value).
+    pub fn byte_offset_of_cursor(&self) -> usize {
+        self.get_byte_i(self.at)
+    }
+
     /// The value of the `StringInput`
     pub fn value(&self) -> &str {
         &self.value
@@ -109,6 +122,49 @@ impl<F> StringInput<F> {
         }
     }
 
+    /// Inserts the string at the 'cursor' in a single operation, moving the 'cursor' to the end of
+    /// the inserted text.
+    ///
+    /// Like characters that are typed, each character is passed through the `filter_map` function.
+    /// This is useful for pasting large amounts of text at once.
+    pub fn insert_str(&mut self, s: &str)
+    where
+        F: Fn(char) -> Option<char>,
+    {
+        let s: String = s.chars().filter_map(&self.filter_map).collect();
+        let len = s.chars().count();
+
+        if self.at == self.value_len {
+            self.value.push_str(&s);
+        } else {
+            let byte_i = self.get_byte_i(self.at);
+            self.value.insert_str(byte_i, &s);
+        }
+
+        self.at += len;
+        self.value_len += len;
+    }
+
+    /// Removes the characters in the given range of character indices.
+    ///
+    /// The range is clamped to the length of the value. If the 'cursor' is within the range, it is
+    /// moved to the start of the range, and if it is after the range, it is moved back so that it
+    /// stays on the same character.
+    pub fn delete_range(&mut self, range: Range<usize>) {
+        let end = range.end.min(self.value_len);
+        let start = range.start.min(end);
+
+        let byte_range = self.get_byte_i(start)..self.get_byte_i(end);
+        self.value.replace_range(byte_range, "");
+        self.value_len -= end - start;
+
+        if self.at >= end {
+            self.at -= end - start;
+        } else if self.at > start {
+            self.at = start;
+        }
+    }
+
     /// Returns the inputted string
     pub fn finish(self) -> String {
         self.value
@@ -131,6 +187,32 @@ impl<F> StringInput<F> {
             .unwrap_or_else(|| self.value.char_indices().count())
     }
 
+    /// Returns the byte index of the start of the grapheme to the left (< byte_i)
+    fn find_grapheme_left(&self, byte_i: usize) -> usize {
+        self.value[..byte_i]
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(new_byte_i, _)| new_byte_i)
+            .unwrap_or(0)
+    }
+
+    /// Returns the byte index of the end of the grapheme to the right (> byte_i)
+    fn find_grapheme_right(&self, byte_i: usize) -> usize {
+        self.value[byte_i..]
+            .graphemes(true)
+            .next()
+            .map(|grapheme| byte_i + grapheme.len())
+            .unwrap_or_else(|| self.value.len())
+    }
+
+    /// Gets the number of cells taken by the first `nchars` characters when rendered.
+    fn rendered_width(&self, nchars: usize, layout: &Layout) -> u16 {
+        match self.mask {
+            Some(mask) => rendered_width((0..nchars).map(|_| mask), layout),
+            None => rendered_width(self.value.chars().take(nchars), layout),
+        }
+    }
+
     /// Get the word bound iterator for a given range
     fn word_iter(&self, r: Range<usize>) -> impl DoubleEndedIterator<Item = (usize, &str)> {
         self.value[r]
@@ -278,14 +360,14 @@ where
                 self.at = self.get_char_i(self.find_word_left(self.get_byte_i(self.at)));
             }
             Some(Movement::Left) if self.at != 0 => {
-                self.at -= 1;
+                self.at = self.get_char_i(self.find_grapheme_left(self.get_byte_i(self.at)));
             }
 
             Some(Movement::NextWord) if self.at != self.value_len => {
                 self.at = self.get_char_i(self.find_word_right(self.get_byte_i(self.at)));
             }
             Some(Movement::Right) if self.at != self.value_len => {
-                self.at += 1;
+                self.at = self.get_char_i(self.find_grapheme_right(self.get_byte_i(self.at)));
             }
 
             Some(Movement::Home) if self.at != 0 => {
@@ -300,7 +382,8 @@ where
         true
     }
 
-    /// This widget ignores [`layout.offset_x`] and wraps around in the terminal.
+    /// Long values are wrapped within the width of the layout, and the lines after the first start
+    /// at [`layout.offset_x`].
     ///
     /// [`layout.offset_x`]: Layout.offset_x
     fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
@@ -308,11 +391,9 @@ where
             return Ok(());
         }
 
-        if let Some(mask) = self.mask {
-            print_mask(self.value_len, mask, backend)?;
-        } else {
-            // Terminal takes care of wrapping in case of large strings
-            backend.write_all(self.value.as_bytes())?;
+        match self.mask {
+            Some(mask) => print_wrapped((0..self.value_len).map(|_| mask), layout, backend)?,
+            None => print_wrapped(self.value.chars(), layout, backend)?,
         }
 
         // Adjust layout
@@ -326,15 +407,15 @@ where
             return 1;
         }
 
-        let mut width = textwrap::core::display_width(&self.value) as u16;
+        let mut width = self.rendered_width(self.value_len, layout);
 
         if width > layout.line_width() {
             width -= layout.line_width();
 
-            layout.line_offset = width % layout.width;
-            layout.offset_y += 1 + width / layout.width;
+            layout.line_offset = width % layout.available_width();
+            layout.offset_y += 1 + width / layout.available_width();
 
-            2 + width / layout.width
+            2 + width / layout.available_width()
         } else {
             layout.line_offset += width;
             1
@@ -342,8 +423,7 @@ where
     }
 
     fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
-        let display_at =
-            textwrap::core::display_width(&self.value[..self.get_byte_i(self.at)]) as u16;
+        let display_at = self.rendered_width(self.at, &layout);
 
         let relative_pos = if self.hide_output {
             // Nothing will be outputted so no need to move the cursor
@@ -354,7 +434,10 @@ where
         } else {
             let at = display_at - layout.line_width();
 
-            (at % layout.width, 1 + at / layout.width)
+            (
+                at % layout.available_width(),
+                1 + at / layout.available_width(),
+            )
         };
 
         layout.offset_cursor(relative_pos)
@@ -367,17 +450,70 @@ impl Default for StringInput {
     }
 }
 
-fn print_mask<W: Write>(len: usize, mask: char, w: &mut W) -> io::Result<()> {
+/// Gets the number of cells taken by the given characters when rendered starting at
+/// `layout.line_offset`. Along with the display width of the characters, this includes the cells
+/// left empty at the end of a line when a wide character does not fit, and is moved to the next
+/// line.
+fn rendered_width(chars: impl Iterator<Item = char>, layout: &Layout) -> u16 {
+    let mut line_width = layout.line_width();
+    let mut col = 0;
+    let mut width = 0;
+
+    for c in chars {
+        let char_width = char_width(c);
+
+        if col + char_width > line_width {
+            width += line_width - col;
+            col = 0;
+            line_width = layout.available_width();
+        }
+
+        col += char_width;
+        width += char_width;
+
+        if col >= line_width {
+            col = 0;
+            line_width = layout.available_width();
+        }
+    }
+
+    width
+}
+
+/// Prints the given characters starting at `layout.line_offset`, moving to the start of the next
+/// line at `layout.offset_x` whenever the next character does not fit in the current line.
+fn print_wrapped<B: Backend>(
+    chars: impl Iterator<Item = char>,
+    layout: &Layout,
+    backend: &mut B,
+) -> io::Result<()> {
+    let mut line_width = layout.line_width();
+    let mut col = 0;
+    let mut row = layout.offset_y;
     let mut buf = [0; 4];
-    let mask = mask.encode_utf8(&mut buf[..]);
 
-    for _ in 0..len {
-        w.write_all(mask.as_bytes())?;
+    for c in chars {
+        let char_width = char_width(c);
+
+        if col + char_width > line_width {
+            row += 1;
+            backend.move_cursor_to(layout.offset_x, row)?;
+            col = 0;
+            line_width = layout.available_width();
+        }
+
+        backend.write_all(c.encode_utf8(&mut buf).as_bytes())?;
+        col += char_width;
     }
 
     Ok(())
 }
 
+fn char_width(c: char) -> u16 {
+    let mut buf = [0; 4];
+    textwrap::core::display_width(c.encode_utf8(&mut buf)) as u16
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,20 +522,155 @@ mod tests {
     #[test]
     fn test_print_mask() {
         fn test(mask: char) {
-            let mut buf = [0u8; 100];
-            let mask_len = mask.len_utf8();
-            print_mask(25, mask, &mut &mut buf[..]).unwrap();
-            assert!(std::str::from_utf8(&buf[0..(25 * mask_len)])
-                .unwrap()
-                .chars()
-                .all(|c| c == mask));
-            assert!(buf[(25 * mask_len)..].iter().all(|&b| b == 0));
+            let size = (100, 20).into();
+            let mut backend = TestBackend::new(size);
+            let mut input = StringInput::default().password(Some(mask));
+            input.set_value("a".repeat(25));
+            input
+                .render(&mut Layout::new(0, size), &mut backend)
+                .unwrap();
+
+            let screen = backend.to_plain_string();
+            let line = screen.lines().next().unwrap().trim_end();
+            assert_eq!(line.chars().count(), 25);
+            assert!(line.chars().all(|c| c == mask));
         }
 
         test('*');
         test('‣');
     }
 
+    #[test]
+    fn test_render_offset() {
+        // A centered layout 20 columns wide, which leaves 10 columns on each side
+        let size = (40, 20).into();
+        let mut layout = Layout::new(5, size).with_offset(10, 0);
+        layout.width = 30;
+
+        let mut backend = TestBackend::new(size);
+        backend.move_cursor_to(15, 0).unwrap();
+        let mut input = StringInput::default();
+        input.set_value("abcdefghijklmnopqrstuvwxyz0123456789".into());
+        input.set_at(20);
+
+        assert_eq!(input.cursor_pos(layout), (15, 1));
+
+        input.render(&mut layout, &mut backend).unwrap();
+
+        let screen = backend.to_plain_string();
+        let lines: Vec<_> = screen.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                format!("{:15}abcdefghijklmno", ""),
+                format!("{:10}pqrstuvwxyz012345678", ""),
+                format!("{:10}9", ""),
+            ]
+        );
+        assert_eq!(layout.line_offset, 1);
+        assert_eq!(layout.offset_y, 2);
+    }
+
+    #[test]
+    fn test_insert_str() {
+        let mut input = StringInput::default();
+        input.set_value("Hello, World!".into());
+        input.set_at(7);
+
+        let pasted = "¿?".repeat(100);
+        input.insert_str(&pasted);
+
+        assert_eq!(input.value(), format!("Hello, {}World!", pasted));
+        assert_eq!(input.get_at(), 207);
+        assert_eq!(input.value_len, 213);
+
+        let mut input =
+            StringInput::with_filter_map(|c: char| if c == '?' { None } else { Some(c) });
+        input.insert_str(&pasted);
+        input.set_at(50);
+        input.insert_str("a?b");
+
+        assert_eq!(
+            input.value(),
+            format!("{}ab{}", "¿".repeat(50), "¿".repeat(50))
+        );
+        assert_eq!(input.get_at(), 52);
+        assert_eq!(input.value_len, 102);
+    }
+
+    #[test]
+    fn test_delete_range() {
+        let mut input = StringInput::default();
+        input.set_value("¿Hello, World?".into());
+
+        // cursor after the range
+        input.set_at(13);
+        input.delete_range(0..1);
+        assert_eq!(input.value(), "Hello, World?");
+        assert_eq!(input.get_at(), 12);
+
+        // cursor within the range
+        input.set_at(7);
+        input.delete_range(5..12);
+        assert_eq!(input.value(), "Hello?");
+        assert_eq!(input.get_at(), 5);
+
+        // cursor before the range
+        input.set_at(0);
+        input.delete_range(5..6);
+        assert_eq!(input.value(), "Hello");
+        assert_eq!(input.get_at(), 0);
+        assert_eq!(input.value_len, 5);
+
+        // empty and out of bounds ranges
+        input.delete_range(3..3);
+        input.delete_range(10..20);
+        assert_eq!(input.value(), "Hello");
+
+        input.set_at(5);
+        input.delete_range(3..100);
+        assert_eq!(input.value(), "Hel");
+        assert_eq!(input.get_at(), 3);
+        assert_eq!(input.value_len, 3);
+
+        input.delete_range(0..3);
+        assert_eq!(input.value(), "");
+        assert_eq!(input.get_at(), 0);
+        assert_eq!(input.value_len, 0);
+    }
+
+    #[test]
+    fn test_set_at_and_byte_offset() {
+        let mut input = StringInput::default();
+        assert_eq!(input.byte_offset_of_cursor(), 0);
+
+        input.set_value("a¿b‣".into());
+
+        let offsets = [(0, 0), (1, 1), (2, 3), (3, 4), (4, 7)];
+        for &(at, byte_offset) in offsets.iter() {
+            input.set_at(at);
+            assert_eq!(input.get_at(), at);
+            assert_eq!(input.byte_offset_of_cursor(), byte_offset);
+        }
+
+        input.set_at(10);
+        assert_eq!(input.get_at(), 4);
+        assert_eq!(input.byte_offset_of_cursor(), input.value().len());
+
+        input.set_at(2);
+        input.insert_str("‣");
+        assert_eq!(input.value(), "a¿‣b‣");
+        assert_eq!(input.byte_offset_of_cursor(), 6);
+        assert_eq!(&input.value()[input.byte_offset_of_cursor()..], "b‣");
+
+        input.set_at(0);
+        input.insert_str("x");
+        input.set_at(usize::MAX);
+        input.insert_str("y");
+        assert_eq!(input.value(), "xa¿‣b‣y");
+        assert_eq!(input.get_at(), 7);
+    }
+
     #[test]
     fn test_delete_movement() {
         let mut input = StringInput::default();
@@ -687,4 +958,45 @@ mod tests {
         layout.offset_y = 3;
         assert_eq!(input.cursor_pos(layout), (35, 4));
     }
+
+    #[test]
+    fn test_cursor_pos_wide_chars() {
+        let layout = Layout::new(0, (100, 20).into());
+        let mut input = StringInput::default();
+        input.set_value("a中b\u{301}c".into());
+        input.set_at(0);
+
+        let mut positions = vec![input.cursor_pos(layout)];
+        while input.handle_key(KeyCode::Right.into()) {
+            positions.push(input.cursor_pos(layout));
+        }
+        // The combining acute accent is skipped over along with the 'b'
+        assert_eq!(positions, [(0, 0), (1, 0), (3, 0), (4, 0), (5, 0)]);
+
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.get_at(), 2);
+        assert_eq!(input.cursor_pos(layout), (3, 0));
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert_eq!(input.cursor_pos(layout), (1, 0));
+
+        // The wide character does not fit at the end of the first line, and is moved to the next
+        let mut layout = Layout::new(2, (6, 20).into());
+        input.set_value("abc中d".into());
+        input.set_at(3);
+        assert_eq!(input.cursor_pos(layout), (5, 0));
+        input.set_at(4);
+        assert_eq!(input.cursor_pos(layout), (2, 1));
+        input.set_at(5);
+        assert_eq!(input.cursor_pos(layout), (3, 1));
+        assert_eq!(input.height(&mut layout), 2);
+        assert_eq!(layout.line_offset, 3);
+
+        // The mask is rendered instead of the wide characters
+        let layout = Layout::new(0, (100, 20).into());
+        let mut input = StringInput::default().mask('*');
+        input.set_value("中中".into());
+        input.set_at(2);
+        assert_eq!(input.cursor_pos(layout), (2, 0));
+    }
 }
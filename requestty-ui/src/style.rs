@@ -2,6 +2,8 @@
 
 use std::{fmt::Display, io};
 
+use once_cell::sync::Lazy;
+
 /// Some content with a particular style applied.
 ///
 /// See also [`write_styled`] and [`Stylize`].
@@ -57,6 +59,122 @@ impl<T: Display + ?Sized> Styled<T> {
     }
 }
 
+/// A style that can be applied to any content, without holding the content itself.
+///
+/// This is useful to configure the look of text which is rendered later, for example the hint of
+/// a [`Prompt`](crate::widgets::Prompt).
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::style::{Attributes, Color, Style};
+///
+/// let style = Style::new().fg(Color::Yellow).attributes(Attributes::BOLD);
+/// let styled = style.apply("warning");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attributes: Attributes,
+}
+
+impl Style {
+    /// Creates a new [`Style`] which does not change the look of the content
+    pub fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            attributes: Attributes::empty(),
+        }
+    }
+
+    /// Sets the foreground color
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background color
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Adds the given attributes
+    pub fn attributes(mut self, attributes: Attributes) -> Self {
+        self.attributes |= attributes;
+        self
+    }
+
+    /// Applies the style to the given content
+    pub fn apply<T: Display>(self, content: T) -> Styled<T> {
+        Styled {
+            fg: self.fg,
+            bg: self.bg,
+            attributes: self.attributes,
+            content,
+        }
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A piece of text with a particular style applied.
+///
+/// See also [`write_styled_spans`].
+///
+/// [`write_styled_spans`]: crate::backend::Backend::write_styled_spans
+pub type StyledSpan<'a> = Styled<&'a str>;
+
+/// Writes the spans one after the other, only changing the style when it differs from the
+/// previous span.
+pub(super) fn write_spans<B: crate::backend::Backend + ?Sized>(
+    spans: &[StyledSpan<'_>],
+    backend: &mut B,
+) -> io::Result<()> {
+    let mut fg = Color::Reset;
+    let mut bg = Color::Reset;
+    let mut attributes = Attributes::empty();
+
+    for span in spans {
+        let span_fg = span.fg.unwrap_or(Color::Reset);
+        if span_fg != fg {
+            backend.set_fg(span_fg)?;
+            fg = span_fg;
+        }
+
+        let span_bg = span.bg.unwrap_or(Color::Reset);
+        if span_bg != bg {
+            backend.set_bg(span_bg)?;
+            bg = span_bg;
+        }
+
+        if span.attributes != attributes {
+            backend.set_attributes(span.attributes)?;
+            attributes = span.attributes;
+        }
+
+        backend.write_all(span.content.as_bytes())?;
+    }
+
+    if fg != Color::Reset {
+        backend.set_fg(Color::Reset)?;
+    }
+    if bg != Color::Reset {
+        backend.set_bg(Color::Reset)?;
+    }
+    if !attributes.is_empty() {
+        backend.set_attributes(Attributes::empty())?;
+    }
+
+    Ok(())
+}
+
 impl<T: Display> From<T> for Styled<T> {
     fn from(content: T) -> Self {
         Self::new(content)
@@ -89,6 +207,206 @@ pub enum Color {
     Ansi(u8),
 }
 
+/// The colors which a terminal can display.
+///
+/// The `crossterm` and `termion` backends downgrade the colors they are asked to set to the
+/// closest color that is supported. The support is [detected](ColorSupport::detect) from the
+/// environment by default, but can be set with `with_color_support` on the backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorSupport {
+    /// No colors are supported, and none are set.
+    None,
+    /// Only the 16 named colors are supported.
+    Ansi16,
+    /// The 256 [`Color::Ansi`] colors are supported.
+    Ansi256,
+    /// Any [`Color::Rgb`] color is supported.
+    TrueColor,
+}
+
+static DETECTED: Lazy<ColorSupport> = Lazy::new(|| {
+    let var = |name| std::env::var(name).ok();
+    ColorSupport::from_env(
+        var("REQUESTTY_COLORS").as_deref(),
+        matches!(var("NO_COLOR"), Some(no_color) if !no_color.is_empty()),
+        var("COLORTERM").as_deref(),
+        var("TERM").as_deref(),
+    )
+});
+
+/// The colors of the 16 named colors in the order of their ANSI values, as they are commonly
+/// displayed.
+const NAMED_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (128, 0, 0)),
+    (Color::Green, (0, 128, 0)),
+    (Color::Yellow, (128, 128, 0)),
+    (Color::Blue, (0, 0, 128)),
+    (Color::Magenta, (128, 0, 128)),
+    (Color::Cyan, (0, 128, 128)),
+    (Color::Grey, (192, 192, 192)),
+    (Color::DarkGrey, (128, 128, 128)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (0, 0, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// The levels of each channel in the 6x6x6 color cube of the 256 colors.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorSupport {
+    /// Detects the color support of the terminal from the environment. It is only detected once,
+    /// and the same value is returned afterwards.
+    ///
+    /// In order of precedence:
+    ///
+    /// 1. `REQUESTTY_COLORS` forces the support if it is one of `none`, `16`, `256` or
+    ///    `truecolor`.
+    /// 2. `NO_COLOR` being set to a non-empty value gives [`None`](ColorSupport::None).
+    /// 3. `COLORTERM` being `truecolor` or `24bit` gives [`TrueColor`](ColorSupport::TrueColor).
+    /// 4. `TERM` being `dumb` gives [`None`](ColorSupport::None), and containing `256color` gives
+    ///    [`Ansi256`](ColorSupport::Ansi256). Any other `TERM` gives
+    ///    [`Ansi16`](ColorSupport::Ansi16).
+    /// 5. If `TERM` is not set, [`TrueColor`](ColorSupport::TrueColor) is assumed on Windows,
+    ///    and [`Ansi16`](ColorSupport::Ansi16) otherwise.
+    pub fn detect() -> ColorSupport {
+        *DETECTED
+    }
+
+    fn from_env(
+        force: Option<&str>,
+        no_color: bool,
+        colorterm: Option<&str>,
+        term: Option<&str>,
+    ) -> ColorSupport {
+        match force
+            .map(|force| force.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("none") => return ColorSupport::None,
+            Some("16") => return ColorSupport::Ansi16,
+            Some("256") => return ColorSupport::Ansi256,
+            Some("truecolor") => return ColorSupport::TrueColor,
+            _ => {}
+        }
+
+        if no_color {
+            return ColorSupport::None;
+        }
+
+        if let Some("truecolor") | Some("24bit") = colorterm {
+            return ColorSupport::TrueColor;
+        }
+
+        match term {
+            Some("dumb") => ColorSupport::None,
+            Some(term) if term.contains("256color") => ColorSupport::Ansi256,
+            Some(_) => ColorSupport::Ansi16,
+            None if cfg!(windows) => ColorSupport::TrueColor,
+            None => ColorSupport::Ansi16,
+        }
+    }
+
+    /// Returns the closest supported color to the given color, or `None` if no colors are
+    /// supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use requestty_ui::style::{Color, ColorSupport};
+    ///
+    /// let orange = Color::Rgb(255, 135, 0);
+    ///
+    /// assert_eq!(ColorSupport::TrueColor.downgrade(orange), Some(orange));
+    /// assert_eq!(ColorSupport::Ansi256.downgrade(orange), Some(Color::Ansi(208)));
+    /// assert_eq!(ColorSupport::Ansi16.downgrade(orange), Some(Color::LightYellow));
+    /// assert_eq!(ColorSupport::None.downgrade(orange), None);
+    /// ```
+    pub fn downgrade(self, color: Color) -> Option<Color> {
+        match (self, color) {
+            (ColorSupport::None, _) => None,
+            (ColorSupport::TrueColor, color) => Some(color),
+            (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => {
+                Some(Color::Ansi(rgb_to_ansi256(r, g, b)))
+            }
+            (ColorSupport::Ansi256, color) => Some(color),
+            (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => Some(nearest_named(r, g, b)),
+            (ColorSupport::Ansi16, Color::Ansi(i)) => {
+                let (r, g, b) = ansi256_to_rgb(i);
+                Some(nearest_named(r, g, b))
+            }
+            (ColorSupport::Ansi16, color) => Some(color),
+        }
+    }
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        v => (v - 35) / 40,
+    };
+
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+    let cube_rgb = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    // The grey ramp goes from 8 to 238 in steps of 10
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let grey_index = if average > 238 {
+        23
+    } else {
+        (average.saturating_sub(3) / 10) as u8
+    };
+    let grey_level = 8 + 10 * grey_index;
+    let grey = 232 + grey_index;
+
+    if distance((r, g, b), (grey_level, grey_level, grey_level)) < distance((r, g, b), cube_rgb) {
+        grey
+    } else {
+        cube
+    }
+}
+
+fn ansi256_to_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => NAMED_COLORS[i as usize].1,
+        16..=231 => {
+            let i = i - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (i - 232);
+            (level, level, level)
+        }
+    }
+}
+
+fn nearest_named(r: u8, g: u8, b: u8) -> Color {
+    NAMED_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .expect("there are 16 named colors")
+        .0
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
 bitflags::bitflags! {
     /// Attributes change the way a piece of text is displayed.
     pub struct Attributes: u16 {
@@ -423,3 +741,85 @@ impl<T, I: Into<Styled<T>>> Stylize<T> for I {
         styled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downgrade() {
+        let color = Color::Rgb(95, 175, 255);
+
+        assert_eq!(ColorSupport::TrueColor.downgrade(color), Some(color));
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(color),
+            Some(Color::Ansi(75))
+        );
+        assert_eq!(ColorSupport::Ansi16.downgrade(color), Some(Color::Grey));
+        assert_eq!(ColorSupport::None.downgrade(color), None);
+
+        // greys use the grey ramp
+        assert_eq!(
+            ColorSupport::Ansi256.downgrade(Color::Rgb(100, 100, 100)),
+            Some(Color::Ansi(241))
+        );
+        // 256 colors are downgraded through their rgb value
+        assert_eq!(
+            ColorSupport::Ansi16.downgrade(Color::Ansi(196)),
+            Some(Color::LightRed)
+        );
+        assert_eq!(
+            ColorSupport::Ansi16.downgrade(Color::Ansi(4)),
+            Some(Color::Blue)
+        );
+        // named colors are always supported
+        assert_eq!(
+            ColorSupport::Ansi16.downgrade(Color::Cyan),
+            Some(Color::Cyan)
+        );
+    }
+
+    #[test]
+    fn test_from_env() {
+        assert_eq!(
+            ColorSupport::from_env(None, false, None, Some("dumb")),
+            ColorSupport::None
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, false, None, Some("xterm")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, false, None, Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            ColorSupport::from_env(None, false, Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::TrueColor
+        );
+        // the level can be forced
+        assert_eq!(
+            ColorSupport::from_env(Some("16"), false, Some("truecolor"), Some("xterm")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(
+            ColorSupport::from_env(Some("TrueColor"), false, None, Some("dumb")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            ColorSupport::from_env(Some("256"), true, None, Some("xterm")),
+            ColorSupport::Ansi256
+        );
+        // colors are only turned off explicitly
+        assert_eq!(
+            ColorSupport::from_env(None, true, Some("truecolor"), Some("xterm")),
+            ColorSupport::None
+        );
+        if !cfg!(windows) {
+            assert_eq!(
+                ColorSupport::from_env(None, false, None, None),
+                ColorSupport::Ansi16
+            );
+        }
+    }
+}
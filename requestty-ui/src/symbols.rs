@@ -49,13 +49,60 @@ pub fn set(new: SymbolSet) {
     *SET.lock().expect("symbol set poisoned") = new;
 }
 
+/// Whether the terminal is likely to render the [`UNICODE`] symbol set.
+///
+/// It is detected from the `$TERM` environment variable, and is `false` if it is unset or names a
+/// terminal with a limited character set, such as `dumb`, `linux` or `vt100`. On Windows, where
+/// `$TERM` is usually unset, Windows Terminal is detected instead.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::symbols;
+/// # use requestty_ui::symbols;
+///
+/// if !symbols::supports_unicode() {
+///     symbols::set(symbols::ASCII);
+/// }
+/// ```
+pub fn supports_unicode() -> bool {
+    match std::env::var("TERM") {
+        Ok(term) => term_supports_unicode(&term),
+        Err(_) => cfg!(windows) && std::env::var_os("WT_SESSION").is_some(),
+    }
+}
+
+fn term_supports_unicode(term: &str) -> bool {
+    !matches!(term, "" | "dumb" | "linux" | "ansi" | "cons25") && !term.starts_with("vt")
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// The various special symbols used by the prompts during rendering.
+///
+/// New symbols may be added in the future, so a custom set is made by changing the symbols of one
+/// of the default sets.
+///
+/// ```
+/// # #[cfg(feature = "ignore this line for doc test as requestty_ui should be used")]
+/// use requestty::symbols;
+/// # use requestty_ui::symbols;
+///
+/// let mut set = symbols::ASCII;
+/// set.pointer = '*';
+/// symbols::set(set);
+/// ```
+#[non_exhaustive]
 pub struct SymbolSet {
     /// Used to point to a special item.
     ///
     /// For example, this is used in the various list prompts to show the currently hovered item.
     pub pointer: char,
+    /// Used to point to a special item from its right.
+    ///
+    /// For example, this is used in the select prompt when the pointer is shown on the right of
+    /// the hovered item.
+    pub pointer_left: char,
     /// Generic decoration mark which points to the right.
     ///
     /// For example, this is used in the prompts when there is no hint between the question and the
@@ -73,6 +120,11 @@ pub struct SymbolSet {
     ///
     /// For example, this is used by prompts if validation fails.
     pub cross: char,
+    /// Repeated to draw a line which separates items.
+    ///
+    /// For example, this is used by the list prompts to draw the default separator across the
+    /// line.
+    pub separator: char,
     /// Character for the top right corner of a box.
     pub box_top_right: char,
     /// Character for the top left corner of a box.
@@ -91,27 +143,31 @@ pub struct SymbolSet {
 ///
 /// It is composed of unicode characters and so may not be supported by all terminal emulators.
 pub const UNICODE: SymbolSet = SymbolSet {
-    /// `'❯' U+276F`
+    // `'❯' U+276F`
     pointer: '❯',
-    /// `'›' U+203A`
+    // `'❮' U+276E`
+    pointer_left: '❮',
+    // `'›' U+203A`
     arrow: '›',
-    /// `'✔' U+2714`
+    // `'✔' U+2714`
     completed: '✔',
-    /// `'·' U+00B7`
+    // `'·' U+00B7`
     middle_dot: '·',
-    /// `'✖' U+2716`
+    // `'✖' U+2716`
     cross: '✖',
-    /// `'┐' U+2510`
+    // `'─' U+2500`
+    separator: '─',
+    // `'┐' U+2510`
     box_top_right: '┐',
-    /// `'┌' U+250C`
+    // `'┌' U+250C`
     box_top_left: '┌',
-    /// `'┘' U+2518`
+    // `'┘' U+2518`
     box_bottom_right: '┘',
-    /// `'└' U+2514`
+    // `'└' U+2514`
     box_bottom_left: '└',
-    /// `'─' U+2500`
+    // `'─' U+2500`
     box_horizontal: '─',
-    /// `'│' U+2502`
+    // `'│' U+2502`
     box_vertical: '│',
 };
 
@@ -121,10 +177,12 @@ pub const UNICODE: SymbolSet = SymbolSet {
 /// good.
 pub const ASCII: SymbolSet = SymbolSet {
     pointer: '>',
+    pointer_left: '<',
     arrow: '>',
     completed: '?',
     middle_dot: '~',
     cross: 'x',
+    separator: '-',
     box_top_right: '.',
     box_top_left: '.',
     box_bottom_right: '\'',
@@ -132,3 +190,19 @@ pub const ASCII: SymbolSet = SymbolSet {
     box_horizontal: '-',
     box_vertical: '|',
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_term_supports_unicode() {
+        assert!(term_supports_unicode("xterm-256color"));
+        assert!(term_supports_unicode("screen"));
+        assert!(term_supports_unicode("alacritty"));
+        assert!(!term_supports_unicode("dumb"));
+        assert!(!term_supports_unicode("linux"));
+        assert!(!term_supports_unicode("vt100"));
+        assert!(!term_supports_unicode(""));
+    }
+}
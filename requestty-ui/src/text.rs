@@ -1,9 +1,25 @@
+use std::borrow::Cow;
+
+use textwrap::core::display_width;
+
 use crate::{backend, layout::Layout, Widget};
 
+/// Where the text is cut short when it is [truncated](Text::truncate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncateMode {
+    /// The start of the text is removed, keeping the end. For example, `…/src/main.rs`.
+    Start,
+    /// The middle of the text is removed, keeping the start and the end. For example,
+    /// `/home/…/main.rs`.
+    Middle,
+    /// The end of the text is removed, keeping the start. For example, `/home/user/…`.
+    End,
+}
+
 /// A string that can render over multiple lines.
 ///
 /// If you need to render a single line of text or you don't want the text to wrap, use the [`Widget`]
-/// implementation on [`str`].
+/// implementation on [`str`], or [`truncate`](Text::truncate) the text.
 #[derive(Debug, Clone)]
 pub struct Text<S> {
     /// The text to render.
@@ -17,6 +33,8 @@ pub struct Text<S> {
     wrapped: String,
     line_offset: u16,
     width: u16,
+    truncate: Option<(TruncateMode, char)>,
+    tab_width: usize,
 }
 
 impl<S: PartialEq> PartialEq for Text<S> {
@@ -35,9 +53,53 @@ impl<S: AsRef<str>> Text<S> {
             wrapped: String::new(),
             width: 0,
             line_offset: 0,
+            truncate: None,
+            tab_width: 8,
         }
     }
 
+    /// Set the number of columns between tab stops. Defaults to 8.
+    ///
+    /// Tabs are expanded to spaces up to the next tab stop, since terminals may not agree on how
+    /// to render them. The tab stops of the first line take the line offset into account.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the `tab_width` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use requestty_ui::widgets::Text;
+    ///
+    /// let text = Text::new("name\tvalue").tab_width(4);
+    /// ```
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        assert!(tab_width > 0, "tab width must be at least 1");
+        self.tab_width = tab_width;
+        self.force_recompute();
+        self
+    }
+
+    /// Truncate each line of the text to fit in the width instead of wrapping it.
+    ///
+    /// Lines which are too long are cut short at the position given by `mode`, and `indicator` is
+    /// shown in place of the removed text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use requestty_ui::widgets::{Text, TruncateMode};
+    ///
+    /// let text = Text::new("/home/user/projects/requestty/src/question/mod.rs")
+    ///     .truncate(TruncateMode::Middle, '…');
+    /// ```
+    pub fn truncate(mut self, mode: TruncateMode, indicator: char) -> Self {
+        self.truncate = Some((mode, indicator));
+        self.force_recompute();
+        self
+    }
+
     /// The computed lines are cached between renders, and are only recomputed if the layout changes.
     /// This will force a recomputation even if the layout is the same. This is useful if you need
     /// to change the text.
@@ -50,7 +112,12 @@ impl<S: AsRef<str>> Text<S> {
         let width = layout.available_width();
 
         if self.width != width || self.line_offset != layout.line_offset {
-            self.wrapped = fill(self.text.as_ref(), layout);
+            let text = expand_tabs(self.text.as_ref(), self.tab_width, layout.line_offset);
+
+            self.wrapped = match self.truncate {
+                Some((mode, indicator)) => truncate(&text, layout, mode, indicator),
+                None => fill(&text, layout),
+            };
             self.width = width;
             self.line_offset = layout.line_offset;
         }
@@ -131,6 +198,41 @@ impl<S: AsRef<str>> From<S> for Text<S> {
     }
 }
 
+/// Replaces each tab with spaces up to the next multiple of `tab_width` columns. The first line
+/// starts at the line offset.
+fn expand_tabs(text: &str, tab_width: usize, line_offset: u16) -> Cow<'_, str> {
+    if !text.contains('\t') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut expanded = String::with_capacity(text.len());
+
+    for (i, line) in text.split('\n').enumerate() {
+        let mut column = 0;
+
+        if i == 0 {
+            column = line_offset as usize;
+        } else {
+            expanded.push('\n');
+        }
+
+        for (j, part) in line.split('\t').enumerate() {
+            if j != 0 {
+                let spaces = tab_width - column % tab_width;
+                for _ in 0..spaces {
+                    expanded.push(' ');
+                }
+                column += spaces;
+            }
+
+            expanded.push_str(part);
+            column += display_width(part);
+        }
+    }
+
+    Cow::Owned(expanded)
+}
+
 // 200 spaces to remove allocation for indent
 static SPACES: &str = "                                                                                                                                                                                                        ";
 
@@ -158,6 +260,89 @@ fn fill(text: &str, layout: Layout) -> String {
     text
 }
 
+/// Truncates each line of the text to fit in the layout. The first line starts at the line offset.
+fn truncate(text: &str, layout: Layout, mode: TruncateMode, indicator: char) -> String {
+    let mut truncated = String::with_capacity(text.len());
+    let mut width = layout.line_width() as usize;
+
+    for (i, line) in text.lines().enumerate() {
+        if i != 0 {
+            truncated.push('\n');
+            width = layout.available_width() as usize;
+        }
+
+        truncate_line(line, width, mode, indicator, &mut truncated);
+    }
+
+    truncated
+}
+
+fn truncate_line(
+    line: &str,
+    width: usize,
+    mode: TruncateMode,
+    indicator: char,
+    truncated: &mut String,
+) {
+    if display_width(line) <= width {
+        truncated.push_str(line);
+        return;
+    }
+
+    let indicator_width = display_width(indicator.encode_utf8(&mut [0; 4]));
+    if indicator_width > width {
+        return;
+    }
+
+    let width = width - indicator_width;
+
+    match mode {
+        TruncateMode::Start => {
+            truncated.push(indicator);
+            truncated.push_str(suffix(line, width));
+        }
+        TruncateMode::Middle => {
+            let end_width = width / 2;
+            truncated.push_str(prefix(line, width - end_width));
+            truncated.push(indicator);
+            truncated.push_str(suffix(line, end_width));
+        }
+        TruncateMode::End => {
+            truncated.push_str(prefix(line, width));
+            truncated.push(indicator);
+        }
+    }
+}
+
+/// The longest start of the line which fits in the width.
+fn prefix(line: &str, width: usize) -> &str {
+    let mut end = 0;
+
+    for (i, c) in line.char_indices() {
+        let next = i + c.len_utf8();
+        if display_width(&line[..next]) > width {
+            break;
+        }
+        end = next;
+    }
+
+    &line[..end]
+}
+
+/// The longest end of the line which fits in the width.
+fn suffix(line: &str, width: usize) -> &str {
+    let mut start = line.len();
+
+    for (i, _) in line.char_indices().rev() {
+        if display_width(&line[i..]) > width {
+            break;
+        }
+        start = i;
+    }
+
+    &line[start..]
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{backend::TestBackend, test_consts::*};
@@ -245,4 +430,76 @@ mod tests {
         crate::assert_backend_snapshot!(backend);
         assert_eq!(layout, Layout::new(0, size).with_offset(10, 16));
     }
+
+    #[test]
+    fn test_tab_width() {
+        assert_eq!(expand_tabs("a\tbc\tdefg\th", 4, 0), "a   bc  defg    h");
+        // the first line starts at the line offset
+        assert_eq!(expand_tabs("a\tb\n\tc", 4, 2), "a b\n    c");
+
+        let mut text = Text::new("key\tvalue\tother value").tab_width(4);
+
+        assert_eq!(text.height(&mut Layout::new(0, (80, 20).into())), 1);
+        assert_eq!(text.wrapped, "key value   other value");
+        assert_eq!(display_width(&text.wrapped), 23);
+
+        // the expanded width is used to wrap the text
+        assert_eq!(text.height(&mut Layout::new(0, (16, 20).into())), 2);
+        assert_eq!(text.wrapped, "key value\nother value");
+    }
+
+    #[test]
+    fn test_truncate() {
+        const PATH: &str = "/home/user/projects/requestty/src/question/mod.rs";
+
+        fn test(mode: TruncateMode, expected: &str) {
+            let mut layout = Layout::new(0, (20, 20).into());
+            let mut text = Text::new(PATH).truncate(mode, '…');
+
+            assert_eq!(text.height(&mut layout), 1);
+            assert_eq!(text.wrapped, expected);
+            assert_eq!(display_width(&text.wrapped), 20);
+        }
+
+        test(TruncateMode::Start, "…src/question/mod.rs");
+        test(TruncateMode::Middle, "/home/user…on/mod.rs");
+        test(TruncateMode::End, "/home/user/projects…");
+
+        // the first line starts after the line offset
+        let mut text = Text::new(PATH).truncate(TruncateMode::End, '~');
+        text.height(&mut Layout::new(10, (20, 20).into()));
+        assert_eq!(text.wrapped, "/home/use~");
+
+        // text which fits is not changed
+        let mut text = Text::new("src/main.rs").truncate(TruncateMode::Middle, '…');
+        text.height(&mut Layout::new(0, (20, 20).into()));
+        assert_eq!(text.wrapped, "src/main.rs");
+    }
+
+    #[test]
+    fn test_render_to_string() {
+        let mut text = Text::new("Hello world, this wraps");
+        assert_eq!(
+            crate::widgets::render_to_string(&mut text, Layout::new(0, (12, 10).into())),
+            "Hello world,\nthis wraps"
+        );
+
+        // The layout offsets are respected
+        let layout = Layout::new(4, (12, 10).into()).with_offset(2, 1);
+        assert_eq!(
+            crate::widgets::render_to_string(&mut text, layout),
+            "\n      Hello\n  world,\n  this wraps"
+        );
+
+        let mut text = Text::new(LOREM);
+        let rendered = crate::widgets::render_to_string(&mut text, Layout::new(0, (40, 20).into()));
+        let mut backend = TestBackend::new((40, 20).into());
+        text.render(&mut Layout::new(0, (40, 20).into()), &mut backend)
+            .unwrap();
+        assert_eq!(rendered, backend.to_plain_string());
+        assert_eq!(
+            rendered.lines().count(),
+            text.max_height(Layout::new(0, (40, 20).into())) as usize
+        );
+    }
 }
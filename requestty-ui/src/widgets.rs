@@ -7,10 +7,10 @@ use textwrap::{core::Fragment, WordSeparator};
 use crate::{backend::Backend, events::KeyEvent, layout::Layout};
 
 pub use crate::char_input::CharInput;
-pub use crate::prompt::{Delimiter, Prompt};
+pub use crate::prompt::{Delimiter, Prompt, Separator};
 pub use crate::select::{List, Select};
 pub use crate::string_input::StringInput;
-pub use crate::text::Text;
+pub use crate::text::{Text, TruncateMode};
 
 /// The default type for `filter_map` in [`StringInput`] and [`CharInput`]
 pub type FilterMapChar = fn(char) -> Option<char>;
@@ -128,3 +128,45 @@ impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
         false
     }
 }
+
+/// Renders the widget to a [`TestBackend`] the size of the layout, and returns the text it would
+/// show on the screen.
+///
+/// The widget is rendered following the [render cycle](Widget#render-cycle) starting at the
+/// offsets of the layout. The result is the same as [`TestBackend::to_plain_string`], so it has
+/// no styling, and the trailing whitespace of every line as well as the trailing empty lines are
+/// removed. This is useful for logging, or to compare what was rendered in tests without a
+/// snapshot.
+///
+/// [`TestBackend`]: crate::backend::TestBackend
+/// [`TestBackend::to_plain_string`]: crate::backend::TestBackend::to_plain_string
+///
+/// # Panics
+///
+/// It panics if the widget returns an error while rendering. Writing to a [`TestBackend`] never
+/// fails, so this can only happen if the widget itself fails.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{layout::Layout, widgets::{self, Text}};
+///
+/// let mut text = Text::new("Hello world");
+/// let rendered = widgets::render_to_string(&mut text, Layout::new(0, (8, 5).into()));
+///
+/// assert_eq!(rendered, "Hello\nworld");
+/// ```
+pub fn render_to_string<W: Widget + ?Sized>(widget: &mut W, layout: Layout) -> String {
+    let size = crate::backend::Size {
+        width: layout.width,
+        height: layout.height,
+    };
+    let mut backend = crate::backend::TestBackend::new_with_layout(size, layout);
+
+    widget.height(&mut layout.clone());
+    widget
+        .render(&mut layout.clone(), &mut backend)
+        .expect("rendering to a `TestBackend` cannot fail");
+
+    backend.to_plain_string()
+}
@@ -1,5 +1,8 @@
 use std::{
-    collections::hash_map::{Entry, HashMap, IntoIter},
+    collections::{
+        hash_map::{Entry, HashMap, IntoIter},
+        HashSet,
+    },
     hash::Hash,
     iter::FromIterator,
     ops::{Deref, DerefMut},
@@ -7,10 +10,18 @@ use std::{
 
 use crate::question::OrderSelectItem;
 
+/// The text shown in place of a secret answer.
+const REDACTED: &str = "****";
+
 /// The different answer types that can be returned by the [`Question`]s
 ///
 /// [`Question`]: crate::question::Question
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub enum Answer {
     /// Strings will be returned by [`input`], [`password`] and [`editor`].
     ///
@@ -44,9 +55,21 @@ pub enum Answer {
     /// [`multi_select`]: crate::question::Question::multi_select
     /// [`order_select`]: crate::question::Question::order_select
     ListItems(Vec<ListItem>),
+    /// Lists of answers will be returned by [`repeat`], with an answer for each time the question
+    /// was answered.
+    ///
+    /// [`repeat`]: crate::question::Question::repeat
+    List(Vec<Answer>),
 }
 
 impl Answer {
+    /// The answer shown in place of a [`secret`] answer.
+    ///
+    /// [`secret`]: crate::question::InputBuilder::secret
+    pub(crate) fn redacted() -> Self {
+        Self::String(REDACTED.to_owned())
+    }
+
     /// Returns `true` if the answer is [`Answer::String`].
     pub fn is_string(&self) -> bool {
         matches!(self, Self::String(..))
@@ -110,6 +133,12 @@ impl Answer {
         }
     }
 
+    /// Returns the key of the [`ExpandItem`] if it is [`Answer::ExpandItem`], otherwise returns
+    /// [`None`].
+    pub fn expand_key(&self) -> Option<&str> {
+        self.as_expand_item().map(|item| &*item.key)
+    }
+
     /// Returns `true` if the answer is [`Answer::Int`].
     pub fn is_int(&self) -> bool {
         matches!(self, Self::Int(..))
@@ -193,6 +222,27 @@ impl Answer {
             _ => Err(self),
         }
     }
+
+    /// Returns `true` if the answer is [`Answer::List`].
+    pub fn is_list(&self) -> bool {
+        matches!(self, Self::List(..))
+    }
+
+    /// Returns [`Some`] if it is [`Answer::List`], otherwise returns [`None`].
+    pub fn as_list(&self) -> Option<&[Answer]> {
+        match self {
+            Self::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Ok(Vec<Answer>)` if it is one, otherwise returns itself as an [`Err`].
+    pub fn try_into_list(self) -> Result<Vec<Answer>, Self> {
+        match self {
+            Self::List(v) => Ok(v),
+            _ => Err(self),
+        }
+    }
 }
 
 macro_rules! impl_from {
@@ -227,6 +277,11 @@ impl From<Vec<OrderSelectItem>> for Answer {
 /// [`select`]: crate::question::Question::select
 /// [`raw_select`]: crate::question::Question::raw_select
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct ListItem {
     /// The index of the choice
     pub index: usize,
@@ -234,6 +289,33 @@ pub struct ListItem {
     pub text: String,
 }
 
+impl ListItem {
+    /// The index of the "none of the above" choice added by [`with_none`].
+    ///
+    /// [`with_none`]: crate::question::SelectBuilder::with_none
+    pub const NONE_INDEX: usize = usize::MAX;
+
+    /// Returns `true` if the item is the "none of the above" choice added by [`with_none`].
+    ///
+    /// [`with_none`]: crate::question::SelectBuilder::with_none
+    pub fn is_none(&self) -> bool {
+        self.index == Self::NONE_INDEX
+    }
+
+    /// The index of the custom answer typed after choosing the choice added by [`with_other`].
+    ///
+    /// [`with_other`]: crate::question::SelectBuilder::with_other
+    pub const OTHER_INDEX: usize = usize::MAX - 1;
+
+    /// Returns `true` if the item is the custom answer typed after choosing the choice added by
+    /// [`with_other`]. The text of the item is the typed answer.
+    ///
+    /// [`with_other`]: crate::question::SelectBuilder::with_other
+    pub fn is_other(&self) -> bool {
+        self.index == Self::OTHER_INDEX
+    }
+}
+
 impl<I: Into<String>> From<(usize, I)> for ListItem {
     fn from((index, text): (usize, I)) -> Self {
         Self {
@@ -259,17 +341,22 @@ impl From<OrderSelectItem> for ListItem {
 /// [`Choice`]: crate::Choice
 /// [`expand`]: crate::question::Question::expand
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct ExpandItem {
     /// The key associated with the choice
-    pub key: char,
+    pub key: String,
     /// The content of the choice -- it is what was displayed to the user
     pub text: String,
 }
 
-impl<I: Into<String>> From<(char, I)> for ExpandItem {
-    fn from((key, text): (char, I)) -> Self {
+impl<K: Into<String>, I: Into<String>> From<(K, I)> for ExpandItem {
+    fn from((key, text): (K, I)) -> Self {
         Self {
-            key,
+            key: key.into(),
             text: text.into(),
         }
     }
@@ -278,14 +365,70 @@ impl<I: Into<String>> From<(char, I)> for ExpandItem {
 /// A collections of answers of previously asked [`Question`]s.
 ///
 /// [`Question`]: crate::question::Question
-#[derive(Default, Clone, PartialEq)]
+#[derive(Default, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate", transparent)
+)]
 pub struct Answers {
     answers: HashMap<String, Answer>,
+    /// The order the answers were first inserted in. It is boxed as `Answers` is part of
+    /// `PartialAnswersError`, which should stay small.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    order: Box<InsertionOrder>,
+    /// The names of the answers which are redacted when shown, because they were given to a
+    /// `secret` question.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    secret: Vec<String>,
+}
+
+/// The names of answers in the order they were first inserted.
+///
+/// Answers inserted through the underlying `HashMap` of [`Answers`] are not tracked, and answers
+/// removed through it are not removed from here, so only the names still in the map should be
+/// used.
+#[derive(Default, Clone)]
+struct InsertionOrder {
+    names: Vec<String>,
+    /// The same names as `names`, so that each name is only added once.
+    seen: HashSet<String>,
+}
+
+impl InsertionOrder {
+    fn push(&mut self, name: &str) {
+        if !self.seen.contains(name) {
+            self.seen.insert(name.to_owned());
+            self.names.push(name.to_owned());
+        }
+    }
+
+    fn remove(&mut self, name: &str) {
+        if self.seen.remove(name) {
+            self.names.retain(|order_name| order_name != name);
+        }
+    }
 }
 
 impl std::fmt::Debug for Answers {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.answers.fmt(f)
+        let redacted = Answer::redacted();
+
+        f.debug_map()
+            .entries(self.answers.iter().map(|(name, answer)| {
+                if self.is_secret(name) {
+                    (name, &redacted)
+                } else {
+                    (name, answer)
+                }
+            }))
+            .finish()
+    }
+}
+
+impl PartialEq for Answers {
+    fn eq(&self, other: &Self) -> bool {
+        self.answers == other.answers
     }
 }
 
@@ -297,38 +440,181 @@ impl Answers {
                 *entry = answer;
                 entry
             }
-            Entry::Vacant(entry) => entry.insert(answer),
+            Entry::Vacant(entry) => {
+                self.order.push(entry.key());
+                entry.insert(answer)
+            }
+        }
+    }
+
+    /// Removes the answer with the given name, along with its order and whether it is secret.
+    pub(crate) fn remove(&mut self, name: &str) -> Option<Answer> {
+        self.order.remove(name);
+        self.set_secret(name, false);
+        self.answers.remove(name)
+    }
+
+    /// Marks whether the answer with the given name is redacted when shown.
+    pub(crate) fn set_secret(&mut self, name: &str, secret: bool) {
+        let index = self
+            .secret
+            .iter()
+            .position(|secret_name| secret_name == name);
+
+        match (index, secret) {
+            (None, true) => self.secret.push(name.to_owned()),
+            (Some(index), false) => {
+                self.secret.swap_remove(index);
+            }
+            _ => {}
+        }
+    }
+
+    fn is_secret(&self, name: &str) -> bool {
+        self.secret.iter().any(|secret_name| secret_name == name)
+    }
+
+    /// Returns a copy of the answers where the secret answers are redacted.
+    pub(crate) fn redacted(&self) -> Self {
+        let mut answers = self.clone();
+
+        for name in &self.secret {
+            if let Some(answer) = answers.answers.get_mut(name) {
+                *answer = Answer::redacted();
+            }
+        }
+
+        answers
+    }
+
+    /// Returns a compact recap of all the answers on a single line, such as
+    /// `name: Alice | age: 30 | color: blue`.
+    ///
+    /// The answers are in the order they were given, followed by any answers added directly to
+    /// the underlying `HashMap` sorted by name. The items of a [`ListItems`] or [`List`] answer are
+    /// joined by commas. The answers to [`secret`] questions are shown as `****`.
+    ///
+    /// [`ListItems`]: Answer::ListItems
+    /// [`List`]: Answer::List
+    /// [`secret`]: crate::question::InputBuilder::secret
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, ListItem};
+    ///
+    /// let answers: Answers = vec![
+    ///     ("name".to_owned(), Answer::String("Alice".to_owned())),
+    ///     ("age".to_owned(), Answer::Int(30)),
+    ///     (
+    ///         "color".to_owned(),
+    ///         Answer::ListItem(ListItem {
+    ///             index: 2,
+    ///             text: "blue".to_owned(),
+    ///         }),
+    ///     ),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(answers.summary_line(), "name: Alice | age: 30 | color: blue");
+    /// ```
+    pub fn summary_line(&self) -> String {
+        let mut names: Vec<_> = self
+            .order
+            .names
+            .iter()
+            .filter(|name| self.answers.contains_key(*name))
+            .collect();
+
+        let ordered = names.len();
+        names.extend(
+            self.answers
+                .keys()
+                .filter(|name| !self.order.seen.contains(*name)),
+        );
+        names[ordered..].sort();
+
+        let mut summary = String::new();
+
+        for name in names {
+            if !summary.is_empty() {
+                summary.push_str(" | ");
+            }
+            summary.push_str(name);
+            summary.push_str(": ");
+
+            if self.is_secret(name) {
+                summary.push_str(REDACTED);
+                continue;
+            }
+
+            push_summary(&mut summary, &self.answers[name]);
+        }
+
+        summary
+    }
+}
+
+/// Writes the answer as it is shown in [`Answers::summary_line`].
+fn push_summary(summary: &mut String, answer: &Answer) {
+    match *answer {
+        Answer::String(ref s) => summary.push_str(s),
+        Answer::ListItem(ref item) => summary.push_str(&item.text),
+        Answer::ExpandItem(ref item) => summary.push_str(&item.text),
+        Answer::Int(i) => summary.push_str(&i.to_string()),
+        Answer::Float(f) => summary.push_str(&f.to_string()),
+        Answer::Bool(b) => summary.push_str(&b.to_string()),
+        Answer::ListItems(ref items) => {
+            let texts: Vec<_> = items.iter().map(|item| &*item.text).collect();
+            summary.push_str(&texts.join(", "));
+        }
+        Answer::List(ref items) => {
+            for (i, item) in items.iter().enumerate() {
+                if i != 0 {
+                    summary.push_str(", ");
+                }
+                push_summary(summary, item);
+            }
         }
     }
 }
 
 impl From<HashMap<String, Answer>> for Answers {
     fn from(answers: HashMap<String, Answer>) -> Self {
-        Self { answers }
+        Self {
+            answers,
+            order: Box::default(),
+            secret: Vec::new(),
+        }
     }
 }
 
 impl FromIterator<(String, Answer)> for Answers {
     fn from_iter<T: IntoIterator<Item = (String, Answer)>>(iter: T) -> Self {
-        Self {
-            answers: iter.into_iter().collect(),
-        }
+        let mut answers = Self::default();
+        answers.extend(iter);
+        answers
     }
 }
 
 impl Extend<(String, Answer)> for Answers {
     fn extend<T: IntoIterator<Item = (String, Answer)>>(&mut self, iter: T) {
-        self.answers.extend(iter)
+        for (name, answer) in iter {
+            self.insert(name, answer);
+        }
     }
 
     #[cfg(nightly)]
-    fn extend_one(&mut self, item: (String, Answer)) {
-        self.answers.extend_one(item);
+    fn extend_one(&mut self, (name, answer): (String, Answer)) {
+        self.insert(name, answer);
     }
 
     #[cfg(nightly)]
     fn extend_reserve(&mut self, additional: usize) {
-        self.answers.extend_reserve(additional)
+        self.answers.extend_reserve(additional);
+        self.order.names.extend_reserve(additional);
+        self.order.seen.extend_reserve(additional);
     }
 }
 
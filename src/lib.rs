@@ -115,8 +115,30 @@
 //! - `termion`: Enabling this feature will use the [`termion`](https://crates.io/crates/termion)
 //!   library for terminal interactions such as drawing and receiving events.
 //!
+//! - `tracing`: Enabling this feature will emit [`tracing`](https://crates.io/crates/tracing)
+//!   events with the target `requestty` at the `DEBUG` level. Every question is asked in a
+//!   `question` span with its `name` as a field. Apart from the `prompt.*` events emitted by
+//!   [`requestty-ui`](https://docs.rs/requestty-ui), the following events are emitted:
+//!   - `question.answered`: The question was answered.
+//!   - `question.skip`: The question was not answered. The `reason` field is one of `answered`
+//!     (it was already answered), `when` or `prompt` (the prompt was skipped, for example with
+//!     `Esc`).
+//!   - `question.error`: Asking the question resulted in an error, given in the `error` field.
+//!
+//! - `unicode-normalization`: Enabling this feature will allow normalizing the answers to
+//!   [`input`] questions to a consistent unicode normalization form with
+//!   `InputBuilder::normalize`.
+//!
+//! - `serde`: Enabling this feature will implement [`serde`](https://crates.io/crates/serde)'s
+//!   `Serialize` and `Deserialize` for [`Answers`] and [`Transcript`], so that sessions recorded
+//!   with [`PromptModule::record`] can be saved and replayed.
+//!
+//! - `json`: Enabling this feature enables `serde`, and allows writing the answers as JSON as they
+//!   are given with `PromptModule::stream_to`.
+//!
 //! [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
 //! [auto completions]: crate::question::InputBuilder::auto_complete
+//! [`input`]: crate::Question::input
 //!
 //! # Examples
 //!
@@ -150,6 +172,7 @@
 mod answer;
 mod prompt_module;
 pub mod question;
+mod transcript;
 
 use ui::{backend::Backend, events::EventIterator};
 
@@ -219,8 +242,12 @@ use ui::{backend::Backend, events::EventIterator};
 pub use r#macro::questions;
 
 pub use answer::{Answer, Answers, ExpandItem, ListItem};
-pub use prompt_module::PromptModule;
+pub use prompt_module::{PartialAnswersError, PromptModule};
 pub use question::{Choice::Choice, Choice::DefaultSeparator, Choice::Separator, Question};
+pub use transcript::{Transcript, TranscriptKey, TranscriptQuestion};
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+pub use ui::terminal_size;
 pub use ui::{symbols, ErrorKind, OnEsc, Result};
 
 /// A module that re-exports all the things required for writing custom [`Prompt`]s.
@@ -231,11 +258,16 @@ pub mod prompt {
     pub use ui::{
         backend::{self, Backend},
         events::{self, EventIterator},
-        style,
+        layout, style,
     };
 }
 
 /// Prompt all the questions in the given iterator, with the default [`Backend`] and [`EventIterator`].
+///
+/// # Errors
+///
+/// This returns an error if a question which would be asked has the same name as a previously
+/// answered question. See [`PromptModule::allow_duplicate_names`] for more.
 #[cfg(any(feature = "crossterm", feature = "termion"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
 pub fn prompt<'a, Q>(questions: Q) -> Result<Answers>
@@ -247,9 +279,15 @@ where
 
 /// Prompt the given question, with the default [`Backend`] and [`EventIterator`].
 ///
-/// # Panics
+/// # Errors
 ///
-/// This will panic if `when` on the [`Question`] prevents the question from being asked.
+/// This returns an [`IoError`] of kind [`InvalidInput`] if the question does not produce an
+/// answer, for example if `when` on the [`Question`] prevents it from being asked, or if it is a
+/// [`note`].
+///
+/// [`IoError`]: ErrorKind::IoError
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+/// [`note`]: Question::note
 #[cfg(any(feature = "crossterm", feature = "termion"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
 pub fn prompt_one<'a, I: Into<Question<'a>>>(question: I) -> Result<Answer> {
@@ -261,6 +299,11 @@ pub fn prompt_one<'a, I: Into<Question<'a>>>(question: I) -> Result<Answer> {
 }
 
 /// Prompt all the questions in the given iterator, with the given [`Backend`] and [`EventIterator`].
+///
+/// # Errors
+///
+/// This returns an error if a question which would be asked has the same name as a previously
+/// answered question. See [`PromptModule::allow_duplicate_names`] for more.
 pub fn prompt_with<'a, Q, B, E>(questions: Q, backend: &mut B, events: &mut E) -> Result<Answers>
 where
     Q: IntoIterator<Item = Question<'a>>,
@@ -272,16 +315,27 @@ where
 
 /// Prompt the given question, with the given [`Backend`] and [`EventIterator`].
 ///
-/// # Panics
+/// # Errors
 ///
-/// This will panic if `when` on the [`Question`] prevents the question from being asked.
+/// This returns an [`IoError`] of kind [`InvalidInput`] if the question does not produce an
+/// answer, for example if `when` on the [`Question`] prevents it from being asked, or if it is a
+/// [`note`].
+///
+/// [`IoError`]: ErrorKind::IoError
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+/// [`note`]: Question::note
 pub fn prompt_one_with<'a, Q, B, E>(question: Q, backend: &mut B, events: &mut E) -> Result<Answer>
 where
     Q: Into<Question<'a>>,
     B: Backend,
     E: EventIterator,
 {
-    let ans = question.into().ask(&Answers::default(), backend, events)?;
-
-    Ok(ans.expect("The question wasn't asked").1)
+    match question.into().ask(&Answers::default(), backend, events)? {
+        Some((_, ans)) => Ok(ans),
+        None => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The question wasn't asked",
+        )
+        .into()),
+    }
 }
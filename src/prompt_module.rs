@@ -1,17 +1,55 @@
+#[cfg(feature = "json")]
+use std::{cell::RefCell, rc::Rc};
+use std::{collections::HashSet, fmt, io};
+
 use ui::{backend::Backend, events::EventIterator};
 
-use crate::{Answer, Answers, Question};
+use crate::{transcript::Recorder, Answer, Answers, ErrorKind, Question, Transcript};
 
 /// A collection of questions and answers for previously answered questions.
 ///
 /// Unlike [`prompt`], this allows you to control how many questions you want to ask, and ask with
 /// previous answers as well.
 ///
+/// Questions in a `PromptModule` should have unique names, since a later question with the same
+/// name would either be skipped or overwrite the answer of an earlier one. Reaching a question
+/// which would be asked (based on its `when`) with the same name as a previously answered question
+/// of the module returns an error, unless [`allow_duplicate_names`] is set. Questions which are
+/// mutually exclusive through `when` can still share a name, and questions which are asked again
+/// with `ask_if_answered` are allowed.
+///
 /// [`prompt`]: crate::prompt()
+/// [`allow_duplicate_names`]: PromptModule::allow_duplicate_names
 #[derive(Debug, Clone, PartialEq)]
 pub struct PromptModule<Q> {
     questions: Q,
     answers: Answers,
+    /// The names of the questions answered so far.
+    names: HashSet<String>,
+    allow_duplicate_names: bool,
+    /// The names of the questions to skip regardless of their options.
+    force_skip: HashSet<String>,
+    /// The names of the questions to ask regardless of their options.
+    force_ask: HashSet<String>,
+    confirm_on_interrupt: bool,
+    tab_navigation: bool,
+    /// Whether text prompts are answered from lines of stdin if it is piped.
+    piped_stdin: bool,
+    /// The title of the terminal window, which is followed by the current step.
+    title: Option<String>,
+    /// Whether the title of the terminal window has been changed and needs to be restored.
+    title_set: bool,
+    /// The number of questions answered so far.
+    steps: usize,
+    /// The number of blank lines between questions.
+    spacing: u16,
+    /// Whether a question has been asked, after which questions are preceded by the spacing.
+    asked: bool,
+    /// The symbols used while asking the questions, instead of the current ones.
+    symbols: Option<ui::symbols::SymbolSet>,
+    /// The writer which each answer is written to as a line of JSON.
+    #[cfg(feature = "json")]
+    stream: Option<AnswerStream>,
 }
 
 impl<'a, Q> PromptModule<Q>
@@ -26,6 +64,21 @@ where
         Self {
             answers: Answers::default(),
             questions: questions.into_iter(),
+            names: HashSet::default(),
+            allow_duplicate_names: false,
+            force_skip: HashSet::default(),
+            force_ask: HashSet::default(),
+            confirm_on_interrupt: false,
+            tab_navigation: false,
+            piped_stdin: false,
+            title: None,
+            title_set: false,
+            steps: 0,
+            spacing: 0,
+            asked: false,
+            symbols: None,
+            #[cfg(feature = "json")]
+            stream: None,
         }
     }
 
@@ -35,6 +88,275 @@ where
         self
     }
 
+    /// Allow multiple questions in the module to have the same name
+    ///
+    /// By default, reaching a question with the same name as a previously answered question
+    /// returns an error, unless the question is asked again with [`ask_if_answered`]. If duplicate
+    /// names are allowed, the later question is skipped, or overwrites the earlier answer if
+    /// [`ask_if_answered`] is set.
+    ///
+    /// [`ask_if_answered`]: crate::question::InputBuilder::ask_if_answered
+    pub fn allow_duplicate_names(mut self, allow_duplicate_names: bool) -> Self {
+        self.allow_duplicate_names = allow_duplicate_names;
+        self
+    }
+
+    /// Skip the questions with the given names, regardless of their `when` and `ask_if_answered`
+    ///
+    /// The questions are skipped as if they were not part of the module, so `when` is not called
+    /// for them. This takes precedence over [`force_ask`], so a question whose name is given to
+    /// both is skipped. This is useful for testing, or to control the flow of the questions from
+    /// outside them.
+    ///
+    /// [`force_ask`]: PromptModule::force_ask
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::confirm("newsletter").build(),
+    /// ])
+    /// .force_skip(vec!["newsletter"]);
+    /// ```
+    pub fn force_skip<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.force_skip.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Ask the questions with the given names, regardless of their `when` and `ask_if_answered`
+    ///
+    /// The questions are asked even if `when` returns `false`, which is not called for them, or if
+    /// they have already been answered, as if [`ask_if_answered`] was set. [`force_skip`] takes
+    /// precedence over this, so a question whose name is given to both is skipped.
+    ///
+    /// [`ask_if_answered`]: crate::question::InputBuilder::ask_if_answered
+    /// [`force_skip`]: PromptModule::force_skip
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::confirm("newsletter").when(false).build(),
+    /// ])
+    /// .force_ask(vec!["newsletter"]);
+    /// ```
+    pub fn force_ask<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.force_ask.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Ask "Discard answers? (y/N)" below the prompt when `Ctrl+C` is pressed, instead of
+    /// immediately returning an [`Interrupted`] error
+    ///
+    /// Pressing `y` or `Ctrl+C` again returns the error, and any other key continues with the
+    /// question. This prevents the answers of a long list of questions from being lost by accident.
+    /// It does not apply to [custom prompts], which handle `Ctrl+C` themselves.
+    ///
+    /// If this is not set, it defaults to `false`.
+    ///
+    /// [`Interrupted`]: ErrorKind::Interrupted
+    /// [custom prompts]: crate::question::Question::custom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::input("address").build(),
+    /// ])
+    /// .confirm_on_interrupt(true);
+    /// ```
+    pub fn confirm_on_interrupt(mut self, confirm_on_interrupt: bool) -> Self {
+        self.confirm_on_interrupt = confirm_on_interrupt;
+        self
+    }
+
+    /// Lets the user move between the questions with `Tab` and `Shift+Tab`, like the fields of a
+    /// form.
+    ///
+    /// `Tab` submits the current question, after validating it, and moves on to the next one. It
+    /// is not used for this by prompts which need it themselves, such as an [`input`] with auto
+    /// complete.
+    ///
+    /// `Shift+Tab` goes back to the previous question and asks it again, removing its answer.
+    /// This is only possible while [all the questions](PromptModule::prompt_all) are prompted,
+    /// and only for questions which can be copied, that is questions without any closures. A
+    /// question which cannot be copied cannot be gone back to or past.
+    ///
+    /// It is `false` by default.
+    ///
+    /// [`input`]: crate::question::Question::input
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::input("address").build(),
+    /// ])
+    /// .tab_navigation(true);
+    /// ```
+    pub fn tab_navigation(mut self, tab_navigation: bool) -> Self {
+        self.tab_navigation = tab_navigation;
+        self
+    }
+
+    /// Answer prompts which take text, such as an input or a number, with lines of stdin if it is
+    /// not a terminal, for example if it is piped
+    ///
+    /// The line is validated and filtered as if it was typed and submitted. If there are no more
+    /// lines, the default is used if there is one. Other prompts, such as a select or a confirm,
+    /// cannot be answered from stdin, and fail with [`ErrorKind::Eof`].
+    ///
+    /// This only changes the events used by [`prompt`](PromptModule::prompt) and the other
+    /// methods which use the default [`EventIterator`]. It is `false` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::int("age").build(),
+    /// ])
+    /// .piped_stdin(true);
+    /// ```
+    pub fn piped_stdin(mut self, piped_stdin: bool) -> Self {
+        self.piped_stdin = piped_stdin;
+        self
+    }
+
+    /// Set the title of the terminal window while the questions are asked, followed by the current
+    /// step, such as "Setup — Step 3"
+    ///
+    /// The step is the number of questions answered so far, plus one. The previous title is
+    /// [saved](Backend::save_title) before the first question, and [restored] once there are no
+    /// questions left or an error occurs. Terminals which cannot restore the title keep the last
+    /// one.
+    ///
+    /// [restored]: Backend::restore_title
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::input("address").build(),
+    /// ])
+    /// .title("Setup");
+    /// ```
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The number of blank lines to print between the questions
+    ///
+    /// The blank lines are printed before every question that is asked, except the first one, so
+    /// there are none after the last question. Questions which are skipped do not add any spacing.
+    ///
+    /// If this is not set, it defaults to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::input("address").build(),
+    /// ])
+    /// .spacing(1);
+    /// ```
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Use the [`ASCII`] symbols instead of the [`UNICODE`] ones while asking the questions
+    ///
+    /// This is for terminals which cannot render the [`UNICODE`] symbols, and can be detected with
+    /// [`symbols::supports_unicode`]. The [current](crate::symbols::current) symbols are restored
+    /// after each question, so they are not changed for prompts outside the module.
+    ///
+    /// If this is not set, the current symbols are used.
+    ///
+    /// [`ASCII`]: crate::symbols::ASCII
+    /// [`UNICODE`]: crate::symbols::UNICODE
+    /// [`symbols::supports_unicode`]: crate::symbols::supports_unicode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{symbols, PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::input("address").build(),
+    /// ])
+    /// .ascii_symbols(!symbols::supports_unicode());
+    /// ```
+    pub fn ascii_symbols(mut self, ascii_symbols: bool) -> Self {
+        self.symbols = Some(if ascii_symbols {
+            ui::symbols::ASCII
+        } else {
+            ui::symbols::UNICODE
+        });
+        self
+    }
+
+    /// Write each answer to `writer` as soon as it is given, as a line of JSON
+    ///
+    /// Every line is a JSON object of the form `{"name":"...","value":...}`, where the value is
+    /// the [`Answer`] serialized the same way as in [`Answers`]. The writer is flushed after every
+    /// line, so that the answers can be consumed while the questions are still being asked. The
+    /// answers to [`secret`] questions are written as `****`. Answers given through
+    /// [`with_answers`] are not written.
+    ///
+    /// The writer is shared by the clones of the module.
+    ///
+    /// [`secret`]: crate::question::InputBuilder::secret
+    /// [`with_answers`]: PromptModule::with_answers
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::int("age").build(),
+    /// ])
+    /// .stream_to(std::io::stdout());
+    /// ```
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn stream_to<W: std::io::Write + 'static>(mut self, writer: W) -> Self {
+        self.stream = Some(AnswerStream(Rc::new(RefCell::new(writer))));
+        self
+    }
+
     /// Prompt a single question with the default [`Backend`] and [`EventIterator`].
     ///
     /// This may or may not actually prompt the question based on what `when` and `ask_if_answered`
@@ -45,13 +367,21 @@ where
         let stdout = std::io::stdout();
         let mut stdout = ui::backend::get_backend(stdout.lock());
 
-        self.prompt_with(&mut stdout, &mut ui::events::get_events())
+        let mut events = ui::events::get_events_allowing_piped(self.piped_stdin);
+
+        self.prompt_with(&mut stdout, &mut events)
     }
 
     /// Prompt a single question with the given [`Backend`] and [`EventIterator`].
     ///
     /// This may or may not actually prompt the question based on what `when` and `ask_if_answered`
     /// returns for that particular question.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the question would be asked and has the same name as a previously
+    /// answered question, unless [`allow_duplicate_names`](PromptModule::allow_duplicate_names) is
+    /// set.
     pub fn prompt_with<B, E>(
         &mut self,
         backend: &mut B,
@@ -61,15 +391,160 @@ where
         B: Backend,
         E: EventIterator,
     {
-        for question in self.questions.by_ref() {
-            if let Some((name, answer)) = question.ask(&self.answers, backend, events)? {
-                return Ok(Some(self.answers.insert(name, answer)));
+        self.prompt_with_hook(backend, events, |_, _| {})
+    }
+
+    /// Same as [`prompt_with`](PromptModule::prompt_with), but calls `on_ask` with every question
+    /// reached before it is asked.
+    fn prompt_with_hook<B, E, F>(
+        &mut self,
+        backend: &mut B,
+        events: &mut E,
+        mut on_ask: F,
+    ) -> crate::Result<Option<&mut Answer>>
+    where
+        B: Backend,
+        E: EventIterator,
+        F: FnMut(&mut E, &Question<'a>),
+    {
+        while let Some(question) = self.questions.next() {
+            match self.ask_question(question, false, backend, events, &mut on_ask) {
+                Ok(Some(name)) => return Ok(self.answers.get_mut(&name)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.restore_title(backend)?;
+                    return Err(e);
+                }
             }
         }
 
+        self.restore_title(backend)?;
+
         Ok(None)
     }
 
+    /// Asks a single question, and returns the name of its answer if it was answered.
+    ///
+    /// `back` is whether the user can go back to the previous question, if [`tab_navigation`] is
+    /// set.
+    ///
+    /// [`tab_navigation`]: PromptModule::tab_navigation
+    fn ask_question<B, E, F>(
+        &mut self,
+        mut question: Question<'a>,
+        back: bool,
+        backend: &mut B,
+        events: &mut E,
+        on_ask: &mut F,
+    ) -> crate::Result<Option<String>>
+    where
+        B: Backend,
+        E: EventIterator,
+        F: FnMut(&mut E, &Question<'a>),
+    {
+        if self.force_skip.contains(question.name()) {
+            return Ok(None);
+        }
+
+        if self.force_ask.contains(question.name()) {
+            question.force_ask();
+        }
+
+        if self.confirm_on_interrupt {
+            question.confirm_on_interrupt("Discard answers?".into());
+        }
+
+        if self.tab_navigation {
+            question.tab_navigation(back);
+        }
+
+        if !self.allow_duplicate_names
+            && !question.is_ask_if_answered()
+            && self.names.contains(question.name())
+            && question.when(&self.answers)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Duplicate question name '{}'", question.name()),
+            )
+            .into());
+        }
+
+        if let Some(ref title) = self.title {
+            if !self.title_set {
+                backend.save_title()?;
+                self.title_set = true;
+            }
+            backend.set_title(&format!("{} — Step {}", title, self.steps + 1))?;
+        }
+
+        if question.will_be_asked(&self.answers) {
+            if self.asked {
+                // The terminal is not in raw mode between questions, so the newlines also move
+                // to the start of the line, and scroll the terminal when printed on the last
+                // row. The next prompt then starts below them based on the cursor position.
+                for _ in 0..self.spacing {
+                    backend.write_all(b"\n")?;
+                }
+            }
+            self.asked = true;
+        }
+
+        on_ask(events, &question);
+
+        let secret = question.is_secret();
+
+        let prev_symbols = self.symbols.clone().map(|symbols| {
+            let prev = ui::symbols::current();
+            ui::symbols::set(symbols);
+            prev
+        });
+
+        let res = question.ask(&self.answers, backend, events);
+
+        if let Some(prev) = prev_symbols {
+            ui::symbols::set(prev);
+        }
+
+        let (name, answer) = match res? {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+
+        if !self.allow_duplicate_names {
+            self.names.insert(name.clone());
+        }
+
+        self.steps += 1;
+
+        self.answers.set_secret(&name, secret);
+
+        #[cfg(feature = "json")]
+        if let Some(ref stream) = self.stream {
+            if secret {
+                stream.write_answer(&name, &Answer::redacted())?;
+            } else {
+                stream.write_answer(&name, &answer)?;
+            }
+        }
+
+        self.answers.insert(name.clone(), answer);
+
+        Ok(Some(name))
+    }
+
+    /// Restores the title of the terminal window if it was changed by [`title`].
+    ///
+    /// [`title`]: PromptModule::title
+    fn restore_title<B: Backend>(&mut self, backend: &mut B) -> std::io::Result<()> {
+        if self.title_set {
+            self.title_set = false;
+            backend.restore_title()?;
+        }
+
+        Ok(())
+    }
+
     /// Prompt all remaining questions with the default [`Backend`] and [`EventIterator`].
     ///
     /// It consumes `self` and returns the answers to all the questions asked.
@@ -78,7 +553,7 @@ where
     pub fn prompt_all(self) -> crate::Result<Answers> {
         let stdout = std::io::stdout();
         let mut stdout = ui::backend::get_backend(stdout.lock());
-        let mut events = ui::events::get_events();
+        let mut events = ui::events::get_events_allowing_piped(self.piped_stdin);
 
         self.prompt_all_with(&mut stdout, &mut events)
     }
@@ -86,20 +561,189 @@ where
     /// Prompt all remaining questions with the given [`Backend`] and [`EventIterator`].
     ///
     /// It consumes `self` and returns the answers to all the questions asked.
-    pub fn prompt_all_with<B, E>(
+    pub fn prompt_all_with<B, E>(self, backend: &mut B, events: &mut E) -> crate::Result<Answers>
+    where
+        B: Backend,
+        E: EventIterator,
+    {
+        self.prompt_all_with_partial(backend, events)
+            .map_err(|e| e.error)
+    }
+
+    /// Prompt all remaining questions with the default [`Backend`] and [`EventIterator`], keeping
+    /// the answers to the questions asked if an error occurs.
+    ///
+    /// It consumes `self` and returns the answers to all the questions asked. See
+    /// [`prompt_all_with_partial`](PromptModule::prompt_all_with_partial) for more.
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+    pub fn prompt_all_partial(self) -> Result<Answers, PartialAnswersError> {
+        let stdout = std::io::stdout();
+        let mut stdout = ui::backend::get_backend(stdout.lock());
+        let mut events = ui::events::get_events_allowing_piped(self.piped_stdin);
+
+        self.prompt_all_with_partial(&mut stdout, &mut events)
+    }
+
+    /// Prompt all remaining questions with the given [`Backend`] and [`EventIterator`], keeping
+    /// the answers to the questions asked if an error occurs.
+    ///
+    /// It consumes `self` and returns the answers to all the questions asked. Unlike
+    /// [`prompt_all_with`](PromptModule::prompt_all_with), if an error occurs (for example, the
+    /// user presses `Ctrl+C`), the answers gathered until then are returned along with the error.
+    /// They can be used to resume later with [`with_answers`](PromptModule::with_answers).
+    pub fn prompt_all_with_partial<B, E>(
+        self,
+        backend: &mut B,
+        events: &mut E,
+    ) -> Result<Answers, PartialAnswersError>
+    where
+        B: Backend,
+        E: EventIterator,
+    {
+        self.prompt_all_with_hook(backend, events, |_, _| {})
+    }
+
+    fn prompt_all_with_hook<B, E, F>(
         mut self,
         backend: &mut B,
         events: &mut E,
-    ) -> crate::Result<Answers>
+        on_ask: F,
+    ) -> Result<Answers, PartialAnswersError>
     where
         B: Backend,
         E: EventIterator,
+        F: FnMut(&mut E, &Question<'a>),
     {
         self.answers.reserve(self.questions.size_hint().0);
 
-        while self.prompt_with(backend, events)?.is_some() {}
+        match self.prompt_remaining(backend, events, on_ask) {
+            Ok(()) => Ok(self.answers),
+            Err(error) => Err(PartialAnswersError {
+                error,
+                answers: self.answers,
+            }),
+        }
+    }
+
+    /// Asks all the remaining questions. With [`tab_navigation`], going back asks the previous
+    /// question again before continuing.
+    ///
+    /// [`tab_navigation`]: PromptModule::tab_navigation
+    fn prompt_remaining<B, E, F>(
+        &mut self,
+        backend: &mut B,
+        events: &mut E,
+        mut on_ask: F,
+    ) -> crate::Result<()>
+    where
+        B: Backend,
+        E: EventIterator,
+        F: FnMut(&mut E, &Question<'a>),
+    {
+        // Copies of the questions which can be gone back to, with the names of their answers, or
+        // `None` if they were skipped
+        let mut history: Vec<(Question<'a>, Option<String>)> = Vec::new();
+        // Questions to ask before the remaining ones after going back, in reverse
+        let mut pending = Vec::new();
+        while let Some(question) = pending.pop().or_else(|| self.questions.next()) {
+            // The question is consumed when asked, so it is copied before in case it needs to be
+            // asked again
+            let copy = if self.tab_navigation {
+                question.try_clone()
+            } else {
+                None
+            };
+            let back = copy.is_some() && history.iter().any(|(_, name)| name.is_some());
+
+            match self.ask_question(question, back, backend, events, &mut on_ask) {
+                // Skipped questions are kept as well, since they may not be skipped after going
+                // back and changing an answer. Questions which cannot be copied, whether answered
+                // or skipped, cannot be gone back past.
+                Ok(name) => match copy {
+                    Some(copy) => history.push((copy, name)),
+                    None => history.clear(),
+                },
+                Err(ErrorKind::Back) => {
+                    pending.extend(copy);
+
+                    // Go back to the last answered question, asking the skipped ones after it
+                    // again
+                    loop {
+                        let (previous, name) =
+                            history.pop().expect("back is only allowed with history");
+                        pending.push(previous);
+
+                        if let Some(name) = name {
+                            self.answers.remove(&name);
+                            self.names.remove(&name);
+                            self.steps -= 1;
+                            break;
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.restore_title(backend)?;
+                    return Err(e);
+                }
+            }
+        }
+
+        self.restore_title(backend)?;
+
+        Ok(())
+    }
+
+    /// Prompt all remaining questions with the default [`Backend`] and [`EventIterator`], recording
+    /// the keys pressed and the answers in a [`Transcript`].
+    ///
+    /// See [`record_with`](PromptModule::record_with) for more.
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+    pub fn record(self) -> crate::Result<Transcript> {
+        let stdout = std::io::stdout();
+        let mut stdout = ui::backend::get_backend(stdout.lock());
+        let mut events = ui::events::get_events_allowing_piped(self.piped_stdin);
 
-        Ok(self.answers)
+        self.record_with(&mut stdout, &mut events)
+    }
+
+    /// Prompt all remaining questions with the given [`Backend`] and [`EventIterator`], recording
+    /// the keys pressed and the answers in a [`Transcript`].
+    ///
+    /// It consumes `self` and returns the transcript of the session. The session can be replayed
+    /// by prompting the same questions with the [`events`](Transcript::events) of the transcript.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    /// use requestty::prompt::{backend::TestBackend, events::{KeyCode, TestEvents}};
+    ///
+    /// let questions = || vec![Question::confirm("proceed").build()];
+    /// let mut backend = TestBackend::new((50, 10).into());
+    /// let mut events = TestEvents::new(vec![KeyCode::Char('y').into(), KeyCode::Enter.into()]);
+    ///
+    /// let transcript = PromptModule::new(questions()).record_with(&mut backend, &mut events)?;
+    ///
+    /// let answers =
+    ///     PromptModule::new(questions()).prompt_all_with(&mut backend, &mut transcript.events())?;
+    /// assert_eq!(answers, transcript.answers);
+    /// # Result::<_, requestty::ErrorKind>::Ok(())
+    /// ```
+    pub fn record_with<B, E>(self, backend: &mut B, events: &mut E) -> crate::Result<Transcript>
+    where
+        B: Backend,
+        E: EventIterator,
+    {
+        let mut recorder = Recorder::new(events);
+        let answers =
+            self.prompt_all_with_hook(backend, &mut recorder, Recorder::start_question)?;
+
+        Ok(Transcript {
+            questions: recorder.questions,
+            answers: answers.redacted(),
+        })
     }
 
     /// Consumes `self` returning the answers to the previously asked questions.
@@ -108,6 +752,124 @@ where
     }
 }
 
+impl<'a> PromptModule<std::vec::IntoIter<Question<'a>>> {
+    /// Runs the validator of each remaining question against its default, without asking any of
+    /// them.
+    ///
+    /// This is useful to catch defaults which would be rejected before the user reaches them, for
+    /// example if they come from a config file. Questions without a default or without a
+    /// validator are skipped. Only the `input`, `int`, `float` and `editor` questions have both.
+    ///
+    /// Returns the name of each question with an invalid default along with the validation error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let mut module = PromptModule::new(vec![
+    ///     Question::int("age")
+    ///         .default(-1)
+    ///         .validate(|age, _| {
+    ///             if age >= 0 {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err("age cannot be negative".into())
+    ///             }
+    ///         })
+    ///         .build(),
+    ///     Question::input("name").default("John").build(),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     module.check_defaults(),
+    ///     Err(vec![("age".to_owned(), "age cannot be negative".to_owned())])
+    /// );
+    /// ```
+    pub fn check_defaults(&mut self) -> Result<(), Vec<(String, String)>> {
+        let answers = &self.answers;
+        let errors: Vec<_> = self
+            .questions
+            .as_mut_slice()
+            .iter_mut()
+            .filter_map(|question| match question.check_default(answers) {
+                Some(Err(e)) => Some((question.name().to_owned(), e)),
+                _ => None,
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// The writer given to [`PromptModule::stream_to`].
+#[cfg(feature = "json")]
+#[derive(Clone)]
+struct AnswerStream(Rc<RefCell<dyn io::Write>>);
+
+#[cfg(feature = "json")]
+impl AnswerStream {
+    fn write_answer(&self, name: &str, value: &Answer) -> io::Result<()> {
+        #[derive(serde_crate::Serialize)]
+        #[serde(crate = "serde_crate")]
+        struct Line<'a> {
+            name: &'a str,
+            value: &'a Answer,
+        }
+
+        let mut writer = self.0.borrow_mut();
+        serde_json::to_writer(&mut *writer, &Line { name, value })?;
+        writer.write_all(b"\n")?;
+        writer.flush()
+    }
+}
+
+#[cfg(feature = "json")]
+impl fmt::Debug for AnswerStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AnswerStream")
+    }
+}
+
+#[cfg(feature = "json")]
+impl PartialEq for AnswerStream {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The error returned by [`PromptModule::prompt_all_with_partial`], along with the answers to the
+/// questions asked before the error occurred.
+#[derive(Debug)]
+pub struct PartialAnswersError {
+    /// The error which occurred.
+    pub error: ErrorKind,
+    /// The answers to the questions asked before the error occurred.
+    pub answers: Answers,
+}
+
+impl fmt::Display for PartialAnswersError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(fmt)
+    }
+}
+
+impl std::error::Error for PartialAnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<PartialAnswersError> for ErrorKind {
+    fn from(e: PartialAnswersError) -> Self {
+        e.error
+    }
+}
+
 /// A macro to easily write a [`PromptModule`].
 ///
 /// # Usage
@@ -1,9 +1,16 @@
 use std::{
     io,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
 };
 
-use ui::{style::Color, widgets::List, Widget};
+use ui::{
+    backend::Backend,
+    events::{KeyEvent, Movement},
+    layout::Layout,
+    style::{Color, Stylize},
+    widgets::{List, Text},
+    Widget,
+};
 
 use crate::ExpandItem;
 
@@ -11,10 +18,14 @@ use crate::ExpandItem;
 pub(crate) struct SelectList<T> {
     pub(crate) choices: Vec<T>,
     page_size: usize,
+    page_size_auto: bool,
     default: usize,
     // note: default is not an option usize because it adds an extra usize of space
     has_default: bool,
     should_loop: bool,
+    scroll_margin: usize,
+    gutter_width: u16,
+    pointer_position: PointerPosition,
     is_selectable: fn(&T) -> bool,
 }
 
@@ -23,9 +34,13 @@ impl<T: std::fmt::Debug> std::fmt::Debug for SelectList<T> {
         f.debug_struct("SelectList")
             .field("choices", &self.choices)
             .field("page_size", &self.page_size)
+            .field("page_size_auto", &self.page_size_auto)
             .field("default", &self.default)
             .field("has_default", &self.has_default)
             .field("should_loop", &self.should_loop)
+            .field("scroll_margin", &self.scroll_margin)
+            .field("gutter_width", &self.gutter_width)
+            .field("pointer_position", &self.pointer_position)
             .finish()
     }
 }
@@ -35,9 +50,13 @@ impl<T> SelectList<T> {
         Self {
             choices: Vec::new(),
             page_size: 15,
+            page_size_auto: false,
             default: 0,
             has_default: false,
             should_loop: true,
+            scroll_margin: 1,
+            gutter_width: 2,
+            pointer_position: PointerPosition::Left,
             is_selectable: f,
         }
     }
@@ -46,6 +65,13 @@ impl<T> SelectList<T> {
         self.choices.len()
     }
 
+    /// Whether any of the choices can be selected.
+    pub(crate) fn has_selectable(&self) -> bool {
+        self.choices
+            .iter()
+            .any(|choice| (self.is_selectable)(choice))
+    }
+
     /// Get a reference to the choice list's default.
     pub(crate) fn default(&self) -> Option<usize> {
         if self.has_default {
@@ -65,21 +91,442 @@ impl<T> SelectList<T> {
         self.should_loop
     }
 
+    /// Get a reference to the choice list's scroll margin.
+    pub(crate) fn scroll_margin(&self) -> usize {
+        self.scroll_margin
+    }
+
+    /// Get a reference to the choice list's gutter width.
+    pub(crate) fn gutter_width(&self) -> u16 {
+        self.gutter_width
+    }
+
+    /// The width of the gutter on the right of every element in the list, which is only used if
+    /// the pointer is shown on the right.
+    pub(crate) fn right_gutter_width(&self) -> u16 {
+        match self.pointer_position {
+            PointerPosition::Left => 0,
+            PointerPosition::Right | PointerPosition::Both => self.gutter_width,
+        }
+    }
+
+    /// Writes the gutter on the left of an item in the list.
+    pub(crate) fn write_left_gutter<B: ui::backend::Backend>(
+        &self,
+        hovered: bool,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let pointer = hovered && self.pointer_position != PointerPosition::Right;
+        write_gutter(self.gutter_width, pointer, b)
+    }
+
+    /// Writes the pointer in the gutter on the right of a hovered item, aligned to the right edge
+    /// of the `layout` on the given `line`. The `layout` must not include the right gutter.
+    pub(crate) fn write_right_gutter<B: ui::backend::Backend>(
+        &self,
+        hovered: bool,
+        layout: ui::layout::Layout,
+        line: u16,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let pointer = ui::symbols::current().pointer_left;
+
+        match (self.right_gutter_width(), hovered) {
+            (0, _) | (_, false) => Ok(()),
+            (1, true) => {
+                b.move_cursor_to(layout.width, line)?;
+                write!(b, "{}", pointer)
+            }
+            (_, true) => {
+                b.move_cursor_to(layout.width, line)?;
+                write!(b, " {}", pointer)
+            }
+        }
+    }
+
     /// Set the choice list's default.
     pub(crate) fn set_default(&mut self, default: usize) {
         self.default = default;
         self.has_default = true;
     }
 
+    /// Remove the choice list's default.
+    pub(crate) fn clear_default(&mut self) {
+        self.has_default = false;
+    }
+
     /// Set the choice list's page size.
     pub(crate) fn set_page_size(&mut self, page_size: usize) {
         self.page_size = page_size;
+        self.page_size_auto = false;
+    }
+
+    /// Fit the page size to the terminal height instead of using a fixed page size.
+    pub(crate) fn set_page_size_auto(&mut self) {
+        self.page_size_auto = true;
+    }
+
+    /// Sets the page size to the terminal height in the `layout` if the page size is automatic,
+    /// leaving `reserved` lines for the rest of the prompt. The page size is a minimum of 5.
+    pub(crate) fn fit_page_size(&mut self, layout: ui::layout::Layout, reserved: u16) {
+        if self.page_size_auto {
+            self.page_size = layout.height.saturating_sub(reserved).max(5) as usize;
+        }
     }
 
     /// Set the choice list's should loop.
     pub(crate) fn set_should_loop(&mut self, should_loop: bool) {
         self.should_loop = should_loop;
     }
+
+    /// Set the choice list's scroll margin.
+    pub(crate) fn set_scroll_margin(&mut self, scroll_margin: usize) {
+        self.scroll_margin = scroll_margin;
+    }
+
+    /// Set the choice list's gutter width.
+    pub(crate) fn set_gutter_width(&mut self, gutter_width: u16) {
+        assert!(gutter_width <= 2, "gutter width can be a maximum of 2");
+        self.gutter_width = gutter_width;
+    }
+
+    /// Set the choice list's pointer position.
+    pub(crate) fn set_pointer_position(&mut self, pointer_position: PointerPosition) {
+        self.pointer_position = pointer_position;
+    }
+}
+
+/// Writes the gutter before an item in a list. If the item is hovered, and the gutter is not
+/// empty, the pointer is shown in the gutter.
+pub(crate) fn write_gutter<B: ui::backend::Backend>(
+    gutter_width: u16,
+    hovered: bool,
+    b: &mut B,
+) -> io::Result<()> {
+    match (gutter_width, hovered) {
+        (0, _) => Ok(()),
+        (1, true) => write!(b, "{}", ui::symbols::current().pointer),
+        (_, true) => write!(b, "{} ", ui::symbols::current().pointer),
+        (_, false) => write!(b, "{:1$}", "", gutter_width as usize),
+    }
+}
+
+/// The direction in which the choices of a [`select`] or [`multi_select`] prompt are laid out.
+///
+/// [`select`]: crate::question::Question::select
+/// [`multi_select`]: crate::question::Question::multi_select
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// The choices are shown one below the other. This is the default.
+    Vertical,
+    /// The choices are shown next to each other, and wrap to the next line if they do not fit.
+    Horizontal,
+    /// The choices are shown in as many equal width columns as fit in the terminal, filled row by
+    /// row.
+    Grid,
+}
+
+// `#[default]` on enum variants requires a newer rust version than the MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Vertical
+    }
+}
+
+/// Where the pointer to the hovered choice of a [`select`] prompt is shown.
+///
+/// [`select`]: crate::question::Question::select
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerPosition {
+    /// The pointer is shown before the choice. This is the default.
+    Left,
+    /// The pointer is shown at the right edge of the terminal, on the first line of the choice.
+    Right,
+    /// The pointer is shown both before the choice, and at the right edge of the terminal.
+    Both,
+}
+
+// `#[default]` on enum variants requires a newer rust version than the MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for PointerPosition {
+    fn default() -> Self {
+        PointerPosition::Left
+    }
+}
+
+/// The spaces between two choices in the horizontal and grid orientations.
+const GAP: u16 = 3;
+
+/// A selectable choice laid out by [`Placement`].
+struct Cell {
+    index: usize,
+    /// The first line of the text of the choice.
+    text: String,
+    /// The column the cell starts at, relative to the start of the line.
+    x: u16,
+    line: u16,
+    /// The maximum width of the text of the choice.
+    text_width: u16,
+}
+
+/// The position of the choices in the horizontal and grid orientations. Only the first line of
+/// each choice is shown, and separators are not shown.
+pub(crate) struct Placement {
+    cells: Vec<Cell>,
+    /// The number of columns in the grid orientation, or `None` in the horizontal orientation.
+    columns: Option<usize>,
+    marker_width: u16,
+}
+
+impl Placement {
+    /// Lays out the choices in the given `width`. `marker_width` is the width of what is rendered
+    /// before the text of each choice.
+    pub(crate) fn new(
+        choices: &ChoiceList<Text<String>>,
+        orientation: Orientation,
+        marker_width: u16,
+        width: u16,
+    ) -> Self {
+        let items = (0..choices.len())
+            .filter(|&i| choices.is_selectable(i))
+            .map(|i| (i, &*choices[i].as_ref().unwrap_choice().text));
+
+        match orientation {
+            Orientation::Grid => Self::grid(items, None, marker_width, width),
+            _ => Self::horizontal(items, marker_width, width),
+        }
+    }
+
+    /// Lays out the items in a grid of equal width columns filled row by row. If `columns` is
+    /// `None`, as many columns as fit in the `width` are used. Otherwise, the text of the items is
+    /// cut short if needed to fit the given number of columns.
+    pub(crate) fn grid<'t>(
+        items: impl IntoIterator<Item = (usize, &'t str)>,
+        columns: Option<usize>,
+        marker_width: u16,
+        width: u16,
+    ) -> Self {
+        let items: Vec<_> = items
+            .into_iter()
+            .map(|(index, text)| (index, first_line(text)))
+            .collect();
+
+        let max_text_width = width.saturating_sub(marker_width);
+        let mut text_width = items
+            .iter()
+            .map(|(_, text)| ui::textwrap::core::display_width(text) as u16)
+            .max()
+            .unwrap_or(0)
+            .min(max_text_width);
+
+        let columns = match columns {
+            Some(columns) => {
+                let columns = columns.clamp(1, items.len().max(1));
+                let gaps = (columns as u16 - 1) * GAP;
+                let fit_width = width.saturating_sub(gaps) / columns as u16;
+                text_width = text_width.min(fit_width.saturating_sub(marker_width));
+                columns
+            }
+            None => {
+                let columns = ((width + GAP) / (marker_width + text_width + GAP)) as usize;
+                columns.clamp(1, items.len().max(1))
+            }
+        };
+
+        let cell_width = marker_width + text_width;
+        let cells = items
+            .into_iter()
+            .enumerate()
+            .map(|(pos, (index, text))| Cell {
+                index,
+                text: text.to_owned(),
+                x: (pos % columns) as u16 * (cell_width + GAP),
+                line: (pos / columns) as u16,
+                text_width,
+            })
+            .collect();
+
+        Self {
+            cells,
+            columns: Some(columns),
+            marker_width,
+        }
+    }
+
+    fn horizontal<'t>(
+        items: impl Iterator<Item = (usize, &'t str)>,
+        marker_width: u16,
+        width: u16,
+    ) -> Self {
+        let max_text_width = width.saturating_sub(marker_width);
+        let mut cells = Vec::new();
+        let mut x = 0;
+        let mut line = 0;
+
+        for (index, text) in items {
+            let text = first_line(text);
+            let text_width = (ui::textwrap::core::display_width(text) as u16).min(max_text_width);
+            let item_width = marker_width + text_width;
+
+            if x != 0 && x + GAP + item_width > width {
+                x = 0;
+                line += 1;
+            } else if x != 0 {
+                x += GAP;
+            }
+
+            cells.push(Cell {
+                index,
+                text: text.to_owned(),
+                x,
+                line,
+                text_width,
+            });
+            x += item_width;
+        }
+
+        Self {
+            cells,
+            columns: None,
+            marker_width,
+        }
+    }
+
+    fn lines(&self) -> u16 {
+        self.cells.last().map_or(0, |cell| cell.line + 1)
+    }
+
+    /// Whether there are more lines than can be shown at once. Only the grid orientation is paged.
+    fn is_paged(&self, page_size: usize) -> bool {
+        self.columns.is_some() && self.lines() as usize > page_size
+    }
+
+    /// The lines which are shown. The lines are split into pages of `page_size` lines, and the
+    /// page containing the choice at `at` is shown.
+    fn visible_lines(&self, at: usize, page_size: usize) -> Range<u16> {
+        let lines = self.lines();
+
+        if !self.is_paged(page_size) {
+            return 0..lines;
+        }
+
+        let page_size = page_size as u16;
+        let line = self
+            .cells
+            .iter()
+            .find(|cell| cell.index == at)
+            .map_or(0, |cell| cell.line);
+        let start = line / page_size * page_size;
+
+        start..(start + page_size).min(lines)
+    }
+
+    /// Renders the choices, starting on a new line. `render_marker` should render what is shown
+    /// before the text of the choice at the given index, and set the colour of the text. The colour
+    /// is reset after the text if the choice is hovered.
+    pub(crate) fn render<B: Backend>(
+        &self,
+        at: usize,
+        page_size: usize,
+        layout: &mut Layout,
+        b: &mut B,
+        mut render_marker: impl FnMut(usize, bool, &mut B) -> io::Result<()>,
+    ) -> io::Result<()> {
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+        }
+
+        let lines = self.visible_lines(at, page_size);
+
+        for cell in self.cells.iter().filter(|cell| lines.contains(&cell.line)) {
+            let hovered = cell.index == at;
+            b.move_cursor_to(
+                layout.offset_x + cell.x,
+                layout.offset_y + cell.line - lines.start,
+            )?;
+
+            render_marker(cell.index, hovered, b)?;
+
+            let mut text = &*cell.text;
+            if ui::textwrap::core::display_width(text) <= cell.text_width as usize {
+                b.write_all(text.as_bytes())?;
+            } else {
+                // The text is cut short with a `...` to fit in the cell
+                let mut text_layout = layout.with_line_offset(cell.x + self.marker_width);
+                text_layout.width = layout.offset_x + cell.x + self.marker_width + cell.text_width;
+                text.render(&mut text_layout, b)?;
+            }
+
+            if hovered {
+                b.set_fg(Color::Reset)?;
+            }
+        }
+
+        layout.offset_y += lines.end - lines.start;
+
+        if self.is_paged(page_size) {
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            b.write_styled(&"(Move up and down to reveal more choices)".dark_grey())?;
+            layout.offset_y += 1;
+        }
+
+        b.move_cursor_to(layout.offset_x, layout.offset_y)
+    }
+
+    /// The height of the choices, starting on a new line.
+    pub(crate) fn height(&self, at: usize, page_size: usize, layout: &mut Layout) -> u16 {
+        let lines = self.visible_lines(at, page_size);
+        let height = (layout.line_offset != 0) as u16
+            + (lines.end - lines.start)
+            + self.is_paged(page_size) as u16;
+
+        layout.line_offset = 0;
+        layout.offset_y += height;
+        height
+    }
+
+    /// Returns the index of the choice to move to from the choice at `at` for the given key, if
+    /// the key moves to a different choice.
+    ///
+    /// In the horizontal orientation, all the arrow keys move to the previous or next choice. In
+    /// the grid orientation, the up and down arrow keys move to the choice in the previous or
+    /// next row.
+    pub(crate) fn handle_key(&self, at: usize, key: KeyEvent, should_loop: bool) -> Option<usize> {
+        let pos = self.cells.iter().position(|cell| cell.index == at)?;
+        let last = self.cells.len() - 1;
+
+        let new_pos = match (self.columns, Movement::try_from_key(key)?) {
+            (_, Movement::Left) | (None, Movement::Up) if pos > 0 => pos - 1,
+            (_, Movement::Left) | (None, Movement::Up) if should_loop => last,
+            (_, Movement::Right) | (None, Movement::Down) if pos < last => pos + 1,
+            (_, Movement::Right) | (None, Movement::Down) if should_loop => 0,
+            (Some(columns), Movement::Up) if pos >= columns => pos - columns,
+            // the last choice in the same column
+            (Some(columns), Movement::Up) if should_loop => {
+                let column = pos % columns;
+                column + (last - column) / columns * columns
+            }
+            (Some(columns), Movement::Down) if pos + columns <= last => pos + columns,
+            // the first choice in the same column
+            (Some(columns), Movement::Down) if should_loop => pos % columns,
+            (_, Movement::Home) | (_, Movement::PageUp) => 0,
+            (_, Movement::End) | (_, Movement::PageDown) => last,
+            _ => return None,
+        };
+
+        if new_pos == pos {
+            None
+        } else {
+            Some(self.cells[new_pos].index)
+        }
+    }
+}
+
+/// The choices are single line in the horizontal and grid orientations.
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("")
 }
 
 impl<T> Index<usize> for SelectList<T> {
@@ -106,18 +553,21 @@ impl<T: Widget> List for SelectList<T> {
     ) -> io::Result<()> {
         if hovered {
             b.set_fg(Color::Cyan)?;
-            write!(b, "{} ", ui::symbols::current().pointer)?;
-        } else {
-            b.write_all(b"  ")?;
+        }
 
-            if !self.is_selectable(index) {
-                b.set_fg(Color::DarkGrey)?;
-            }
+        self.write_left_gutter(hovered, b)?;
+
+        if !hovered && !self.is_selectable(index) {
+            b.set_fg(Color::DarkGrey)?;
         }
 
-        layout.offset_x += 2;
+        let line = layout.offset_y;
+        layout.offset_x += self.gutter_width;
+        layout.width -= self.right_gutter_width();
         self.choices[index].render(&mut layout, b)?;
 
+        self.write_right_gutter(hovered, layout, line, b)?;
+
         b.set_fg(Color::Reset)
     }
 
@@ -133,8 +583,13 @@ impl<T: Widget> List for SelectList<T> {
         self.should_loop
     }
 
+    fn scroll_margin(&self) -> usize {
+        self.scroll_margin
+    }
+
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
-        layout.offset_x += 2;
+        layout.offset_x += self.gutter_width;
+        layout.width -= self.right_gutter_width();
 
         self[index].height(&mut layout)
     }
@@ -146,6 +601,54 @@ impl<T: Widget> List for SelectList<T> {
 
 pub(crate) type ChoiceList<T> = SelectList<Choice<T>>;
 
+impl<T> ChoiceList<T> {
+    /// Sorts the choices between each pair of separators, so that the separators stay in place.
+    /// Choices are also not moved across any of the `breaks`, which are the indices at which a new
+    /// group of choices starts. The default moves along with its choice.
+    ///
+    /// It returns the index each choice was at before sorting.
+    pub(crate) fn sort_by<F>(&mut self, breaks: &[usize], mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        let choices = &self.choices;
+        let mut order: Vec<usize> = (0..choices.len()).collect();
+        let mut start = 0;
+
+        for i in 0..=choices.len() {
+            let is_separator = i < choices.len() && choices[i].is_separator();
+
+            if i == choices.len() || is_separator || breaks.contains(&i) {
+                order[start..i].sort_by(|&a, &b| match (&choices[a], &choices[b]) {
+                    (Choice::Choice(a), Choice::Choice(b)) => compare(a, b),
+                    _ => unreachable!("segments do not contain separators"),
+                });
+
+                start = if is_separator { i + 1 } else { i };
+            }
+        }
+
+        let mut choices: Vec<_> = std::mem::take(&mut self.choices)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.choices = order
+            .iter()
+            .map(|&i| choices[i].take().expect("every choice is moved once"))
+            .collect();
+
+        if let Some(default) = self.default() {
+            let default = order
+                .iter()
+                .position(|&i| i == default)
+                .expect("the default is one of the choices");
+            self.set_default(default);
+        }
+
+        order
+    }
+}
+
 impl<T> std::iter::FromIterator<T> for ChoiceList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut this = Self::new(Choice::is_choice);
@@ -171,7 +674,13 @@ pub enum Choice<T> {
     ///
     /// If the text is more than one line, it will be cut-off.
     Separator(String),
-    /// A separator which prints a line: "──────────────"
+    /// A separator which draws a line across the width of the terminal: "──────────────"
+    ///
+    /// The line is drawn with the [`separator`] symbol of the current symbol set, which can be
+    /// changed with [`symbols::set`].
+    ///
+    /// [`separator`]: crate::symbols::SymbolSet::separator
+    /// [`symbols::set`]: crate::symbols::set
     DefaultSeparator,
 }
 
@@ -231,12 +740,27 @@ impl<T> Choice<T> {
     }
 }
 
-#[inline]
-pub(crate) fn get_sep_str<T>(separator: &Choice<T>) -> &str {
+/// Renders a separator on a single line. The [`Choice::DefaultSeparator`] fills the rest of the
+/// line.
+pub(crate) fn render_separator<T, B: Backend>(
+    separator: &Choice<T>,
+    layout: &mut Layout,
+    backend: &mut B,
+) -> io::Result<()> {
     match separator {
         Choice::Choice(_) => unreachable!(),
-        Choice::Separator(s) => s,
-        Choice::DefaultSeparator => "──────────────",
+        Choice::Separator(s) => s.as_str().render(layout, backend),
+        Choice::DefaultSeparator => {
+            let symbol = ui::symbols::current().separator;
+            let symbol_width = ui::textwrap::core::display_width(symbol.encode_utf8(&mut [0; 4]));
+            let len = layout.line_width() as usize / symbol_width.max(1);
+
+            symbol
+                .to_string()
+                .repeat(len)
+                .as_str()
+                .render(layout, backend)
+        }
     }
 }
 
@@ -248,7 +772,7 @@ impl<T: ui::Widget> ui::Widget for Choice<T> {
     ) -> io::Result<()> {
         match self {
             Choice::Choice(c) => c.render(layout, backend),
-            sep => get_sep_str(sep).render(layout, backend),
+            sep => render_separator(sep, layout, backend),
         }
     }
 
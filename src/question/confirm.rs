@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, time::Duration};
 
 use ui::{
     backend::Backend,
@@ -13,23 +13,145 @@ use crate::{Answer, Answers};
 #[derive(Debug, Default)]
 pub(super) struct Confirm<'a> {
     default: Option<bool>,
+    /// The style of the hint, or the default style if `None`.
+    hint_style: Option<ui::style::Style>,
     transform: Transform<'a, bool>,
+    on_invalid_hint: Option<String>,
+    /// The words which can be typed as an answer, and the answer they map to. If it is empty, a
+    /// single `y` or `n` is typed instead.
+    words: Vec<(String, bool)>,
+    /// The answer given on its own once the duration passes without a key being pressed.
+    auto_answer: Option<(Duration, bool)>,
+}
+
+impl Confirm<'_> {
+    /// Returns a copy of the confirm, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            default: self.default,
+            hint_style: self.hint_style,
+            transform: self.transform.try_clone()?,
+            on_invalid_hint: self.on_invalid_hint.clone(),
+            words: self.words.clone(),
+            auto_answer: self.auto_answer,
+        })
+    }
+}
+
+/// The input used to type the answer, which depends on whether [words] are accepted.
+///
+/// [words]: ConfirmBuilder::accept_words
+enum ConfirmInput {
+    Char(widgets::CharInput),
+    Words(widgets::StringInput),
+}
+
+impl Widget for ConfirmInput {
+    fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        match self {
+            ConfirmInput::Char(input) => input.render(layout, b),
+            ConfirmInput::Words(input) => input.render(layout, b),
+        }
+    }
+
+    fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        match self {
+            ConfirmInput::Char(input) => input.height(layout),
+            ConfirmInput::Words(input) => input.height(layout),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match self {
+            ConfirmInput::Char(input) => input.handle_key(key),
+            ConfirmInput::Words(input) => input.handle_key(key),
+        }
+    }
+
+    fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
+        match self {
+            ConfirmInput::Char(input) => input.cursor_pos(layout),
+            ConfirmInput::Words(input) => input.cursor_pos(layout),
+        }
+    }
 }
 
 struct ConfirmPrompt<'a> {
-    prompt: widgets::Prompt<&'a str>,
+    prompt: widgets::Prompt<&'a str, String>,
     confirm: Confirm<'a>,
-    input: widgets::CharInput,
+    input: ConfirmInput,
+    /// The time left before the [`auto_answer`] is given, or `None` if there is no countdown.
+    ///
+    /// [`auto_answer`]: ConfirmBuilder::auto_answer
+    countdown: Option<Duration>,
+}
+
+impl ConfirmPrompt<'_> {
+    /// The answer typed so far, or `None` if nothing valid has been typed.
+    fn typed_answer(&self) -> Option<bool> {
+        match self.input {
+            ConfirmInput::Char(ref input) => match input.value()? {
+                'y' | 'Y' => Some(true),
+                _ => Some(false),
+            },
+            ConfirmInput::Words(ref input) => parse_word(&self.confirm.words, input.value()),
+        }
+    }
+
+    /// The answer given by the countdown, if it has run out.
+    fn auto_answer(&self) -> Option<bool> {
+        match (self.countdown, self.confirm.auto_answer) {
+            (Some(remaining), Some((_, answer))) if remaining == Duration::from_secs(0) => {
+                Some(answer)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self.input {
+            ConfirmInput::Char(ref input) => input.value().is_none(),
+            ConfirmInput::Words(ref input) => input.value().trim().is_empty(),
+        }
+    }
 }
 
 impl Widget for ConfirmPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
         self.prompt.render(layout, b)?;
-        self.input.render(layout, b)
+        self.input.render(layout, b)?;
+
+        if let (Some(remaining), Some((_, answer))) = (self.countdown, self.confirm.auto_answer) {
+            layout.offset_y += 1;
+            layout.line_offset = 0;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+            // Rounded up, so that it does not show 0s while there is still time left
+            let secs = remaining.as_secs() + (remaining.subsec_nanos() > 0) as u64;
+            let text = format!(
+                "(Answering {} in {}s, press any key to cancel)",
+                if answer { "Yes" } else { "No" },
+                secs
+            );
+            // The `str` widget cuts the text short so that it stays on a single line
+            b.set_fg(ui::style::Color::DarkGrey)?;
+            text.as_str().render(layout, b)?;
+            b.set_fg(ui::style::Color::Reset)?;
+        }
+
+        Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.input.height(layout) - 1
+        let height = self.prompt.height(layout) + self.input.height(layout) - 1;
+
+        if self.countdown.is_some() && self.confirm.auto_answer.is_some() {
+            layout.offset_y += 1;
+            layout.line_offset = 0;
+            height + 1
+        } else {
+            height
+        }
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
@@ -49,60 +171,156 @@ fn only_yn(c: char) -> Option<char> {
     }
 }
 
+/// Finds the answer for the typed word, ignoring case. A word can also be shortened, as long as
+/// all the words it could be short for have the same answer.
+fn parse_word(words: &[(String, bool)], input: &str) -> Option<bool> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+
+    if let Some(&(_, ans)) = words.iter().find(|(word, _)| word.to_lowercase() == input) {
+        return Some(ans);
+    }
+
+    let mut answers = words
+        .iter()
+        .filter(|(word, _)| word.to_lowercase().starts_with(&input))
+        .map(|&(_, ans)| ans);
+
+    let ans = answers.next()?;
+    if answers.all(|other| other == ans) {
+        Some(ans)
+    } else {
+        None
+    }
+}
+
 impl Prompt for ConfirmPrompt<'_> {
-    type ValidateErr = &'static str;
+    type ValidateErr = String;
     type Output = bool;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if self.input.value().is_some() || self.confirm.default.is_some() {
-            Ok(Validation::Finish)
-        } else {
-            Err("Please enter y or n")
+        if self.is_empty() && self.auto_answer().is_some() {
+            return Ok(Validation::Finish);
+        }
+
+        if self.is_empty() {
+            return match self.confirm.default {
+                Some(_) => Ok(Validation::Finish),
+                None if self.confirm.words.is_empty() => Err("Please enter y or n".into()),
+                None => Err(self.confirm.words_error()),
+            };
+        }
+
+        match self.typed_answer() {
+            Some(_) => Ok(Validation::Finish),
+            None => Err(self.confirm.words_error()),
         }
     }
 
+    fn countdown(&mut self, remaining: Option<Duration>) {
+        self.countdown = remaining;
+    }
+
     fn finish(self) -> Self::Output {
-        match self.input.value() {
-            Some('y') | Some('Y') => true,
-            Some('n') | Some('N') => false,
-            _ => self
-                .confirm
-                .default
-                .expect("Validation would fail if there was no answer and no default"),
-        }
+        self.typed_answer()
+            .or_else(|| self.auto_answer())
+            .or(self.confirm.default)
+            .expect("Validation would fail if there was no answer and no default")
     }
 }
 
 impl<'a> Confirm<'a> {
+    fn words_error(&self) -> String {
+        let words: Vec<_> = self.words.iter().map(|(word, _)| &word[..]).collect();
+        format!("Please enter one of {}", words.join(", "))
+    }
+
+    /// The first word for each answer, with the default in uppercase.
+    fn words_hint(&self) -> String {
+        let word = |ans: bool| {
+            self.words
+                .iter()
+                .find(|&&(_, word_ans)| word_ans == ans)
+                .map(|(word, _)| {
+                    if self.default == Some(ans) {
+                        word.to_uppercase()
+                    } else {
+                        word.clone()
+                    }
+                })
+        };
+
+        let words: Vec<_> = word(true).into_iter().chain(word(false)).collect();
+        words.join("/")
+    }
+
     fn into_confirm_prompt(self, message: &'a str) -> ConfirmPrompt<'a> {
-        let hint = match self.default {
-            Some(true) => "Y/n",
-            Some(false) => "y/N",
-            None => "y/n",
+        let (hint, input) = if self.words.is_empty() {
+            let hint = match self.default {
+                Some(true) => "Y/n",
+                Some(false) => "y/N",
+                None => "y/n",
+            };
+
+            (
+                hint.to_owned(),
+                ConfirmInput::Char(widgets::CharInput::with_filter_map(only_yn)),
+            )
+        } else {
+            (
+                self.words_hint(),
+                ConfirmInput::Words(widgets::StringInput::new()),
+            )
         };
 
         ConfirmPrompt {
-            prompt: widgets::Prompt::new(message).with_hint(hint),
+            prompt: widgets::Prompt::new(message)
+                .with_hint(hint)
+                .with_optional_hint_style(self.hint_style),
             confirm: self,
-            input: widgets::CharInput::with_filter_map(only_yn),
+            input,
+            countdown: None,
         }
     }
 
+    pub(crate) fn answer_with(self, value: Answer) -> Answer {
+        let value = value
+            .as_bool()
+            .expect("`confirm` questions are answered with a `Bool`");
+
+        Answer::Bool(value)
+    }
+
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let on_invalid_hint = self.on_invalid_hint.take();
+        let auto_answer = self.auto_answer;
+
+        let mut input = ui::Input::new(self.into_confirm_prompt(&message), b)
+            .on_esc(exit.on_esc)
+            .confirm_on_interrupt(exit.interrupt_confirm)
+            .submit_on_tab(exit.submit_on_tab)
+            .back_on_back_tab(exit.back_on_back_tab)
+            .ensure_newline(exit.ensure_newline)
+            .on_invalid_hint(on_invalid_hint);
+
+        if let Some((duration, _)) = auto_answer {
+            input = input.countdown(duration);
+        }
 
-        let ans = ui::Input::new(self.into_confirm_prompt(&message), b)
-            .on_esc(on_esc)
-            .run(events)?;
+        let ans = input.run(events)?;
 
-        crate::write_final!(transform, message, ans, answers, b, |ans| {
+        crate::write_final!(transform, silent, message, ans, answers, b, |ans| {
             let ans = if ans { "Yes" } else { "No" };
             b.write_styled(&ans.cyan())?;
         })
@@ -180,6 +398,17 @@ impl<'a> ConfirmBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -190,6 +419,39 @@ impl<'a> ConfirmBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the confirm
@@ -210,6 +472,90 @@ impl<'a> ConfirmBuilder<'a> {
         self
     }
 
+    /// Accept the given words as answers, instead of a single `y` or `n`
+    ///
+    /// Each word is mapped to the answer it gives. The words are matched ignoring case, and can be
+    /// shortened as long as all the words they could be short for give the same answer. So `y` and
+    /// `n` are still accepted if `yes` and `no` are given. If the input text is empty, the
+    /// [`default`] is taken as the answer.
+    ///
+    /// The first word for each answer is shown as the hint, with the default in uppercase.
+    ///
+    /// [`default`]: Self::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .accept_words(&[("yes", true), ("no", false), ("true", true), ("false", false)])
+    ///     .build();
+    /// ```
+    pub fn accept_words(mut self, words: &[(&str, bool)]) -> Self {
+        self.confirm.words = words
+            .iter()
+            .map(|&(word, ans)| (word.to_owned(), ans))
+            .collect();
+        self
+    }
+
+    /// Answer on its own once `duration` has passed without a key being pressed
+    ///
+    /// The time left is shown below the question, counting down every second, and `answer` is
+    /// taken as the answer once it runs out. Pressing any key cancels the countdown, after which
+    /// the question has to be answered as usual.
+    ///
+    /// The time is measured while waiting for keys, so the countdown only runs with events which
+    /// support [polling](crate::prompt::EventIterator::poll_event). Only the default events of the
+    /// `crossterm` backend do. With the `termion` backend, or with [piped] stdin, the countdown is
+    /// never shown and the question waits for a key as if `auto_answer` was not set.
+    ///
+    /// [piped]: crate::PromptModule::piped_stdin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("update")
+    ///     .message("Install the update?")
+    ///     .default(true)
+    ///     .auto_answer(Duration::from_secs(10), true)
+    ///     .build();
+    /// ```
+    pub fn auto_answer(mut self, duration: Duration, answer: bool) -> Self {
+        self.confirm.auto_answer = Some((duration, answer));
+        self
+    }
+
+    crate::impl_hint_style_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Attributes, Color, Style}, Question};
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD))
+    ///     .build();
+    /// ```
+    confirm
+    }
+
+    crate::impl_on_invalid_hint_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .on_invalid_hint("Please press y or n")
+    ///     .build();
+    /// ```
+    confirm
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -226,6 +572,20 @@ impl<'a> ConfirmBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .transform_with_layout(|anonymous, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         let answer = if anonymous { "Yes" } else { "No" };
+    ///         write!(backend, "{:>1$}", answer, layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
     by val bool; confirm
     }
 
@@ -335,6 +695,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_word() {
+        let words: Vec<_> = [("yes", true), ("no", false), ("nope", false), ("ja", true)]
+            .iter()
+            .map(|&(word, ans)| (word.to_owned(), ans))
+            .collect();
+
+        assert_eq!(parse_word(&words, "yes"), Some(true));
+        assert_eq!(parse_word(&words, " YES "), Some(true));
+        assert_eq!(parse_word(&words, "n"), Some(false));
+        assert_eq!(parse_word(&words, "j"), Some(true));
+        assert_eq!(parse_word(&words, "yesss"), None);
+        assert_eq!(parse_word(&words, ""), None);
+
+        let words = vec![("no".to_owned(), false), ("nah-yes".to_owned(), true)];
+        // `n` could be short for either
+        assert_eq!(parse_word(&words, "n"), None);
+        assert_eq!(parse_word(&words, "no"), Some(false));
+    }
+
     #[test]
     fn test_cursor_pos() {
         let size = (50, 20).into();
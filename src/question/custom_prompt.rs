@@ -1,4 +1,10 @@
-use ui::{backend::Backend, events::EventIterator};
+use std::{fmt, io};
+
+use ui::{
+    backend::Backend,
+    events::EventIterator,
+    style::{Color, Stylize},
+};
 
 use super::{Options, Question, QuestionKind};
 use crate::{Answer, Answers};
@@ -25,6 +31,159 @@ pub trait Prompt: std::fmt::Debug {
     ) -> ui::Result<Option<Answer>>;
 }
 
+/// A [`Prompt`] which asks a [`ui::Prompt`] widget.
+///
+/// This allows a widget built with `requestty-ui` to be used with [`Question::custom`] alongside
+/// the in-built questions, without implementing [`Prompt`] by hand. The widget is created with the
+/// message and previous [`Answers`] when the question is asked. Its output must implement
+/// `Into<Answer>`, which is the case for all the types held by the [`Answer`] variants. After the
+/// user submits, the answer is shown similar to the in-built questions.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{question::WidgetPrompt, Question};
+/// # use requestty::prompt::{backend::Backend, events::KeyEvent};
+/// # use ui as requestty_ui;
+/// # use requestty_ui::{layout::Layout, Widget};
+///
+/// #[derive(Debug)]
+/// struct MyWidget { /* ... */ }
+///
+/// # impl MyWidget {
+/// #     fn new(message: String) -> MyWidget {
+/// #         MyWidget {}
+/// #     }
+/// # }
+/// #
+/// # impl Widget for MyWidget {
+/// #     fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> std::io::Result<()> {
+/// #         todo!()
+/// #     }
+/// #     fn height(&mut self, _: &mut Layout) -> u16 {
+/// #         todo!()
+/// #     }
+/// #     fn cursor_pos(&mut self, _: Layout) -> (u16, u16) {
+/// #         todo!()
+/// #     }
+/// #     fn handle_key(&mut self, _: KeyEvent) -> bool {
+/// #         todo!()
+/// #     }
+/// # }
+/// #
+/// impl requestty_ui::Prompt for MyWidget {
+///     type ValidateErr = &'static str;
+///     type Output = i64;
+///
+///     fn finish(self) -> i64 {
+///         // ...
+/// #         todo!()
+///     }
+/// }
+///
+/// let prompt = Question::custom(
+///     "my-widget",
+///     WidgetPrompt::new(|message, _previous_answers| MyWidget::new(message)),
+/// )
+/// .message("Hello from MyWidget!")
+/// .build();
+/// ```
+pub struct WidgetPrompt<F> {
+    make_widget: F,
+}
+
+impl<F> WidgetPrompt<F> {
+    /// Creates a new `WidgetPrompt` which creates the widget with the given function.
+    ///
+    /// The function is given the message of the question and the previous [`Answers`].
+    pub fn new<P>(make_widget: F) -> Self
+    where
+        F: FnOnce(String, &Answers) -> P,
+        P: ui::Prompt,
+        P::Output: Into<Answer>,
+    {
+        Self { make_widget }
+    }
+}
+
+impl<F> fmt::Debug for WidgetPrompt<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WidgetPrompt").finish_non_exhaustive()
+    }
+}
+
+impl<F, P> Prompt for WidgetPrompt<F>
+where
+    F: FnOnce(String, &Answers) -> P,
+    P: ui::Prompt,
+    P::Output: Into<Answer>,
+{
+    fn ask(
+        self,
+        message: String,
+        answers: &Answers,
+        mut backend: &mut dyn Backend,
+        mut events: &mut dyn EventIterator,
+    ) -> ui::Result<Option<Answer>> {
+        let widget = (self.make_widget)(message.clone(), answers);
+        let ans = ui::Input::new(widget, &mut backend)
+            .ensure_newline(true)
+            .run(&mut events)?
+            .map(Into::into);
+
+        ui::widgets::Prompt::write_finished_message(&message, ans.is_none(), &mut backend)?;
+
+        match ans {
+            Some(ref ans) => {
+                backend.set_fg(Color::Cyan)?;
+                write_answer(ans, &mut backend)?;
+                backend.set_fg(Color::Reset)?;
+            }
+            None => backend.write_styled(&"Skipped".dark_grey())?,
+        }
+
+        backend.write_all(b"\n")?;
+        backend.flush()?;
+
+        Ok(ans)
+    }
+}
+
+/// Writes a short, single line representation of the answer.
+fn write_answer<B: Backend>(ans: &Answer, b: &mut B) -> io::Result<()> {
+    match ans {
+        Answer::String(s) => b.write_all(s.as_bytes()),
+        Answer::ListItem(item) => b.write_all(first_line(&item.text).as_bytes()),
+        Answer::ExpandItem(item) => b.write_all(first_line(&item.text).as_bytes()),
+        Answer::Int(i) => write!(b, "{}", i),
+        Answer::Float(f) => write!(b, "{}", f),
+        Answer::Bool(true) => b.write_all(b"Yes"),
+        Answer::Bool(false) => b.write_all(b"No"),
+        Answer::ListItems(items) => {
+            for (i, item) in items.iter().enumerate() {
+                if i != 0 {
+                    b.write_all(b", ")?;
+                }
+                b.write_all(first_line(&item.text).as_bytes())?;
+            }
+            Ok(())
+        }
+        Answer::List(items) => {
+            for (i, item) in items.iter().enumerate() {
+                if i != 0 {
+                    b.write_all(b", ")?;
+                }
+                write_answer(item, b)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or("")
+}
+
 /// The same trait as `Prompt`, except it take `&mut self` instead of `self`.
 ///
 /// This is required since traits with functions that take `self` are not object safe, and so
@@ -209,6 +368,72 @@ impl<'a> CustomPromptBuilder<'a> {
     ///     .ask_if_answered(true)
     ///     .build();
     /// ```
+
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt, Question};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn new() -> MyPrompt {
+    /// #         MyPrompt {}
+    /// #     }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &prompt::Answers,
+    ///         backend: &mut dyn prompt::Backend,
+    ///         events: &mut dyn prompt::EventIterator,
+    ///     ) -> requestty::Result<Option<prompt::Answer>> {
+    ///         // ...
+    /// #         todo!()
+    ///     }
+    /// }
+    ///
+    /// let prompt = Question::custom("my-prompt", MyPrompt::new())
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt, Question};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyPrompt { /* ... */ }
+    ///
+    /// # impl MyPrompt {
+    /// #     fn new() -> MyPrompt {
+    /// #         MyPrompt {}
+    /// #     }
+    /// # }
+    ///
+    /// impl prompt::Prompt for MyPrompt {
+    ///     fn ask(
+    ///         self,
+    ///         message: String,
+    ///         answers: &prompt::Answers,
+    ///         backend: &mut dyn prompt::Backend,
+    ///         events: &mut dyn prompt::EventIterator,
+    ///     ) -> requestty::Result<Option<prompt::Answer>> {
+    ///         // ...
+    /// #         todo!()
+    ///     }
+    /// }
+    ///
+    /// let prompt = Question::custom("my-prompt", MyPrompt::new())
+    ///     .secret(true)
+    ///     .build();
+    /// ```
     }
 
     /// Consumes the builder returning a [`Question`]
@@ -2,19 +2,42 @@ use std::{
     env,
     fs::File,
     io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
     process::Command,
 };
 
-use ui::{backend::Backend, events::EventIterator, style::Stylize, widgets, Validation, Widget};
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyCode, KeyEvent},
+    style::{Color, Stylize},
+    widgets, Validation, Widget,
+};
 
-use super::{Filter, Options, Transform, Validate};
+use super::{Filter, Launcher, Options, Transform, Validate, REQUIRED_ERROR};
 use crate::{Answer, Answers, Question};
 
 #[derive(Debug)]
 pub(super) struct Editor<'a> {
     extension: Option<String>,
     default: Option<String>,
+    /// The file to read the default value from. Only one of `default` and `default_file` is set.
+    default_file: Option<PathBuf>,
     editor: Command,
+    /// The program run by `editor` followed by its arguments. It is `None` if the editor was
+    /// explicitly given as a [`Command`], since its program cannot be retrieved.
+    editor_args: Option<Vec<String>>,
+    /// Editors to try in order if `editor` could not be found.
+    fallbacks: Vec<Vec<String>>,
+    /// The number of lines of the entered content to preview after validation fails. The preview
+    /// is not shown if it is `0`.
+    show_preview: usize,
+    /// The style of the hint, or the default style if `None`.
+    hint_style: Option<ui::style::Style>,
+    /// Whether an empty answer is rejected.
+    required: bool,
+    /// The style of the required marker, or the default style if `None`.
+    required_style: Option<ui::style::Style>,
+    launcher: Launcher<'a, Path>,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     transform: Transform<'a, str>,
@@ -22,10 +45,21 @@ pub(super) struct Editor<'a> {
 
 impl<'a> Default for Editor<'a> {
     fn default() -> Self {
+        let mut candidates = get_editor_candidates();
+        let editor_args = candidates.remove(0);
+
         Self {
-            editor: get_editor(),
+            editor: to_command(&editor_args),
+            editor_args: Some(editor_args),
+            fallbacks: candidates,
+            show_preview: 0,
+            hint_style: None,
+            required: false,
+            required_style: None,
+            launcher: Launcher::None,
             extension: None,
             default: None,
+            default_file: None,
             filter: Filter::None,
             validate: Validate::None,
             transform: Transform::None,
@@ -33,6 +67,31 @@ impl<'a> Default for Editor<'a> {
     }
 }
 
+impl Editor<'_> {
+    /// Returns a copy of the editor, or `None` if it has a function or the editor was given as a
+    /// [`Command`], which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        let editor_args = self.editor_args.clone()?;
+
+        Some(Self {
+            extension: self.extension.clone(),
+            default: self.default.clone(),
+            default_file: self.default_file.clone(),
+            editor: to_command(&editor_args),
+            editor_args: Some(editor_args),
+            fallbacks: self.fallbacks.clone(),
+            show_preview: self.show_preview,
+            hint_style: self.hint_style,
+            required: self.required,
+            required_style: self.required_style,
+            launcher: self.launcher.try_clone()?,
+            filter: self.filter.try_clone()?,
+            validate: self.validate.try_clone()?,
+            transform: self.transform.try_clone()?,
+        })
+    }
+}
+
 #[cfg(unix)]
 fn split(command: &str) -> Option<Vec<String>> {
     shell_words::split(command).ok()
@@ -42,19 +101,25 @@ fn split(command: &str) -> Option<Vec<String>> {
     Some(winsplit::split(command))
 }
 
-fn get_editor() -> Command {
-    let editor_args = env::var_os("VISUAL")
-        .or_else(|| env::var_os("EDITOR"))
-        .and_then(|editor_command| editor_command.to_str().map(split))
-        .flatten()
-        .unwrap_or_else(|| {
-            if cfg!(windows) {
-                vec!["notepad".into()]
-            } else {
-                vec!["vim".into()]
-            }
-        });
+/// Returns the editors to try in order of preference. It is never empty, and none of the
+/// candidates are empty.
+fn get_editor_candidates() -> Vec<Vec<String>> {
+    let fallbacks: &[&str] = if cfg!(windows) {
+        &["notepad"]
+    } else {
+        &["vim", "vi", "nano"]
+    };
 
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(env::var_os)
+        .filter_map(|editor_command| editor_command.to_str().and_then(split))
+        .filter(|editor_args| !editor_args.is_empty())
+        .chain(fallbacks.iter().map(|&editor| vec![editor.into()]))
+        .collect()
+}
+
+fn to_command(editor_args: &[String]) -> Command {
     let mut command = Command::new(&editor_args[0]);
     command.args(&editor_args[1..]);
     command
@@ -63,7 +128,12 @@ fn get_editor() -> Command {
 struct EditorPrompt<'a, 'e> {
     prompt: widgets::Prompt<&'a str>,
     file: File,
+    path: &'a Path,
     ans: String,
+    /// The lines of `ans` currently shown in the preview, if validation has failed.
+    preview: Option<widgets::Text<String>>,
+    /// The first line of `ans` shown in the preview.
+    preview_offset: usize,
     editor: Editor<'e>,
     answers: &'a Answers,
 }
@@ -74,11 +144,42 @@ impl Widget for EditorPrompt<'_, '_> {
         layout: &mut ui::layout::Layout,
         backend: &mut B,
     ) -> io::Result<()> {
-        self.prompt.render(layout, backend)
+        self.prompt.render(layout, backend)?;
+
+        if let Some(ref mut preview) = self.preview {
+            // The prompt leaves the layout on its last line, so the preview starts on the next one
+            layout.offset_y += 1;
+            layout.line_offset = 0;
+            backend.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+            let mut preview_layout =
+                layout.with_max_height(preview_height(self.editor.show_preview, *layout));
+
+            backend.set_fg(Color::DarkGrey)?;
+            preview.render(&mut preview_layout, backend)?;
+            backend.set_fg(Color::Reset)?;
+
+            layout.offset_y = preview_layout.offset_y;
+        }
+
+        Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout)
+        let mut height = self.prompt.height(layout);
+
+        if let Some(ref mut preview) = self.preview {
+            layout.offset_y += 1;
+            layout.line_offset = 0;
+
+            let mut preview_layout =
+                layout.with_max_height(preview_height(self.editor.show_preview, *layout));
+            height += preview.height(&mut preview_layout);
+
+            layout.offset_y = preview_layout.offset_y;
+        }
+
+        height
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -91,34 +192,130 @@ impl Widget for EditorPrompt<'_, '_> {
         }
     }
 
-    fn handle_key(&mut self, _: ui::events::KeyEvent) -> bool {
-        false
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.preview.is_none() {
+            return false;
+        }
+
+        let max_offset = self
+            .ans
+            .lines()
+            .count()
+            .saturating_sub(self.editor.show_preview);
+
+        let preview_offset = match key.code {
+            KeyCode::Up => self.preview_offset.saturating_sub(1),
+            KeyCode::Down => (self.preview_offset + 1).min(max_offset),
+            _ => return false,
+        };
+
+        if preview_offset == self.preview_offset {
+            return false;
+        }
+
+        self.preview_offset = preview_offset;
+        self.update_preview();
+        true
     }
 }
 
+/// The maximum height of the preview, which is at most `show_preview` lines.
+fn preview_height(show_preview: usize, layout: ui::layout::Layout) -> u16 {
+    (show_preview.min(u16::MAX as usize) as u16).min(layout.max_height)
+}
+
 fn map_err(err: io::Error) -> widgets::Text<String> {
     widgets::Text::new(err.to_string())
 }
 
+impl EditorPrompt<'_, '_> {
+    /// Recomputes the preview starting from `preview_offset`.
+    fn update_preview(&mut self) {
+        let lines: Vec<_> = self
+            .ans
+            .lines()
+            .skip(self.preview_offset)
+            .take(self.editor.show_preview)
+            .collect();
+
+        self.preview = Some(widgets::Text::new(lines.join("\n")));
+    }
+
+    /// Runs the editor, moving on to the fallback editors if it cannot be found.
+    fn launch(&mut self) -> Result<(), widgets::Text<String>> {
+        if let Launcher::Sync(ref launcher) = self.editor.launcher {
+            return match launcher(self.path) {
+                Ok(true) => Ok(()),
+                Ok(false) => Err(widgets::Text::new("Editor exited unsuccessfully".into())),
+                Err(e) => Err(map_err(e)),
+            };
+        }
+
+        // The error should mention the editor that was configured, not the last fallback tried
+        let editor_name = self.editor.editor_args.as_ref().map(|args| args[0].clone());
+
+        let status = loop {
+            match self.editor.editor.status() {
+                Ok(status) => break status,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    if self.editor.fallbacks.is_empty() {
+                        return Err(widgets::Text::new(match editor_name {
+                            Some(name) => {
+                                format!("Editor '{}' not found; set $VISUAL or $EDITOR", name)
+                            }
+                            None => "Editor not found".into(),
+                        }));
+                    }
+
+                    let editor_args = self.editor.fallbacks.remove(0);
+                    self.editor.editor = to_command(&editor_args);
+                    self.editor.editor.arg(self.path);
+                    self.editor.editor_args = Some(editor_args);
+                }
+                Err(e) => return Err(map_err(e)),
+            }
+        };
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(widgets::Text::new(match self.editor.editor_args {
+                Some(ref args) => {
+                    format!("Editor '{}' exited unsuccessfully ({})", args[0], status)
+                }
+                None => format!("Editor exited unsuccessfully ({})", status),
+            }))
+        }
+    }
+}
+
 impl ui::Prompt for EditorPrompt<'_, '_> {
     type ValidateErr = widgets::Text<String>;
     type Output = String;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        if !self.editor.editor.status().map_err(map_err)?.success() {
-            return Err(map_err(io::Error::new(
-                io::ErrorKind::Other,
-                "Could not open editor",
-            )));
-        }
+        self.preview = None;
+        self.preview_offset = 0;
+
+        self.launch()?;
 
         self.ans.clear();
         self.file.read_to_string(&mut self.ans).map_err(map_err)?;
         self.file.seek(SeekFrom::Start(0)).map_err(map_err)?;
 
+        // Editors commonly add a trailing newline, so whitespace alone is also treated as empty
+        if self.editor.required && self.ans.trim().is_empty() {
+            return Err(widgets::Text::new(REQUIRED_ERROR.into()));
+        }
+
         if let Validate::Sync(ref mut validate) = self.editor.validate {
-            validate(&self.ans, self.answers)
-                .map_err(|err| map_err(io::Error::new(io::ErrorKind::InvalidInput, err)))?;
+            if let Err(err) = validate(&self.ans, self.answers) {
+                if self.editor.show_preview > 0 {
+                    self.update_preview();
+                }
+
+                return Err(map_err(io::Error::new(io::ErrorKind::InvalidInput, err)));
+            }
         }
 
         Ok(Validation::Finish)
@@ -133,10 +330,39 @@ impl ui::Prompt for EditorPrompt<'_, '_> {
 }
 
 impl Editor<'_> {
+    /// Runs `validate` against the default, if there is one.
+    pub(crate) fn check_default(&mut self, answers: &Answers) -> Option<Result<(), String>> {
+        match (&self.default, &mut self.validate) {
+            (Some(default), Validate::Sync(validate)) => Some(validate(default, answers)),
+            _ => None,
+        }
+    }
+
+    /// Validates and filters the value as if it was read from the file after the editor exited.
+    pub(crate) fn answer_with(mut self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let ans = value
+            .try_into_string()
+            .expect("`editor` questions are answered with a `String`");
+
+        if self.required && ans.trim().is_empty() {
+            return Err(super::invalid_answer(REQUIRED_ERROR));
+        }
+
+        if let Validate::Sync(ref mut validate) = self.validate {
+            validate(&ans, answers).map_err(super::invalid_answer)?;
+        }
+
+        Ok(Answer::String(match self.filter {
+            Filter::Sync(filter) => filter(ans, answers),
+            _ => ans,
+        }))
+    }
+
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -153,6 +379,20 @@ impl Editor<'_> {
             file.write_all(default.as_bytes())?;
             file.seek(SeekFrom::Start(0))?;
             file.flush()?;
+        } else if let Some(ref default_file) = self.default_file {
+            let mut default = File::open(default_file).map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!(
+                        "Could not read the default from '{}': {}",
+                        default_file.display(),
+                        e
+                    ),
+                )
+            })?;
+            io::copy(&mut default, &mut file)?;
+            file.seek(SeekFrom::Start(0))?;
+            file.flush()?;
         }
 
         let transform = self.transform.take();
@@ -167,18 +407,28 @@ impl Editor<'_> {
             EditorPrompt {
                 prompt: widgets::Prompt::new(&*message)
                     .with_hint("Press <enter> to launch your preferred editor.")
-                    .with_delim(widgets::Delimiter::None),
+                    .with_delim(widgets::Delimiter::None)
+                    .with_optional_hint_style(self.hint_style)
+                    .with_required(self.required)
+                    .with_optional_required_style(self.required_style),
                 editor: self,
                 file,
+                path: &path,
                 ans: String::new(),
+                preview: None,
+                preview_offset: 0,
                 answers,
             },
             b,
         )
-        .on_esc(on_esc)
+        .on_esc(exit.on_esc)
+        .confirm_on_interrupt(exit.interrupt_confirm)
+        .submit_on_tab(exit.submit_on_tab)
+        .back_on_back_tab(exit.back_on_back_tab)
+        .ensure_newline(exit.ensure_newline)
         .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |_ans| b
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |_ans| b
             .write_styled(&"Received".dark_grey())?)
     }
 }
@@ -188,7 +438,8 @@ impl Editor<'_> {
 /// Once the user exits their editor, the contents of the temporary file are read in as the
 /// result. The editor to use can be specified by the [`editor`] method. If unspecified, the editor
 /// is determined by the `$VISUAL` or `$EDITOR` environment variables. If neither of those are
-/// present, `vim` (for unix) or `notepad` (for windows) is used.
+/// present, `vim` (for unix) or `notepad` (for windows) is used. If the editor cannot be found, the
+/// remaining candidates (`$EDITOR`, then `vim`, `vi` and `nano` on unix) are tried in order.
 ///
 /// <img
 ///   src="https://raw.githubusercontent.com/lutetium-vanadium/requestty/master/assets/editor.gif"
@@ -260,6 +511,17 @@ impl<'a> EditorBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let editor = Question::editor("description")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -270,6 +532,39 @@ impl<'a> EditorBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the file
@@ -288,6 +583,38 @@ impl<'a> EditorBuilder<'a> {
     /// ```
     pub fn default<I: Into<String>>(mut self, default: I) -> Self {
         self.editor.default = Some(default.into());
+        self.editor.default_file = None;
+        self
+    }
+
+    /// Set a file to read the default value for the file from
+    ///
+    /// If set, when the user first opens the file, it will contain the contents of the file at
+    /// `path`, which is read when the question is asked. This is useful to edit an existing
+    /// document. The file at `path` is not modified. If it cannot be read, for example because it
+    /// does not exist, an [`IoError`] mentioning `path` is returned.
+    ///
+    /// Only one of [`default`] and `default_from_file` can be set, so this replaces a previously
+    /// set [`default`], and vice versa. Unlike [`default`], the contents of the file are not
+    /// checked by [`check_defaults`].
+    ///
+    /// [`IoError`]: crate::ErrorKind::IoError
+    /// [`default`]: EditorBuilder::default
+    /// [`check_defaults`]: crate::PromptModule::check_defaults
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("readme")
+    ///     .default_from_file("README.md")
+    ///     .extension(".md")
+    ///     .build();
+    /// ```
+    pub fn default_from_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.editor.default_file = Some(path.into());
+        self.editor.default = None;
         self
     }
 
@@ -317,6 +644,8 @@ impl<'a> EditorBuilder<'a> {
     /// variables. If neither of those are present, `vim` (for unix) or `notepad` (for windows) is
     /// used.
     ///
+    /// Unlike the default editor, no other editors are tried if the given one cannot be found.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -331,6 +660,62 @@ impl<'a> EditorBuilder<'a> {
     /// ```
     pub fn editor<E: Into<Command>>(mut self, editor: E) -> Self {
         self.editor.editor = editor.into();
+        self.editor.editor_args = None;
+        self.editor.fallbacks.clear();
+        self
+    }
+
+    /// Use a custom function to launch the editor instead of running a [`Command`]
+    ///
+    /// The function is given the path to the temporary file, and should return whether the file
+    /// was edited successfully. When set, it completely replaces the [`editor`] and the default
+    /// editor, which is useful for tests and sandboxed environments.
+    ///
+    /// [`editor`]: EditorBuilder::editor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .launcher(|path| {
+    ///         std::fs::write(path, "My name is requestty")?;
+    ///         Ok(true)
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn launcher<F>(mut self, launcher: F) -> Self
+    where
+        F: Fn(&Path) -> io::Result<bool> + 'a,
+    {
+        self.editor.launcher = Launcher::Sync(Box::new(launcher));
+        self
+    }
+
+    /// Show a preview of the entered content after validation fails
+    ///
+    /// If set, the first `lines` lines of what the user wrote are shown dimmed below the prompt so
+    /// that they can recall what they entered. Lines longer than the terminal width are wrapped,
+    /// and the preview can be scrolled with the up and down arrow keys if the content is longer
+    /// than `lines`. It is not shown if `lines` is `0`, which is the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .validate(|description, previous_answers| if description.lines().count() >= 2 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Please enter a few lines".to_owned())
+    ///     })
+    ///     .show_preview(3)
+    ///     .build();
+    /// ```
+    pub fn show_preview(mut self, lines: usize) -> Self {
+        self.editor.show_preview = lines;
         self
     }
 
@@ -348,6 +733,35 @@ impl<'a> EditorBuilder<'a> {
     String; editor
     }
 
+    crate::impl_required_builder! {
+    required
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let description = Question::editor("description")
+    ///     .required(true)
+    ///     .build();
+    /// ```
+
+    required_style
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{
+    ///     prompt::style::{Color, Style},
+    ///     Question,
+    /// };
+    ///
+    /// let description = Question::editor("description")
+    ///     .required(true)
+    ///     .required_style(Style::new().fg(Color::Magenta))
+    ///     .build();
+    /// ```
+    editor
+    }
+
     crate::impl_validate_builder! {
     /// # Examples
     ///
@@ -365,6 +779,19 @@ impl<'a> EditorBuilder<'a> {
     str; editor
     }
 
+    crate::impl_hint_style_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Attributes, Color, Style}, Question};
+    ///
+    /// let editor = Question::editor("description")
+    ///     .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD))
+    ///     .build();
+    /// ```
+    editor
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -377,6 +804,20 @@ impl<'a> EditorBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .transform_with_layout(|description, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         let lines = format!("{} lines", description.lines().count());
+    ///         write!(backend, "{:>1$}", lines, layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
     str; editor
     }
 
@@ -384,7 +825,10 @@ impl<'a> EditorBuilder<'a> {
     ///
     /// [`Question`]: crate::question::Question
     pub fn build(self) -> Question<'a> {
-        Question::new(self.opts, super::QuestionKind::Editor(self.editor))
+        Question::new(
+            self.opts,
+            super::QuestionKind::Editor(Box::new(self.editor)),
+        )
     }
 }
 
@@ -397,4 +841,226 @@ impl<'a> From<EditorBuilder<'a>> for Question<'a> {
     }
 }
 
-// TODO: figure out a way to write tests for this
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn launch_with(editor: Editor<'_>) -> Result<(), String> {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let (file, path) = file.into_parts();
+
+        EditorPrompt {
+            prompt: widgets::Prompt::new("message"),
+            editor,
+            file,
+            path: &path,
+            ans: String::new(),
+            preview: None,
+            preview_offset: 0,
+            answers: &Answers::default(),
+        }
+        .launch()
+        .map_err(|e| e.text)
+    }
+
+    fn unwrap_editor(question: Question<'_>) -> Editor<'_> {
+        match question.kind {
+            crate::question::QuestionKind::Editor(editor) => *editor,
+            _ => unreachable!(),
+        }
+    }
+
+    fn editor_from(candidates: &[&str]) -> Editor<'static> {
+        let mut candidates: Vec<Vec<String>> =
+            candidates.iter().map(|&c| vec![c.to_owned()]).collect();
+        let editor_args = candidates.remove(0);
+
+        Editor {
+            editor: to_command(&editor_args),
+            editor_args: Some(editor_args),
+            fallbacks: candidates,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_editor_not_found() {
+        assert_eq!(
+            launch_with(editor_from(&["requestty-nonexistent-editor"])),
+            Err("Editor 'requestty-nonexistent-editor' not found; set $VISUAL or $EDITOR".into())
+        );
+
+        let editor = Editor {
+            editor: Command::new("requestty-nonexistent-editor"),
+            editor_args: None,
+            fallbacks: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(launch_with(editor), Err("Editor not found".into()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_editor_fallback() {
+        assert_eq!(
+            launch_with(editor_from(&[
+                "requestty-nonexistent-editor",
+                "requestty-other-nonexistent-editor",
+                "true",
+            ])),
+            Ok(())
+        );
+
+        assert_eq!(
+            launch_with(editor_from(&[
+                "requestty-nonexistent-editor",
+                "requestty-other-nonexistent-editor",
+            ])),
+            Err("Editor 'requestty-nonexistent-editor' not found; set $VISUAL or $EDITOR".into())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_editor_exit_failure() {
+        assert_eq!(
+            launch_with(editor_from(&["false", "true"])),
+            Err("Editor 'false' exited unsuccessfully (exit status: 1)".into())
+        );
+    }
+
+    #[test]
+    fn test_launcher() {
+        let editor = EditorBuilder::new("name".into())
+            .editor(Command::new("requestty-nonexistent-editor"))
+            .launcher(|path| {
+                std::fs::write(path, "written by the launcher")?;
+                Ok(true)
+            })
+            .build();
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let (file, path) = file.into_parts();
+        let answers = Answers::default();
+
+        let mut prompt = EditorPrompt {
+            prompt: widgets::Prompt::new("message"),
+            editor: unwrap_editor(editor),
+            file,
+            path: &path,
+            ans: String::new(),
+            preview: None,
+            preview_offset: 0,
+            answers: &answers,
+        };
+
+        assert_eq!(ui::Prompt::validate(&mut prompt), Ok(Validation::Finish));
+        assert_eq!(ui::Prompt::finish(prompt), "written by the launcher");
+
+        let editor = Editor {
+            launcher: Launcher::Sync(Box::new(|_| Ok(false))),
+            ..editor_from(&["requestty-nonexistent-editor"])
+        };
+        assert_eq!(
+            launch_with(editor),
+            Err("Editor exited unsuccessfully".into())
+        );
+    }
+
+    #[test]
+    fn test_show_preview() {
+        let editor = EditorBuilder::new("name".into())
+            .launcher(|path| {
+                std::fs::write(
+                    path,
+                    "line 1\nline 2 is long enough that it has to wrap\nline 3\nline 4\nline 5",
+                )?;
+                Ok(true)
+            })
+            .validate(|_, _| Err("invalid".into()))
+            .show_preview(3)
+            .build();
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let (file, path) = file.into_parts();
+        let answers = Answers::default();
+
+        let mut prompt = EditorPrompt {
+            prompt: widgets::Prompt::new("message")
+                .with_hint("Press <enter> to launch your preferred editor.")
+                .with_delim(widgets::Delimiter::None),
+            editor: unwrap_editor(editor),
+            file,
+            path: &path,
+            ans: String::new(),
+            preview: None,
+            preview_offset: 0,
+            answers: &answers,
+        };
+
+        assert!(ui::Prompt::validate(&mut prompt).is_err());
+
+        let size = (30, 20).into();
+        let base_layout = ui::layout::Layout::new(0, size);
+        let mut backend = ui::backend::TestBackend::new(size);
+
+        for &key in [KeyCode::Down, KeyCode::Down, KeyCode::Down].iter() {
+            let mut layout = base_layout;
+            backend.reset_with_layout(layout);
+            assert!(prompt.render(&mut layout, &mut backend).is_ok());
+            ui::assert_backend_snapshot!(backend);
+            assert_eq!(
+                prompt.height(&mut base_layout.clone()),
+                layout.offset_y - base_layout.offset_y
+            );
+
+            prompt.handle_key(key.into());
+        }
+
+        assert_eq!(prompt.preview_offset, 2);
+    }
+
+    #[test]
+    fn test_default_from_file() {
+        let mut default = tempfile::NamedTempFile::new().unwrap();
+        default.write_all(b"line 1\nline 2\n").unwrap();
+
+        let opened = std::cell::RefCell::new(String::new());
+        let editor = EditorBuilder::new("name".into())
+            .default_from_file(default.path())
+            .launcher(|path| {
+                *opened.borrow_mut() = std::fs::read_to_string(path)?;
+                Ok(true)
+            });
+
+        let mut backend = ui::backend::TestBackend::new((50, 20).into());
+        let mut events = ui::events::TestEvents::new(Some(KeyCode::Enter.into()));
+        let answer = crate::prompt_one_with(editor, &mut backend, &mut events).unwrap();
+
+        assert_eq!(*opened.borrow(), "line 1\nline 2\n");
+        assert_eq!(answer.as_string(), Some("line 1\nline 2\n"));
+        // The original file is left untouched
+        assert_eq!(
+            std::fs::read_to_string(default.path()).unwrap(),
+            "line 1\nline 2\n"
+        );
+
+        let path = default.path().to_owned();
+        drop(default);
+
+        let editor = EditorBuilder::new("name".into())
+            .default("replaced")
+            .default_from_file(&path)
+            .launcher(|_| Ok(true));
+        let err = crate::prompt_one_with(editor, &mut backend, &mut events).unwrap_err();
+
+        assert!(matches!(
+            err,
+            crate::ErrorKind::IoError(ref e) if e.kind() == io::ErrorKind::NotFound
+                && e.to_string().starts_with(&format!(
+                    "Could not read the default from '{}'",
+                    path.display()
+                ))
+        ));
+    }
+}
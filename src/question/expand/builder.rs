@@ -13,6 +13,10 @@ use crate::{
 /// The keys are ascii case-insensitive characters. The 'h' option is added by the prompt and
 /// shouldn't be defined.
 ///
+/// Keys can also be made of multiple characters (for example `"sa"`), in which case the user
+/// types out the full key. A key is allowed to be the prefix of another key (for example `"s"` and
+/// `"sa"`), and the shorter key is chosen if the user presses `Enter` after typing it.
+///
 /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
 /// but [`Choice::Separator`]s can only be single line.
 ///
@@ -45,7 +49,7 @@ use crate::{
 pub struct ExpandBuilder<'a> {
     opts: Options<'a>,
     expand: Expand<'a>,
-    keys: HashSet<char>,
+    keys: HashSet<String>,
 }
 
 impl<'a> ExpandBuilder<'a> {
@@ -94,6 +98,17 @@ impl<'a> ExpandBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -104,6 +119,39 @@ impl<'a> ExpandBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default key for the expand
@@ -128,8 +176,8 @@ impl<'a> ExpandBuilder<'a> {
     ///     .default('d')
     ///     .build();
     /// ```
-    pub fn default(mut self, default: char) -> Self {
-        self.expand.default = default;
+    pub fn default<K: Into<String>>(mut self, default: K) -> Self {
+        self.expand.default = default.into();
         self
     }
 
@@ -160,6 +208,82 @@ impl<'a> ExpandBuilder<'a> {
         self
     }
 
+    /// Fit the page size to the height of the terminal
+    ///
+    /// The page size is recomputed whenever the prompt is rendered, so it adapts if the terminal
+    /// is resized. It leaves space for the rest of the prompt, and is still a minimum of 5.
+    ///
+    /// This is overridden by [`page_size`](Self::page_size) if it is called after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .page_size_auto()
+    ///     .build();
+    /// ```
+    pub fn page_size_auto(mut self) -> Self {
+        self.expand.choices.set_page_size_auto();
+        self
+    }
+
+    /// The number of columns the choices are shown in when the user expands the prompt
+    ///
+    /// The choices are filled row by row in columns of equal width, and text that does not fit is
+    /// cut short. Unlike the single column list, separators are not shown, and all the choices are
+    /// shown at once instead of a page at a time.
+    ///
+    /// If `columns` is not set, it will default to 1.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if `columns` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .choice('y', "Overwrite")
+    ///     .choice('n', "Skip")
+    ///     .choice('a', "Overwrite all")
+    ///     .choice('d', "Show diff")
+    ///     .columns(2)
+    ///     .build();
+    /// ```
+    pub fn columns(mut self, columns: usize) -> Self {
+        assert!(columns > 0, "there must be at least one column");
+
+        self.expand.columns = columns;
+        self
+    }
+
+    /// Show the choices in as many columns as fit in the terminal when the user expands the prompt
+    ///
+    /// See [`columns`](Self::columns) for how the choices are laid out. The number of columns is
+    /// recomputed whenever the prompt is rendered, so it adapts if the terminal is resized.
+    ///
+    /// This is overridden by [`columns`](Self::columns) if it is called after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .choice('y', "Overwrite")
+    ///     .choice('n', "Skip")
+    ///     .columns_auto()
+    ///     .build();
+    /// ```
+    pub fn columns_auto(mut self) -> Self {
+        self.expand.columns = 0;
+        self
+    }
+
     /// Whether to wrap around when user gets to the last element.
     ///
     /// If `should_loop` is not set, it will default to `true`. It will only be used if the user
@@ -179,6 +303,54 @@ impl<'a> ExpandBuilder<'a> {
         self
     }
 
+    /// The minimum number of elements to keep visible above and below the hovered element when
+    /// the list is scrolled.
+    ///
+    /// The margin is kept where possible. It is capped based on the [`page_size`], and cannot be
+    /// kept near the ends of the list if [`should_loop`] is `false`. If `scroll_margin` is not
+    /// set, it will default to 1. It will only be used if the user
+    /// expands the prompt.
+    ///
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .scroll_margin(3)
+    ///     .build();
+    /// ```
+    pub fn scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.expand.choices.set_scroll_margin(scroll_margin);
+        self
+    }
+
+    /// The width of the gutter on the left of every element in the list.
+    ///
+    /// It can be 0, 1 or 2. If `gutter_width` is not set, it will default to 2. It will only be used
+    /// if the user expands the prompt.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `gutter_width` is more than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .gutter_width(0)
+    ///     .build();
+    /// ```
+    pub fn gutter_width(mut self, gutter_width: u16) -> Self {
+        self.expand.choices.set_gutter_width(gutter_width);
+        self
+    }
+
     /// Inserts a [`Choice`] with the given key and text
     ///
     /// See [`expand`] for more information.
@@ -188,7 +360,7 @@ impl<'a> ExpandBuilder<'a> {
     ///
     /// # Panics
     ///
-    /// It will panic if the key is 'h' or a duplicate.
+    /// It will panic if the key is 'h', empty or a duplicate.
     ///
     /// # Examples
     ///
@@ -197,19 +369,11 @@ impl<'a> ExpandBuilder<'a> {
     ///
     /// let expand = Question::expand("overwrite")
     ///     .choice('x', "Abort")
+    ///     .choice("sa", "Save all")
     ///     .build();
     /// ```
-    pub fn choice<I: Into<String>>(mut self, mut key: char, text: I) -> Self {
-        key = key.to_ascii_lowercase();
-
-        if key == 'h' {
-            panic!("Reserved key 'h'");
-        }
-        if self.keys.contains(&key) {
-            panic!("Duplicate key '{}'", key);
-        }
-
-        self.keys.insert(key);
+    pub fn choice<K: Into<String>, I: Into<String>>(mut self, key: K, text: I) -> Self {
+        let key = add_key(&mut self.keys, key.into());
 
         self.expand.choices.choices.push(Choice::Choice(ExpandText {
             key,
@@ -273,7 +437,7 @@ impl<'a> ExpandBuilder<'a> {
     ///
     /// # Panics
     ///
-    /// It will panic if the key of any choice is 'h' or a duplicate.
+    /// It will panic if the key of any choice is 'h', empty or a duplicate.
     ///
     /// # Examples
     ///
@@ -300,26 +464,41 @@ impl<'a> ExpandBuilder<'a> {
         } = self;
 
         expand.choices.choices.extend(choices.into_iter().map(|c| {
-            c.into().map(|ExpandItem { text, mut key }| {
-                key = key.to_ascii_lowercase();
-                if key == 'h' {
-                    panic!("Reserved key 'h'");
-                }
-                if keys.contains(&key) {
-                    panic!("Duplicate key '{}'", key);
-                }
-                keys.insert(key);
-
-                ExpandText {
-                    text: Text::new(text),
-                    key,
-                }
+            c.into().map(|ExpandItem { text, key }| ExpandText {
+                text: Text::new(text),
+                key: add_key(keys, key),
             })
         }));
 
         self
     }
 
+    crate::impl_hint_style_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Attributes, Color, Style}, Question};
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD))
+    ///     .build();
+    /// ```
+    expand
+    }
+
+    crate::impl_hide_cursor_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .hide_cursor(false)
+    ///     .build();
+    /// ```
+    expand
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -332,13 +511,26 @@ impl<'a> ExpandBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .transform_with_layout(|choice, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         write!(backend, "{:>1$}", choice.text, layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
     ExpandItem; expand
     }
 
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
-    pub fn build(self) -> crate::question::Question<'a> {
+    pub fn build(mut self) -> crate::question::Question<'a> {
         if !self.expand.has_valid_default() {
             panic!(
                 "Invalid default '{}' does not occur in the given choices",
@@ -346,6 +538,13 @@ impl<'a> ExpandBuilder<'a> {
             );
         }
 
+        self.expand.key_width = self
+            .keys
+            .iter()
+            .map(|key| key.chars().count() as u16)
+            .max()
+            .unwrap_or(1);
+
         crate::question::Question::new(
             self.opts,
             crate::question::QuestionKind::Expand(self.expand),
@@ -361,3 +560,21 @@ impl<'a> From<ExpandBuilder<'a>> for crate::question::Question<'a> {
         builder.build()
     }
 }
+
+/// Checks that the key is valid and adds it to the set of keys, returning the normalised key.
+fn add_key(keys: &mut HashSet<String>, mut key: String) -> String {
+    key.make_ascii_lowercase();
+
+    if key.is_empty() {
+        panic!("Empty key");
+    }
+    if key == super::HELP_KEY {
+        panic!("Reserved key 'h'");
+    }
+    if keys.contains(&key) {
+        panic!("Duplicate key '{}'", key);
+    }
+
+    keys.insert(key.clone());
+    key
+}
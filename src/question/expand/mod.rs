@@ -2,13 +2,13 @@ use std::io;
 
 use ui::{
     backend::{Backend, MoveDirection},
-    events::{EventIterator, KeyEvent},
+    events::{EventIterator, KeyCode, KeyEvent},
     style::{Color, Stylize},
     widgets::{self, Text},
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Transform};
+use super::{choice::Placement, Choice, Transform};
 use crate::{Answer, Answers, ExpandItem};
 pub use builder::ExpandBuilder;
 
@@ -17,9 +17,9 @@ mod builder;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct ExpandText {
-    key: char,
+    key: String,
     text: Text<String>,
 }
 
@@ -48,32 +48,167 @@ impl Widget for ExpandText {
 #[derive(Debug)]
 pub(super) struct Expand<'a> {
     choices: super::ChoiceList<ExpandText>,
-    selected: Option<char>,
-    default: char,
+    selected: Option<String>,
+    default: String,
+    /// The width of the longest key, used to align the choices
+    key_width: u16,
+    /// The style of the hint, or the default style if `None`.
+    hint_style: Option<ui::style::Style>,
+    /// Whether the cursor is shown while the prompt is running.
+    show_cursor: bool,
+    /// The number of columns the expanded list is shown in, or 0 to fit as many as possible.
+    columns: usize,
     transform: Transform<'a, ExpandItem>,
 }
 
 impl<'a> Default for Expand<'a> {
     fn default() -> Self {
         Expand {
-            default: 'h',
+            default: HELP_KEY.into(),
             selected: None,
+            key_width: 1,
+            hint_style: None,
+            show_cursor: false,
+            columns: 1,
             choices: Default::default(),
             transform: Transform::None,
         }
     }
 }
 
-struct ExpandPrompt<'a, F> {
+impl Expand<'_> {
+    /// Returns a copy of the expand, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            choices: self.choices.clone(),
+            selected: self.selected.clone(),
+            default: self.default.clone(),
+            key_width: self.key_width,
+            hint_style: self.hint_style,
+            show_cursor: self.show_cursor,
+            columns: self.columns,
+            transform: self.transform.try_clone()?,
+        })
+    }
+}
+
+const HELP_KEY: &str = "h";
+const HELP_TEXT: &str = "Help, list all options";
+
+struct ExpandPrompt<'a> {
     prompt: widgets::Prompt<&'a str, &'a str>,
     select: widgets::Select<Expand<'a>>,
-    input: widgets::CharInput<F>,
+    input: widgets::StringInput,
     expanded: bool,
 }
 
-impl<F: Fn(char) -> Option<char>> ExpandPrompt<'_, F> {
+impl ExpandPrompt<'_> {
+    /// Fits the page size to the terminal height, leaving space for the prompt and the answer line.
+    fn fit_page_size(&mut self, layout: ui::layout::Layout) {
+        let reserved = self.prompt.height(&mut layout.clone()) + 1;
+        self.select.list.choices.fit_page_size(layout, reserved);
+    }
+
+    /// Lays out the expanded list in columns. Separators are not shown, and all the choices are
+    /// shown at once.
+    fn placement(&self, width: u16) -> Placement {
+        let expand = &self.select.list;
+        let columns = match expand.columns {
+            0 => None,
+            columns => Some(columns),
+        };
+
+        let items = expand
+            .choices
+            .choices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, choice)| match choice {
+                Choice::Choice(choice) => Some((i, &*choice.text.text)),
+                _ => None,
+            })
+            .chain(std::iter::once((expand.choices.len(), HELP_TEXT)));
+
+        Placement::grid(items, columns, expand.key_offset(), width)
+    }
+
+    /// The index of the selected choice in the expanded list, which is one past the choices for
+    /// the help option.
+    fn selected_index(&self) -> Option<usize> {
+        let expand = &self.select.list;
+        let selected = expand.selected.as_deref()?;
+
+        if selected == HELP_KEY {
+            return Some(expand.choices.len());
+        }
+
+        expand
+            .choices
+            .choices
+            .iter()
+            .position(|choice| matches!(choice, Choice::Choice(choice) if choice.key == selected))
+    }
+
+    fn render_list<B: Backend>(
+        &mut self,
+        layout: &mut ui::layout::Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        if self.select.list.columns == 1 {
+            return self.select.render(layout, b);
+        }
+
+        let at = self.selected_index().unwrap_or(usize::MAX);
+        let expand = &self.select.list;
+
+        self.placement(layout.available_width()).render(
+            at,
+            usize::MAX,
+            layout,
+            b,
+            |index, hovered, b| expand.write_key(index, hovered, b),
+        )
+    }
+
+    fn list_height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        if self.select.list.columns == 1 {
+            return self.select.height(layout);
+        }
+
+        let at = self.selected_index().unwrap_or(usize::MAX);
+        self.placement(layout.available_width())
+            .height(at, usize::MAX, layout)
+    }
+
+    fn choice_keys(&self) -> impl Iterator<Item = &str> {
+        self.select
+            .list
+            .choices
+            .choices
+            .iter()
+            .filter_map(|choice| match choice {
+                Choice::Choice(choice) => Some(&*choice.key),
+                _ => None,
+            })
+    }
+
+    /// Returns the key that would be chosen if the user submitted now
+    fn key(&self) -> &str {
+        match self.input.value() {
+            "" => &self.select.list.default,
+            value => value,
+        }
+    }
+
+    /// Whether the input is either the help key or a key of a choice, and not just a prefix of a
+    /// key
+    fn has_selection(&self) -> bool {
+        let value = self.input.value();
+        value == HELP_KEY || self.choice_keys().any(|key| key == value)
+    }
+
     fn selected(&mut self) -> Option<&mut ExpandText> {
-        let key = self.input.value()?;
+        let key = self.input.value();
 
         self.select
             .list
@@ -87,7 +222,33 @@ impl<F: Fn(char) -> Option<char>> ExpandPrompt<'_, F> {
             .find(|item| item.key == key)
     }
 
-    fn finish_with(self, c: char) -> ExpandItem {
+    /// Adds the character to the input if it leads to a valid key. If it doesn't, the character
+    /// alone is used if it is the start of a key. This way, when all keys are single characters,
+    /// typing a different key simply replaces the previous one.
+    fn push_key_char(&mut self, c: char) -> bool {
+        let c = c.to_ascii_lowercase();
+        let is_prefix = |this: &Self, value: &str| {
+            HELP_KEY.starts_with(value) || this.choice_keys().any(|key| key.starts_with(value))
+        };
+
+        let mut value = self.input.value().to_owned();
+        value.push(c);
+
+        if !is_prefix(self, &value) {
+            value = c.to_string();
+
+            if !is_prefix(self, &value) {
+                return false;
+            }
+        }
+
+        let len = value.chars().count();
+        self.input.set_value(value);
+        self.input.set_at(len);
+        true
+    }
+
+    fn finish_with(self, key: &str) -> ExpandItem {
         let item = self
             .select
             .into_inner()
@@ -98,7 +259,7 @@ impl<F: Fn(char) -> Option<char>> ExpandPrompt<'_, F> {
                 Choice::Choice(choice) => Some(choice),
                 _ => None,
             })
-            .find(|item| item.key == c)
+            .find(|item| item.key == key)
             .expect("Validation would fail unless an option was chosen");
 
         ExpandItem {
@@ -108,42 +269,46 @@ impl<F: Fn(char) -> Option<char>> ExpandPrompt<'_, F> {
     }
 }
 
-impl<F: Fn(char) -> Option<char>> Prompt for ExpandPrompt<'_, F> {
+impl Prompt for ExpandPrompt<'_> {
     type ValidateErr = &'static str;
     type Output = ExpandItem;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        match self.input.value().unwrap_or(self.select.list.default) {
-            'h' => {
-                self.expanded = true;
-                self.input.clear_value();
-                self.select.list.selected = None;
-                Ok(Validation::Continue)
-            }
-            _ => Ok(Validation::Finish),
+        let key = self.key();
+
+        if key == HELP_KEY {
+            self.expanded = true;
+            self.input.set_value(String::new());
+            self.select.list.selected = None;
+            Ok(Validation::Continue)
+        } else if self.choice_keys().any(|k| k == key) {
+            Ok(Validation::Finish)
+        } else {
+            Err("Please enter a complete key")
         }
     }
 
     fn finish(self) -> Self::Output {
-        let c = self.input.value().unwrap_or(self.select.list.default);
-        self.finish_with(c)
+        let key = self.key().to_owned();
+        self.finish_with(&key)
     }
 }
 
 const ANSWER_PROMPT: &[u8] = b"  Answer: ";
 
-impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
+impl ui::Widget for ExpandPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.fit_page_size(*layout);
         self.prompt.render(layout, b)?;
         if self.expanded {
-            self.select.render(layout, b)?;
+            self.render_list(layout, b)?;
             b.write_all(ANSWER_PROMPT)?;
             layout.line_offset = ANSWER_PROMPT.len() as u16;
             self.input.render(layout, b)
         } else {
             self.input.render(layout, b)?;
 
-            if self.input.value().is_some() {
+            if self.has_selection() {
                 b.move_cursor(MoveDirection::NextLine(1))?;
                 b.write_styled(&ui::symbols::current().arrow.cyan())?;
                 b.write_all(b" ")?;
@@ -172,12 +337,14 @@ impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.fit_page_size(*layout);
         if self.expanded {
             // Don't need to add 1 for the answer prompt, since this will over count by 1 anyways
-            let height = self.prompt.height(layout) + self.select.height(layout);
-            layout.line_offset = ANSWER_PROMPT.len() as u16 + self.input.value().is_some() as u16;
+            let height = self.prompt.height(layout) + self.list_height(layout);
+            layout.line_offset = ANSWER_PROMPT.len() as u16;
+            self.input.height(layout);
             height
-        } else if self.input.value().is_some() {
+        } else if self.has_selection() {
             let height = self.prompt.height(layout) - 1 + self.input.height(layout);
 
             layout.offset_y += 1;
@@ -199,8 +366,25 @@ impl<F: Fn(char) -> Option<char>> ui::Widget for ExpandPrompt<'_, F> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        if self.input.handle_key(key) {
-            self.select.list.selected = self.input.value();
+        let handled = match key.code {
+            KeyCode::Char(c) => self.push_key_char(c),
+            KeyCode::Backspace | KeyCode::Delete if !self.input.value().is_empty() => {
+                self.input.replace_with(|mut value| {
+                    value.pop();
+                    value
+                });
+                true
+            }
+            _ => false,
+        };
+
+        if handled {
+            let value = self.input.value();
+            self.select.list.selected = if value.is_empty() {
+                None
+            } else {
+                Some(value.to_owned())
+            };
             true
         } else if self.expanded {
             self.select.handle_key(key)
@@ -234,15 +418,17 @@ impl widgets::List for Expand<'_> {
         b: &mut B,
     ) -> io::Result<()> {
         if index == self.choices.len() {
-            return self.render_choice(None, layout, b);
+            return self.render_choice(index, layout, b);
         }
 
+        let sep_offset = self.choices.gutter_width() + 1;
+
         match &mut self.choices[index] {
-            Choice::Choice(_) => self.render_choice(Some(index), layout, b),
+            Choice::Choice(_) => self.render_choice(index, layout, b),
             separator => {
                 b.set_fg(Color::DarkGrey)?;
-                b.write_all(b"   ")?;
-                super::get_sep_str(separator).render(&mut layout.with_line_offset(3), b)?;
+                write!(b, "{:1$}", "", sep_offset as usize)?;
+                super::render_separator(separator, &mut layout.with_line_offset(sep_offset), b)?;
                 b.set_fg(Color::Reset)
             }
         }
@@ -257,7 +443,7 @@ impl widgets::List for Expand<'_> {
             // Help option
             1
         } else {
-            layout.offset_x += 5;
+            layout.offset_x += self.key_offset();
             self.choices[index].height(&mut layout)
         }
     }
@@ -273,43 +459,98 @@ impl widgets::List for Expand<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn scroll_margin(&self) -> usize {
+        self.choices.scroll_margin()
+    }
 }
 
 impl Expand<'_> {
+    pub(crate) fn answer_with(self, value: Answer) -> ui::Result<Answer> {
+        let key = value
+            .try_into_expand_item()
+            .expect("`expand` questions are answered with an `ExpandItem`")
+            .key
+            .to_ascii_lowercase();
+
+        let key = if key.is_empty() { self.default } else { key };
+
+        self.choices
+            .choices
+            .into_iter()
+            .filter_map(|choice| match choice {
+                Choice::Choice(choice) => Some(choice),
+                _ => None,
+            })
+            .find(|item| item.key == key)
+            .map(|item| {
+                Answer::ExpandItem(ExpandItem {
+                    key: item.key,
+                    text: item.text.text,
+                })
+            })
+            .ok_or_else(|| super::invalid_answer("Please enter a complete key"))
+    }
+
     fn has_valid_default(&self) -> bool {
-        self.default == 'h'
+        self.default == HELP_KEY
             || self.choices.choices.iter().any(
                 |c| matches!(c, Choice::Choice(ExpandText { key, .. }) if *key == self.default),
             )
     }
 
-    fn render_choice<B: Backend>(
-        &mut self,
-        index: Option<usize>,
-        mut layout: ui::layout::Layout,
-        b: &mut B,
-    ) -> io::Result<()> {
-        let key = match index {
-            Some(index) => self.choices[index].as_ref().unwrap_choice().key,
-            None => 'h',
-        };
+    /// The width of the gutter and key before the text of each choice.
+    fn key_offset(&self) -> u16 {
+        self.choices.gutter_width() + 2 + self.key_width
+    }
 
-        let hovered = self.selected.map(|c| c == key).unwrap_or(false);
+    /// The key of the choice at the index, which is one past the choices for the help option.
+    fn key(&self, index: usize) -> &str {
+        if index == self.choices.len() {
+            HELP_KEY
+        } else {
+            &self.choices[index].as_ref().unwrap_choice().key
+        }
+    }
 
+    /// Writes the gutter and key before the text of the choice at the index, and sets the colour
+    /// of the text if it is hovered.
+    fn write_key<B: Backend>(&self, index: usize, hovered: bool, b: &mut B) -> io::Result<()> {
         if hovered {
             b.set_fg(Color::Cyan)?;
         }
 
-        write!(b, "  {}) ", key)?;
+        write!(
+            b,
+            "{:gutter$}{:width$}) ",
+            "",
+            self.key(index),
+            gutter = self.choices.gutter_width() as usize,
+            width = self.key_width as usize
+        )
+    }
 
-        layout.offset_x += 5;
+    /// Renders the choice at the index, which is one past the choices for the help option.
+    fn render_choice<B: Backend>(
+        &mut self,
+        index: usize,
+        mut layout: ui::layout::Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let hovered = self.selected.as_deref() == Some(self.key(index));
+        self.write_key(index, hovered, b)?;
+
+        let key_offset = self.key_offset();
+        layout.offset_x += key_offset;
 
-        match index {
-            Some(index) => self.choices[index]
+        if index == self.choices.len() {
+            let mut help_text = HELP_TEXT;
+            help_text.render(&mut layout.with_line_offset(key_offset), b)?;
+        } else {
+            self.choices[index]
                 .as_mut()
                 .unwrap_choice()
-                .render(&mut layout, b)?,
-            None => "Help, list all options".render(&mut layout.with_line_offset(5), b)?,
+                .render(&mut layout, b)?;
         }
 
         if hovered {
@@ -322,14 +563,22 @@ impl Expand<'_> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
-        let help_key = if self.default == 'h' { 'H' } else { 'h' };
+        let help_key = if self.default == HELP_KEY {
+            HELP_KEY.to_ascii_uppercase()
+        } else {
+            HELP_KEY.into()
+        };
 
-        let hint: String = self
+        // Single character keys are shown together, while longer keys need to be separated
+        let separator = if self.key_width > 1 { "," } else { "" };
+
+        let hint = self
             .choices
             .choices
             .iter()
@@ -337,32 +586,40 @@ impl Expand<'_> {
                 Choice::Choice(choice) if self.default == choice.key => {
                     Some(choice.key.to_ascii_uppercase())
                 }
-                Choice::Choice(choice) => Some(choice.key.to_ascii_lowercase()),
+                Choice::Choice(choice) => Some(choice.key.clone()),
                 _ => None,
             })
             .chain(std::iter::once(help_key))
-            .collect();
+            .collect::<Vec<_>>()
+            .join(separator);
 
         let transform = self.transform.take();
+        let show_cursor = self.show_cursor;
 
-        let ans = ui::Input::new(
+        let mut input = ui::Input::new(
             ExpandPrompt {
-                prompt: widgets::Prompt::new(&*message).with_hint(&hint),
-                input: widgets::CharInput::with_filter_map(|c| {
-                    let c = c.to_ascii_lowercase();
-                    hint.chars()
-                        .find(|o| o.eq_ignore_ascii_case(&c))
-                        .and(Some(c))
-                }),
+                prompt: widgets::Prompt::new(&*message)
+                    .with_hint(&*hint)
+                    .with_optional_hint_style(self.hint_style),
+                input: widgets::StringInput::default(),
                 select: widgets::Select::new(self),
                 expanded: false,
             },
             b,
         )
-        .on_esc(on_esc)
-        .run(events)?;
+        .on_esc(exit.on_esc)
+        .confirm_on_interrupt(exit.interrupt_confirm)
+        .submit_on_tab(exit.submit_on_tab)
+        .back_on_back_tab(exit.back_on_back_tab)
+        .ensure_newline(exit.ensure_newline);
+
+        if !show_cursor {
+            input = input.hide_cursor();
+        }
+
+        let ans = input.run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |ans| b.write_styled(
             &ans.text
                 .lines()
                 .next()
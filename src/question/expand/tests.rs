@@ -65,12 +65,7 @@ macro_rules! expand {
 
         let mut $expand = ExpandPrompt {
             prompt: widgets::Prompt::new($message).with_hint(&hint),
-            input: widgets::CharInput::with_filter_map(|c| {
-                let c = c.to_ascii_lowercase();
-                hint.chars()
-                    .find(|o| o.eq_ignore_ascii_case(&c))
-                    .and(Some(c))
-            }),
+            input: widgets::StringInput::default(),
             select: widgets::Select::new(expand),
             expanded: false,
         };
@@ -224,3 +219,109 @@ fn test_cursor_pos() {
     assert!(expand.handle_key(KeyCode::Char('c').into()));
     assert_eq!(expand.cursor_pos(layout), (11, 10));
 }
+
+fn multi_char_expand(choices: &[(&str, &str)]) -> Expand<'static> {
+    let expand = ExpandBuilder::new("name".into())
+        .choices(choices.iter().map(|&(key, text)| (key, text)))
+        .build();
+
+    match expand.kind {
+        QuestionKind::Expand(e) => e,
+        _ => unreachable!(),
+    }
+}
+
+fn multi_char_prompt(expand: Expand<'_>) -> ExpandPrompt<'_> {
+    ExpandPrompt {
+        prompt: widgets::Prompt::new("message").with_hint("s,sa,x,H"),
+        input: widgets::StringInput::default(),
+        select: widgets::Select::new(expand),
+        expanded: false,
+    }
+}
+
+#[test]
+fn test_multi_char_keys() {
+    let choices = [("s", "Save"), ("sa", "Save all"), ("x", "Exit")];
+
+    let mut expand = multi_char_prompt(multi_char_expand(&choices));
+    assert!(expand.handle_key(KeyCode::Char('s').into()));
+    assert_eq!(expand.input.value(), "s");
+    assert_eq!(expand.selected().map(|item| &*item.text.text), Some("Save"));
+
+    assert!(expand.handle_key(KeyCode::Char('A').into()));
+    assert_eq!(expand.input.value(), "sa");
+    assert_eq!(expand.select.list.selected.as_deref(), Some("sa"));
+    assert_eq!(
+        expand.selected().map(|item| &*item.text.text),
+        Some("Save all")
+    );
+
+    // 'sax' is not a key, so 'x' replaces the previous key
+    assert!(expand.handle_key(KeyCode::Char('x').into()));
+    assert_eq!(expand.input.value(), "x");
+
+    // not the start of any key
+    assert!(!expand.handle_key(KeyCode::Char('z').into()));
+    assert_eq!(expand.input.value(), "x");
+
+    assert!(expand.handle_key(KeyCode::Char('s').into()));
+    assert!(expand.handle_key(KeyCode::Char('a').into()));
+    assert!(expand.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(expand.input.value(), "s");
+
+    assert_eq!(expand.validate(), Ok(Validation::Finish));
+    assert_eq!(expand.finish(), ExpandItem::from(("s", "Save")));
+
+    let mut expand = multi_char_prompt(multi_char_expand(&choices));
+    assert!(expand.handle_key(KeyCode::Char('s').into()));
+    assert!(expand.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(expand.validate(), Ok(Validation::Finish));
+    assert_eq!(expand.finish(), ExpandItem::from(("sa", "Save all")));
+}
+
+#[test]
+fn test_multi_char_partial_key() {
+    let mut expand = multi_char_prompt(multi_char_expand(&[
+        ("sa", "Save all"),
+        ("sb", "Save buffer"),
+    ]));
+
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+
+    assert!(expand.handle_key(KeyCode::Char('s').into()));
+    assert!(expand.selected().is_none());
+    // Only a prefix has been typed, so there is nothing to show below the prompt
+    let mut layout = base_layout;
+    assert_eq!(expand.height(&mut layout), 1);
+    assert_eq!(expand.validate(), Err("Please enter a complete key"));
+
+    assert!(expand.handle_key(KeyCode::Char('b').into()));
+    let mut layout = base_layout;
+    assert_eq!(expand.height(&mut layout), 2);
+    assert_eq!(expand.validate(), Ok(Validation::Finish));
+    assert_eq!(expand.finish(), ExpandItem::from(("sb", "Save buffer")));
+}
+
+#[test]
+fn test_multi_char_render() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+
+    let mut expand = multi_char_prompt(multi_char_expand(&[
+        ("s", "Save"),
+        ("sa", "Save all"),
+        ("x", "Exit"),
+    ]));
+
+    assert_eq!(expand.validate(), Ok(Validation::Continue));
+    assert!(expand.handle_key(KeyCode::Char('s').into()));
+    assert!(expand.handle_key(KeyCode::Char('a').into()));
+
+    let mut layout = base_layout;
+    assert!(expand.render(&mut layout, &mut backend).is_ok());
+    ui::assert_backend_snapshot!(backend);
+    assert_eq!(layout, base_layout.with_offset(0, 5).with_line_offset(12));
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use ui::backend::Backend;
+use ui::{backend::Backend, layout::Layout};
 
 use super::Completions;
 use crate::Answers;
@@ -17,6 +17,16 @@ macro_rules! handler {
             pub(super) fn take(&mut self) -> Self {
                 std::mem::replace(self, Self::None)
             }
+
+            /// Returns a copy of the handler, or `None` if it has a function, which cannot be
+            /// cloned.
+            #[allow(unused)]
+            pub(super) fn try_clone(&self) -> Option<Self> {
+                match self {
+                    Self::Sync(_) => None,
+                    Self::None => Some(Self::None),
+                }
+            }
         }
 
         impl<T> Default for $name<'_, T> {
@@ -47,6 +57,16 @@ macro_rules! handler {
             pub(super) fn take(&mut self) -> Self {
                 std::mem::replace(self, Self::None)
             }
+
+            /// Returns a copy of the handler, or `None` if it has a function, which cannot be
+            /// cloned.
+            #[allow(unused)]
+            pub(super) fn try_clone(&self) -> Option<Self> {
+                match self {
+                    Self::Sync(_) => None,
+                    Self::None => Some(Self::None),
+                }
+            }
         }
 
         impl<T: ?Sized> Default for $name<'_, T> {
@@ -72,8 +92,42 @@ handler!(Validate, ?Sized FnMut(&T, &Answers) -> Result<(), String>);
 handler!(ValidateByVal, FnMut(T, &Answers) -> Result<(), String>);
 handler!(ValidateOnKey, ?Sized FnMut(&T, &Answers) -> bool);
 handler!(ValidateOnKeyByVal, FnMut(T, &Answers) -> bool);
-handler!(Transform, ?Sized FnOnce(&T, &Answers, &mut dyn Backend) -> std::io::Result<()>);
+handler!(Predicate, ?Sized Fn(&T) -> bool);
+handler!(Compare, ?Sized Fn(&T, &T) -> std::cmp::Ordering);
+handler!(Launcher, ?Sized Fn(&T) -> std::io::Result<bool>);
+handler!(Format, ?Sized FnOnce(&T) -> String);
+handler!(FormatByVal, Fn(T) -> String);
+handler!(
+    Transform,
+    ?Sized FnOnce(&T, &Answers, Layout, &mut dyn Backend) -> std::io::Result<()>
+);
+handler!(
+    TransformWithChoices,
+    ?Sized FnOnce(&T, &[&str], &Answers, &mut dyn Backend) -> std::io::Result<()>
+);
 handler!(
     TransformByVal,
-    FnOnce(T, &Answers, &mut dyn Backend) -> std::io::Result<()>
+    FnOnce(T, &Answers, Layout, &mut dyn Backend) -> std::io::Result<()>
 );
+
+impl<'a, T: ?Sized + 'a> TransformWithChoices<'a, T> {
+    /// Turns it into a [`Transform`] which is given the text of the choices returned by
+    /// `choices`. If there is no transform, `transform` is returned instead.
+    pub(super) fn into_transform(
+        self,
+        transform: Transform<'a, T>,
+        choices: impl FnOnce() -> Vec<String>,
+    ) -> Transform<'a, T> {
+        match self {
+            Self::Sync(transform_with_choices) => {
+                let choices = choices();
+
+                Transform::Sync(Box::new(move |ans, answers, _, b| {
+                    let choices: Vec<_> = choices.iter().map(String::as_str).collect();
+                    transform_with_choices(ans, &choices, answers, b)
+                }))
+            }
+            Self::None => transform,
+        }
+    }
+}
@@ -143,18 +143,156 @@ macro_rules! impl_validate_on_key_builder {
 
 #[doc(hidden)]
 #[macro_export]
-macro_rules! impl_transform_builder {
-    ($(#[$meta:meta])+ $t:ty; $inner:ident) => {
-        $crate::impl_transform_builder!($(#[$meta])* impl &$t; $inner Transform);
+macro_rules! impl_validate_on_change_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// Run the function given to [`validate`](Self::validate) every time the value changes,
+        /// and show the error (if any) below the prompt while typing.
+        ///
+        /// The error is only informational, and is cleared once the value is valid again. The
+        /// value is still validated when the user presses `Enter`, which is the only validation
+        /// that can prevent a user submission.
+        ///
+        /// If this is not given, it defaults to `false`.
+        ///
+        ///
+        $(#[$meta])+
+        pub fn validate_on_change(mut self, validate_on_change: bool) -> Self {
+            self.$inner.validate_on_change = validate_on_change;
+            self
+        }
     };
+}
 
-    ($(#[$meta:meta])+ by val $t:ty; $inner:ident) => {
-        $crate::impl_transform_builder!($(#[$meta])* impl $t; $inner TransformByVal);
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_on_invalid_hint_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// A hint to show below the prompt when the user types a character that is not accepted.
+        ///
+        /// The hint is cleared as soon as the user presses a key that is accepted. If this is not
+        /// given, rejected characters are silently ignored.
+        ///
+        ///
+        $(#[$meta])+
+        pub fn on_invalid_hint<S: Into<String>>(mut self, on_invalid_hint: S) -> Self {
+            self.$inner.on_invalid_hint = Some(on_invalid_hint.into());
+            self
+        }
     };
+}
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_hint_style_builder {
     // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
     // other docs that come from the macro invocation have appropriate spacing
-    ($(#[$meta:meta])+ impl $t:ty; $inner:ident $handler:ident) => {
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// Change the style of the hint shown after the message.
+        ///
+        /// The [`Style`] can set the colors and attributes of the hint. If it is not set, the hint
+        /// is dark grey.
+        ///
+        /// [`Style`]: crate::prompt::style::Style
+        ///
+        ///
+        $(#[$meta])+
+        pub fn hint_style(mut self, hint_style: $crate::prompt::style::Style) -> Self {
+            self.$inner.hint_style = Some(hint_style);
+            self
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_collapse_hint_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// Hide the hint shown after the message once the first key is pressed.
+        ///
+        /// The hint explains how to use the prompt, and is no longer needed once the user starts
+        /// using it. Hiding it frees the lines it took up if it was wrapped.
+        ///
+        /// If this is not given, it defaults to `false`.
+        ///
+        ///
+        $(#[$meta])+
+        pub fn collapse_hint(mut self, collapse_hint: bool) -> Self {
+            self.$inner.collapse_hint = collapse_hint;
+            self
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_hide_cursor_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// Hide the cursor of the terminal while the prompt is running.
+        ///
+        /// The highlighted choice already shows where the user is, so the cursor is hidden by
+        /// default for prompts which do not take text, such as `select`. It is shown by default for
+        /// prompts which do, such as `input`, since it marks where the typed text goes.
+        ///
+        ///
+        $(#[$meta])+
+        pub fn hide_cursor(mut self, hide_cursor: bool) -> Self {
+            self.$inner.show_cursor = !hide_cursor;
+            self
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_required_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    (required $(#[$required_meta:meta])+
+     required_style $(#[$required_style_meta:meta])+
+     $inner:ident) => {
+        /// Mark the question as required.
+        ///
+        /// A required question shows a red asterisk after its message, and submitting an empty
+        /// answer fails with the error "This field is required". This check happens before
+        /// [`validate`](Self::validate) is called.
+        ///
+        /// If this is not given, it defaults to `false`.
+        ///
+        ///
+        $(#[$required_meta])+
+        pub fn required(mut self, required: bool) -> Self {
+            self.$inner.required = required;
+            self
+        }
+
+        /// Change the style of the asterisk shown after the message of a
+        /// [`required`](Self::required) question.
+        ///
+        /// If it is not set, the asterisk is red.
+        ///
+        ///
+        $(#[$required_style_meta])+
+        pub fn required_style(mut self, required_style: $crate::prompt::style::Style) -> Self {
+            self.$inner.required_style = Some(required_style);
+            self
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_transform_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ with_layout $(#[$layout_meta:meta])+ impl $t:ty; $inner:ident $handler:ident $($reset:ident)?) => {
         /// Change the way the answer looks when displayed to the user.
         ///
         /// It is a [`FnOnce`] that is given the answer, previous [`Answers`] and the [`Backend`] to
@@ -162,16 +300,84 @@ macro_rules! impl_transform_builder {
         ///
         /// It will only be called once the user finishes answering the question.
         ///
+        /// To align the answer, use [`transform_with_layout`](Self::transform_with_layout)
+        /// instead.
+        ///
         /// [`Answers`]: crate::Answers
         /// [`Backend`]: crate::prompt::Backend
         ///
         ///
         $(#[$meta])*
-        pub fn transform<F>(mut self, transform: F) -> Self
+        pub fn transform<F>(self, transform: F) -> Self
         where
             F: FnOnce($t, &$crate::Answers, &mut dyn Backend) -> std::io::Result<()> + 'a,
+        {
+            self.transform_with_layout(move |ans, answers, _, b| transform(ans, answers, b))
+        }
+
+        /// Change the way the answer looks when displayed to the user, knowing where it is
+        /// displayed.
+        ///
+        /// It is the same as [`transform`](Self::transform), except that it is also given the
+        /// [`Layout`] of the line the answer is displayed on, right after the message. The
+        /// `line_offset` of the layout is the column the answer starts at, and `width` is the
+        /// width of the terminal. This can be used to align the answer, such as to the right of
+        /// the terminal.
+        ///
+        /// [`Layout`]: crate::prompt::layout::Layout
+        ///
+        ///
+        $(#[$layout_meta])*
+        pub fn transform_with_layout<F>(mut self, transform: F) -> Self
+        where
+            F: FnOnce($t, &$crate::Answers, $crate::prompt::layout::Layout, &mut dyn Backend)
+                -> std::io::Result<()>
+                + 'a,
         {
             self.$inner.transform = $crate::question::$handler::Sync(Box::new(transform));
+            $(self.$inner.$reset = Default::default();)?
+            self
+        }
+    };
+
+    ($(#[$meta:meta])+ with_layout $(#[$layout_meta:meta])+ by val $t:ty; $inner:ident) => {
+        $crate::impl_transform_builder!(
+            $(#[$meta])* with_layout $(#[$layout_meta])* impl $t; $inner TransformByVal
+        );
+    };
+
+    ($(#[$meta:meta])+ with_layout $(#[$layout_meta:meta])+ $t:ty; $inner:ident $(, reset $reset:ident)?) => {
+        $crate::impl_transform_builder!(
+            $(#[$meta])* with_layout $(#[$layout_meta])* impl &$t; $inner Transform $($reset)?
+        );
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_transform_with_choices_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $t:ty; $inner:ident) => {
+        /// Change the way the answer looks when displayed to the user, knowing the other choices.
+        ///
+        /// It is the same as [`transform`](Self::transform), except that it is also given the text
+        /// of every choice in the order they are shown. Separators are left out. This can be used
+        /// to show the answer relative to the other choices.
+        ///
+        /// If it is called along with [`transform`](Self::transform) or
+        /// [`transform_with_layout`](Self::transform_with_layout), the last one is used.
+        ///
+        ///
+        $(#[$meta])*
+        pub fn transform_with_choices<F>(mut self, transform: F) -> Self
+        where
+            F: FnOnce(&$t, &[&str], &$crate::Answers, &mut dyn Backend) -> std::io::Result<()>
+                + 'a,
+        {
+            self.$inner.transform_with_choices =
+                $crate::question::TransformWithChoices::Sync(Box::new(transform));
+            self.$inner.transform = $crate::question::Transform::None;
             self
         }
     };
@@ -180,22 +386,44 @@ macro_rules! impl_transform_builder {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! write_final {
-    ($transform:expr, $message:expr, $ans:ident $([$tt:tt])?, $answers:expr, $backend:expr, |$ident:ident| $custom:expr) => {{
-        ui::widgets::Prompt::write_finished_message(&$message, $ans.is_none(), $backend)?;
-
-        // Weird reborrowing trick to make sure ans is not moved when $tt is ref, but is copied when
-        // $tt is not there
-        match (&$ans, $transform) {
-            (&Some($($tt)? ans), Transform::Sync(transform)) => transform(ans, $answers, $backend)?,
-            (&Some($($tt)? $ident), _) => $custom,
-            (None, _) => {
-                $backend.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
+    ($transform:expr, $silent:expr, $message:expr, $ans:ident $([$tt:tt])?, $answers:expr, $backend:expr, |$ident:ident| $custom:expr) => {{
+        // The prompt has already been cleared and the cursor is at its start, so there is nothing
+        // more to do if silent
+        if !$silent {
+            ui::widgets::Prompt::write_finished_message(&$message, $ans.is_none(), $backend)?;
+
+            // Weird reborrowing trick to make sure ans is not moved when $tt is ref, but is copied
+            // when $tt is not there
+            match (&$ans, $transform) {
+                (&Some($($tt)? ans), Transform::Sync(transform)) => {
+                    let layout = $crate::question::finished_layout(&$message, $backend)?;
+                    transform(ans, $answers, layout, $backend)?
+                }
+                (&Some($($tt)? $ident), _) => $custom,
+                (None, _) => {
+                    $backend.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
+                }
             }
+
+            $backend.write_all(b"\n")?;
         }
 
-        $backend.write_all(b"\n")?;
         $backend.flush()?;
 
         Ok($ans.map($crate::answer::Answer::from))
     }};
 }
+
+/// Emits a `tracing` event with the given name at the `DEBUG` level. It expands to nothing if
+/// the `tracing` feature is not enabled.
+macro_rules! trace_event {
+    ($name:literal $(, $($fields:tt)*)?) => {{
+        #[cfg(feature = "tracing")]
+        tracing_crate::event!(
+            name: $name,
+            target: "requestty",
+            tracing_crate::Level::DEBUG,
+            { $($($fields)*)? }
+        );
+    }};
+}
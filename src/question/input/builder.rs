@@ -78,6 +78,17 @@ impl<'a> InputBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let input = Question::input("name")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -88,6 +99,39 @@ impl<'a> InputBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the input
@@ -113,6 +157,55 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// Set the default value for the input from an environment variable
+    ///
+    /// The environment variable is read when the question is asked, not when it is built. If it is
+    /// set, its value is used as the [`default`], taking precedence over any value given to
+    /// [`default`]. If it is not set (or is not valid unicode), the value given to [`default`] is
+    /// used, if any.
+    ///
+    /// [`default`]: InputBuilder::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("editor")
+    ///     .default_env("EDITOR")
+    ///     .default("vim")
+    ///     .build();
+    /// ```
+    pub fn default_env<I: Into<String>>(mut self, var_name: I) -> Self {
+        self.input.default_env = Some(var_name.into());
+        self
+    }
+
+    /// Set the text the input starts with
+    ///
+    /// Unlike [`default`], the `initial_value` is prefilled as editable text with the cursor
+    /// placed at its end. If the user submits it unchanged, it is taken as the answer after going
+    /// through validation.
+    ///
+    /// If both are set, the `initial_value` is the starting text, and the [`default`] is only used
+    /// if the user clears the text and presses `Enter`.
+    ///
+    /// [`default`]: InputBuilder::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("path")
+    ///     .initial_value("~/projects/")
+    ///     .build();
+    /// ```
+    pub fn initial_value<I: Into<String>>(mut self, initial_value: I) -> Self {
+        self.input.initial_value = Some(initial_value.into());
+        self
+    }
+
     crate::impl_auto_complete_builder! {
     /// # Examples
     ///
@@ -182,6 +275,106 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// The minimum number of completions to keep visible above and below the hovered completion
+    /// when the list is scrolled.
+    ///
+    /// The margin is kept where possible. It is capped based on the [`page_size`], and cannot be
+    /// kept near the ends of the list if [`should_loop`] is `false`. If `scroll_margin` is not
+    /// set, it will default to 1. It will only be used if [`auto_complete`] is set, and returns
+    /// more than 1 completions.
+    ///
+    /// [`page_size`]: InputBuilder::page_size
+    /// [`should_loop`]: InputBuilder::should_loop
+    /// [`auto_complete`]: InputBuilder::auto_complete
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .scroll_margin(3)
+    ///     .build();
+    /// ```
+    pub fn scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.input.scroll_margin = scroll_margin;
+        self
+    }
+
+    /// Show the number of characters typed below the input
+    ///
+    /// The counter is dimmed, and is updated as the answer is typed. If a [`max_length`] is set,
+    /// it is shown after the number of characters, as in `12/140`, and the counter turns red once
+    /// it is exceeded. Characters are counted as unicode scalar values.
+    ///
+    /// If `show_counter` is not set, it will default to `false`.
+    ///
+    /// [`max_length`]: InputBuilder::max_length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("bio")
+    ///     .show_counter(true)
+    ///     .build();
+    /// ```
+    pub fn show_counter(mut self, show_counter: bool) -> Self {
+        self.input.show_counter = show_counter;
+        self
+    }
+
+    /// The maximum number of characters the answer can have
+    ///
+    /// A longer answer is rejected with an error when it is submitted. The characters are counted
+    /// as unicode scalar values, the same as by the [`show_counter`] counter, which also shows
+    /// the maximum length.
+    ///
+    /// [`show_counter`]: InputBuilder::show_counter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("status")
+    ///     .show_counter(true)
+    ///     .max_length(140)
+    ///     .build();
+    /// ```
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.input.max_length = Some(max_length);
+        self
+    }
+
+    /// Normalize the answer to the given unicode normalization form.
+    ///
+    /// The answer is normalized before it is validated and filtered, so that `validate`, `filter`
+    /// and the returned answer all see text in a consistent form, regardless of how it was typed.
+    /// [`NormalizationForm::Nfc`] is the default form, and the one that should be used unless
+    /// the answer is compared against decomposed text.
+    ///
+    /// If `normalize` is not set, the answer is returned as it was typed.
+    ///
+    /// [`NormalizationForm::Nfc`]: crate::question::NormalizationForm::Nfc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::NormalizationForm, Question};
+    ///
+    /// let input = Question::input("name")
+    ///     .normalize(NormalizationForm::Nfc)
+    ///     .build();
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    pub fn normalize(mut self, form: super::NormalizationForm) -> Self {
+        self.input.normalize = Some(form);
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -195,6 +388,35 @@ impl<'a> InputBuilder<'a> {
     String; input
     }
 
+    crate::impl_required_builder! {
+    required
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .required(true)
+    ///     .build();
+    /// ```
+
+    required_style
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{
+    ///     prompt::style::{Color, Style},
+    ///     Question,
+    /// };
+    ///
+    /// let input = Question::input("name")
+    ///     .required(true)
+    ///     .required_style(Style::new().fg(Color::Magenta))
+    ///     .build();
+    /// ```
+    input
+    }
+
     crate::impl_validate_builder! {
     /// # Examples
     ///
@@ -212,6 +434,24 @@ impl<'a> InputBuilder<'a> {
     str; input
     }
 
+    crate::impl_validate_on_change_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("email")
+    ///     .validate(|email, previous_answers| if email.contains('@') {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Please enter a valid email".to_owned())
+    ///     })
+    ///     .validate_on_change(true)
+    ///     .build();
+    /// ```
+    input
+    }
+
     crate::impl_validate_on_key_builder! {
     /// # Examples
     ///
@@ -235,17 +475,58 @@ impl<'a> InputBuilder<'a> {
     str; input
     }
 
-    crate::impl_transform_builder! {
+    crate::impl_hide_cursor_builder! {
     /// # Examples
     ///
     /// ```
     /// use requestty::Question;
     ///
     /// let input = Question::input("name")
+    ///     .hide_cursor(true)
+    ///     .build();
+    /// ```
+    input
+    }
+
+    crate::impl_transform_builder! {
+    /// The backend can be used to style the answer, and its [`size`] gives the width of the
+    /// terminal.
+    ///
+    /// [`size`]: crate::prompt::Backend::size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::Color, Question};
+    ///
+    /// let input = Question::input("name")
     ///     .transform(|name, previous_answers, backend| {
     ///         write!(backend, "Hello, {}!", name)
     ///     })
     ///     .build();
+    ///
+    /// let input = Question::input("name")
+    ///     .transform(|name, previous_answers, backend| {
+    ///         let width = backend.size()?.width as usize;
+    ///         backend.set_fg(Color::Green)?;
+    ///         // Leave space for the message before the answer
+    ///         write!(backend, "{:.1$}", name, width / 2)?;
+    ///         backend.set_fg(Color::Reset)
+    ///     })
+    ///     .build();
+    /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .transform_with_layout(|name, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         write!(backend, "{:>1$}", name, layout.line_width() as usize)
+    ///     })
+    ///     .build();
     /// ```
     str; input
     }
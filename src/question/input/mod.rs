@@ -7,7 +7,7 @@ use ui::{
     widgets, Prompt, Validation, Widget,
 };
 
-use super::{AutoComplete, ChoiceList, Filter, Transform, Validate, ValidateOnKey};
+use super::{AutoComplete, ChoiceList, Filter, Transform, Validate, ValidateOnKey, REQUIRED_ERROR};
 use crate::{Answer, Answers};
 
 pub use builder::InputBuilder;
@@ -20,26 +20,122 @@ mod tests;
 #[derive(Debug)]
 pub(super) struct Input<'a> {
     default: Option<(String, usize)>,
+    default_env: Option<String>,
+    initial_value: Option<String>,
+    /// Whether an empty answer is rejected.
+    required: bool,
+    /// The style of the required marker, or the default style if `None`.
+    required_style: Option<ui::style::Style>,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
+    /// Whether to run `validate` every time the value changes.
+    validate_on_change: bool,
     transform: Transform<'a, str>,
     auto_complete: AutoComplete<'a, String>,
     page_size: usize,
     should_loop: bool,
+    scroll_margin: usize,
+    /// Whether to show the number of characters typed below the input.
+    show_counter: bool,
+    /// The maximum number of characters in the answer.
+    max_length: Option<usize>,
+    /// Whether the cursor is shown while the prompt is running.
+    show_cursor: bool,
+    #[cfg(feature = "unicode-normalization")]
+    normalize: Option<NormalizationForm>,
 }
 
 impl<'a> Default for Input<'a> {
     fn default() -> Self {
         Self {
             default: None,
+            default_env: None,
+            initial_value: None,
+            required: false,
+            required_style: None,
             filter: Filter::None,
             validate: Validate::None,
             validate_on_key: ValidateOnKey::None,
+            validate_on_change: false,
             transform: Transform::None,
             auto_complete: AutoComplete::None,
             page_size: 15,
             should_loop: true,
+            scroll_margin: 1,
+            show_counter: false,
+            max_length: None,
+            show_cursor: true,
+            #[cfg(feature = "unicode-normalization")]
+            normalize: None,
+        }
+    }
+}
+
+impl Input<'_> {
+    /// Returns a copy of the input, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            default: self.default.clone(),
+            default_env: self.default_env.clone(),
+            initial_value: self.initial_value.clone(),
+            required: self.required,
+            required_style: self.required_style,
+            filter: self.filter.try_clone()?,
+            validate: self.validate.try_clone()?,
+            validate_on_key: self.validate_on_key.try_clone()?,
+            validate_on_change: self.validate_on_change,
+            transform: self.transform.try_clone()?,
+            auto_complete: self.auto_complete.try_clone()?,
+            page_size: self.page_size,
+            should_loop: self.should_loop,
+            scroll_margin: self.scroll_margin,
+            show_counter: self.show_counter,
+            max_length: self.max_length,
+            show_cursor: self.show_cursor,
+            #[cfg(feature = "unicode-normalization")]
+            normalize: self.normalize,
+        })
+    }
+}
+
+/// The unicode normalization form an [`input`] answer is converted to.
+///
+/// Text typed on some platforms, such as macOS, may use the decomposed form, where accented
+/// characters are made up of the base character followed by combining characters. Normalizing the
+/// answer makes sure it compares equal to text in the same form from elsewhere.
+///
+/// The default is [`NormalizationForm::Nfc`].
+///
+/// [`input`]: crate::question::Question::input
+#[cfg(feature = "unicode-normalization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical composition, where accented characters are a single character where possible.
+    Nfc,
+    /// Canonical decomposition, where accented characters are the base character followed by
+    /// combining characters.
+    Nfd,
+}
+
+#[cfg(feature = "unicode-normalization")]
+// `#[default]` on enum variants requires a newer rust version than the MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for NormalizationForm {
+    fn default() -> Self {
+        NormalizationForm::Nfc
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl NormalizationForm {
+    fn normalize(self, s: &str) -> String {
+        use unicode_normalization_crate::UnicodeNormalization;
+
+        match self {
+            NormalizationForm::Nfc => s.nfc().collect(),
+            NormalizationForm::Nfd => s.nfd().collect(),
         }
     }
 }
@@ -96,6 +192,61 @@ impl InputPrompt<'_, '_> {
         None
     }
 
+    /// The number of characters typed, followed by the maximum length if there is one, and
+    /// whether the maximum length is exceeded. Returns `None` if the counter is not shown.
+    fn counter(&self) -> Option<(String, bool)> {
+        if !self.input_opts.show_counter {
+            return None;
+        }
+
+        let len = self.input.value().chars().count();
+
+        Some(match self.input_opts.max_length {
+            Some(max_length) => (format!("{}/{}", len, max_length), len > max_length),
+            None => (len.to_string(), false),
+        })
+    }
+
+    fn check_max_length(&self) -> Result<(), widgets::Text<String>> {
+        match self.input_opts.max_length {
+            Some(max_length) if self.input.value().chars().count() > max_length => {
+                Err(widgets::Text::new(format!(
+                    "The answer can be at most {} characters long",
+                    max_length
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The height of the prompt without the counter.
+    fn input_height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        let mut height = self.prompt.height(layout) - 1;
+
+        if self.get_remaining_default().is_some() {
+            let mut width = self.input_opts.default.as_ref().unwrap().1 as u16;
+
+            if width > layout.line_width() {
+                width -= layout.line_width();
+
+                layout.line_offset = width % layout.width;
+                layout.offset_y += 1 + width / layout.width;
+
+                height += 2 + width / layout.width;
+            } else {
+                layout.line_offset += width;
+                height += 1;
+            }
+        } else {
+            height = self.input.height(layout);
+        }
+
+        if let Some(picker_height) = self.maybe_select_op(|select| select.height(layout)) {
+            height += picker_height - 1;
+        }
+        height
+    }
+
     fn check_complete_default(&mut self) -> bool {
         if self.get_remaining_default().is_some() {
             let (default, default_len) = self.input_opts.default.as_ref().unwrap();
@@ -132,40 +283,45 @@ impl Widget for InputPrompt<'_, '_> {
             // We need to update the layout to reflect the rest of the hint that is rendered.
             // Instead of doing the math to compute where the cursor ends after rendering, we use
             // the height function which already calculates it.
-            self.height(&mut original_layout);
+            self.input_height(&mut original_layout);
             *layout = original_layout;
         } else {
             self.maybe_select_op(|select| select.render(layout, b))
                 .transpose()?;
         }
 
+        if let Some((counter, exceeded)) = self.counter() {
+            if layout.line_offset != 0 {
+                layout.offset_y += 1;
+                layout.line_offset = 0;
+            }
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+            if exceeded {
+                b.set_fg(ui::style::Color::Red)?;
+            } else {
+                b.set_fg(ui::style::Color::DarkGrey)?;
+            }
+            counter.as_str().render(layout, b)?;
+            b.set_fg(ui::style::Color::Reset)?;
+        }
+
         Ok(())
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        let mut height = self.prompt.height(layout) - 1;
-
-        if self.get_remaining_default().is_some() {
-            let mut width = self.input_opts.default.as_ref().unwrap().1 as u16;
-
-            if width > layout.line_width() {
-                width -= layout.line_width();
+        let mut height = self.input_height(layout);
 
-                layout.line_offset = width % layout.width;
-                layout.offset_y += 1 + width / layout.width;
-
-                height += 2 + width / layout.width;
-            } else {
-                layout.line_offset += width;
-                height += 1;
+        if self.counter().is_some() {
+            // The counter is on the line below the input, or below the auto complete choices
+            if layout.line_offset != 0 {
+                layout.offset_y += 1;
+                layout.line_offset = 0;
             }
-        } else {
-            height = self.input.height(layout);
+            layout.offset_y += 1;
+            height += 1;
         }
 
-        if let Some(picker_height) = self.maybe_select_op(|select| select.height(layout)) {
-            height += picker_height - 1;
-        }
         height
     }
 
@@ -177,6 +333,7 @@ impl Widget for InputPrompt<'_, '_> {
                 } else {
                     let page_size = self.input_opts.page_size;
                     let should_loop = self.input_opts.should_loop;
+                    let scroll_margin = self.input_opts.scroll_margin;
 
                     let Self {
                         input,
@@ -197,6 +354,7 @@ impl Widget for InputPrompt<'_, '_> {
                                 completions.into_iter().map(widgets::Text::new).collect();
                             choices.set_page_size(page_size);
                             choices.set_should_loop(should_loop);
+                            choices.set_scroll_margin(scroll_margin);
 
                             *select = Some(widgets::Select::new(choices));
 
@@ -244,6 +402,11 @@ impl Prompt for InputPrompt<'_, '_> {
             }
         }
 
+        #[cfg(feature = "unicode-normalization")]
+        if let Some(form) = self.input_opts.normalize {
+            ans = form.normalize(&ans);
+        }
+
         if let Filter::Sync(filter) = self.input_opts.filter {
             ans = filter(ans, self.answers);
         }
@@ -257,26 +420,117 @@ impl Prompt for InputPrompt<'_, '_> {
             return Ok(Validation::Continue);
         }
 
-        if self.input.value().is_empty() && self.input_opts.default.is_some() {
-            return Ok(Validation::Finish);
+        if self.input.value().is_empty() {
+            if self.input_opts.default.is_some() {
+                return Ok(Validation::Finish);
+            } else if self.input_opts.required {
+                return Err(widgets::Text::new(REQUIRED_ERROR.into()));
+            }
         }
 
-        if let Validate::Sync(ref mut validate) = self.input_opts.validate {
-            validate(self.input.value(), self.answers)?;
-        }
+        self.check_max_length()?;
+        self.run_validate()?;
 
         Ok(Validation::Finish)
     }
+
+    fn validate_on_change(&mut self) -> Result<(), Self::ValidateErr> {
+        // The default is used for an empty input, so it is not validated
+        if self.select.is_some()
+            || (self.input.value().is_empty() && self.input_opts.default.is_some())
+        {
+            return Ok(());
+        }
+
+        self.check_max_length()?;
+        self.run_validate()
+    }
+}
+
+impl InputPrompt<'_, '_> {
+    /// Runs `validate` against the value of the input, normalized if a normalization form is set.
+    fn run_validate(&mut self) -> Result<(), widgets::Text<String>> {
+        let validate = match self.input_opts.validate {
+            Validate::Sync(ref mut validate) => validate,
+            Validate::None => return Ok(()),
+        };
+
+        #[cfg(feature = "unicode-normalization")]
+        if let Some(form) = self.input_opts.normalize {
+            validate(&form.normalize(self.input.value()), self.answers)?;
+            return Ok(());
+        }
+
+        validate(self.input.value(), self.answers)?;
+        Ok(())
+    }
 }
 
 impl<'i> Input<'i> {
-    fn into_input_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> InputPrompt<'i, 'a> {
+    /// Runs `validate` against the default, if there is one.
+    pub(crate) fn check_default(&mut self, answers: &Answers) -> Option<Result<(), String>> {
+        let env_default = self
+            .default_env
+            .as_ref()
+            .and_then(|var| std::env::var(var).ok());
+
+        let default = match (env_default, &self.default) {
+            (Some(default), _) => default,
+            (None, Some((default, _))) => default.clone(),
+            (None, None) => return None,
+        };
+
+        match self.validate {
+            Validate::Sync(ref mut validate) => Some(validate(&default, answers)),
+            Validate::None => None,
+        }
+    }
+
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let value = value
+            .try_into_string()
+            .expect("`input` questions are answered with a `String`");
+
+        let mut prompt = self.into_input_prompt("", answers);
+        prompt.input.set_value(value);
+
+        super::submit(prompt).map(Answer::String)
+    }
+
+    fn into_input_prompt<'a>(
+        mut self,
+        message: &'a str,
+        answers: &'a Answers,
+    ) -> InputPrompt<'i, 'a> {
+        let mut input = widgets::StringInput::default();
+        let mut is_valid = true;
+
+        // The environment variable is read when the question is asked, so that it reflects any
+        // changes made since the question was built
+        if let Some(var) = self.default_env.take() {
+            if let Ok(default) = std::env::var(var) {
+                let len = default.chars().count();
+                self.default = Some((default, len));
+            }
+        }
+
+        if let Some(initial_value) = self.initial_value.take() {
+            input.set_value(initial_value);
+            input.set_at(usize::MAX);
+
+            if let ValidateOnKey::Sync(ref mut validate) = self.validate_on_key {
+                is_valid = validate(input.value(), answers);
+            }
+        }
+
         InputPrompt {
-            prompt: widgets::Prompt::new(message),
+            prompt: widgets::Prompt::new(message)
+                .with_required(self.required)
+                .with_optional_required_style(self.required_style),
             input_opts: self,
-            input: widgets::StringInput::default(),
+            input,
             select: None,
-            is_valid: true,
+            is_valid,
             answers,
         }
     }
@@ -284,18 +538,43 @@ impl<'i> Input<'i> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let validate_on_change = self.validate_on_change;
+        let show_cursor = self.show_cursor;
+
+        let prompt = self.into_input_prompt(&message, answers);
+
+        let ans = if events.is_interactive() {
+            let mut input = ui::Input::new(prompt, b)
+                .on_esc(exit.on_esc)
+                .confirm_on_interrupt(exit.interrupt_confirm)
+                .submit_on_tab(exit.submit_on_tab)
+                .back_on_back_tab(exit.back_on_back_tab)
+                .ensure_newline(exit.ensure_newline)
+                .validate_on_change(validate_on_change);
+
+            if !show_cursor {
+                input = input.hide_cursor();
+            }
 
-        let ans = ui::Input::new(self.into_input_prompt(&message, answers), b)
-            .on_esc(on_esc)
-            .run(events)?;
-
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b
+            input.run(events)?
+        } else {
+            let has_default = prompt.input_opts.default.is_some();
+            Some(super::answer_piped(
+                prompt,
+                has_default,
+                |prompt, line| prompt.input.set_value(line),
+                events,
+            )?)
+        };
+
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |ans| b
             .write_styled(&ans.as_str().cyan())?)
     }
 }
@@ -9,6 +9,7 @@ mod handler;
 mod impl_macros;
 mod input;
 mod multi_select;
+mod note;
 mod number;
 mod order_select;
 #[macro_use]
@@ -16,35 +17,142 @@ mod options;
 mod custom_prompt;
 mod password;
 mod raw_select;
+mod repeat;
 mod select;
 
-pub use choice::Choice;
+pub use choice::{Choice, Orientation, PointerPosition};
 pub use confirm::ConfirmBuilder;
-pub use custom_prompt::{CustomPromptBuilder, Prompt};
+pub use custom_prompt::{CustomPromptBuilder, Prompt, WidgetPrompt};
 pub use editor::EditorBuilder;
 pub use expand::ExpandBuilder;
 pub use input::InputBuilder;
+#[cfg(feature = "unicode-normalization")]
+pub use input::NormalizationForm;
 pub use multi_select::MultiSelectBuilder;
+pub use note::NoteBuilder;
 pub use number::{FloatBuilder, IntBuilder};
 pub use order_select::{builder::OrderSelectBuilder, OrderSelectItem};
 pub use password::PasswordBuilder;
 pub use raw_select::RawSelectBuilder;
-pub use select::SelectBuilder;
+pub use repeat::RepeatBuilder;
+pub use select::{AsChoices, SelectBuilder, TypedSelect, TypedSelectBuilder};
+
+use std::io;
 
 use ui::{backend::Backend, events::EventIterator};
 
 use crate::{Answer, Answers};
-use choice::{get_sep_str, ChoiceList};
+use choice::{render_separator, ChoiceList};
 use custom_prompt::CustomPromptInteral;
 use handler::{
-    AutoComplete, Filter, Transform, TransformByVal, Validate, ValidateByVal, ValidateOnKey,
+    AutoComplete, Compare, Filter, Format, FormatByVal, Launcher, Predicate, Transform,
+    TransformByVal, TransformWithChoices, Validate, ValidateByVal, ValidateOnKey,
     ValidateOnKeyByVal,
 };
 use options::Options;
 
+/// The error shown when a required question is submitted without a value.
+const REQUIRED_ERROR: &str = "This field is required";
+
+/// The error returned when a question with choices is asked without any choice to select.
+const NO_CHOICES_ERROR: &str = "There are no choices to select";
+
+/// Answers a prompt which takes text with the next line of non-interactive `events` instead of
+/// running it. The line is validated and filtered as if it was typed and submitted, but nothing is
+/// rendered.
+///
+/// If there are no more lines, an empty line is used if the prompt has a default, so that the
+/// default is taken. Otherwise, it fails with [`ErrorKind::Eof`](ui::ErrorKind::Eof).
+fn answer_piped<P, E, F>(
+    mut prompt: P,
+    has_default: bool,
+    set_value: F,
+    events: &mut E,
+) -> ui::Result<P::Output>
+where
+    P: ui::Prompt,
+    P::ValidateErr: ErrorText,
+    E: EventIterator,
+    F: FnOnce(&mut P, String),
+{
+    let line = match events.next_line()? {
+        Some(line) => line,
+        None if has_default => String::new(),
+        None => return Err(ui::ErrorKind::Eof),
+    };
+
+    set_value(&mut prompt, line);
+
+    submit(prompt)
+}
+
+/// Validates and finishes a prompt whose state has been set without running it, as if it was
+/// submitted by the user.
+///
+/// If the prompt is not accepted, it fails with an [`io::Error`] of kind `InvalidInput`.
+fn submit<P>(mut prompt: P) -> ui::Result<P::Output>
+where
+    P: ui::Prompt,
+    P::ValidateErr: ErrorText,
+{
+    match prompt.validate() {
+        // There is no one to ask for a confirmation, and the answer was given explicitly
+        Ok(ui::Validation::Finish) | Ok(ui::Validation::Confirm(_)) => Ok(prompt.finish()),
+        Ok(_) => Err(invalid_answer("incomplete answer")),
+        Err(e) => Err(invalid_answer(e.into_text())),
+    }
+}
+
+fn invalid_answer<E: Into<String>>(error: E) -> ui::ErrorKind {
+    io::Error::new(io::ErrorKind::InvalidInput, error.into()).into()
+}
+
+/// The layout of the line after the message written by [`write_finished_message`], which is
+/// where the answer is written.
+///
+/// [`write_finished_message`]: ui::widgets::Prompt::write_finished_message
+fn finished_layout<B: Backend + ?Sized>(message: &str, b: &B) -> io::Result<ui::layout::Layout> {
+    let size = b.size()?;
+    let width = ui::widgets::Prompt::finished_message_width(&message);
+
+    Ok(ui::layout::Layout::new(
+        (width % size.width.max(1) as usize) as u16,
+        size,
+    ))
+}
+
+/// The number of decimal digits needed to write `n`.
+fn number_of_digits(mut n: usize) -> u16 {
+    let mut digits = 1;
+
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+
+    digits
+}
+
+/// A validation error of a prompt which can be converted to its text.
+trait ErrorText {
+    fn into_text(self) -> String;
+}
+
+impl ErrorText for ui::widgets::Text<String> {
+    fn into_text(self) -> String {
+        self.text
+    }
+}
+
+impl ErrorText for &str {
+    fn into_text(self) -> String {
+        self.to_owned()
+    }
+}
+
 /// A `Question` that can be asked.
 ///
-/// There are 12 variants.
+/// There are 13 variants.
 ///
 /// - [`input`](Question::input)
 /// - [`password`](Question::password)
@@ -57,6 +165,7 @@ use options::Options;
 /// - [`raw_select`](Question::raw_select)
 /// - [`multi_select`](Question::multi_select)
 /// - [`order_select`](Question::order_select)
+/// - [`note`](Question::note)
 /// - [`custom`](Question::custom)
 ///
 /// Every [`Question`] has 4 common options.
@@ -296,7 +405,7 @@ impl Question<'static> {
     /// Prompt that allows the user to select from a list of options by key
     ///
     /// The keys are ascii case-insensitive characters. The 'h' option is added by the prompt and
-    /// shouldn't be defined.
+    /// shouldn't be defined. Keys may also be made of multiple characters, see [`ExpandBuilder`].
     ///
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
     /// but [`Choice::Separator`]s can only be single line.
@@ -365,6 +474,39 @@ impl Question<'static> {
         SelectBuilder::new(name.into())
     }
 
+    /// Prompt that allows the user to select from a list of options, each of which carries a value
+    ///
+    /// Unlike [`select`], the value of the selected choice is returned directly when it is
+    /// prompted with [`TypedSelect::prompt`], instead of a [`ListItem`] which has to be mapped
+    /// back to a value.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// #[derive(Debug)]
+    /// enum Crust {
+    ///     Thin,
+    ///     Stuffed,
+    /// }
+    ///
+    /// let select = Question::typed_select("crust")
+    ///     .message("Which crust do you want?")
+    ///     .choice("Thin crust", Crust::Thin)
+    ///     .choice("Stuffed crust", Crust::Stuffed)
+    ///     .build();
+    /// ```
+    ///
+    /// [`select`]: Question::select
+    /// [`ListItem`]: crate::ListItem
+    /// [`builder`]: TypedSelectBuilder
+    pub fn typed_select<T, N: Into<String>>(name: N) -> TypedSelectBuilder<'static, T> {
+        TypedSelectBuilder::new(name.into())
+    }
+
     /// Prompt that allows the user to select from a list of options with indices
     ///
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
@@ -465,10 +607,38 @@ impl Question<'static> {
         OrderSelectBuilder::new(name.into())
     }
 
+    /// A read-only note which shows its message, such as instructions or a warning, without
+    /// asking anything.
+    ///
+    /// It is shown and continues to the next question immediately, unless it is set to
+    /// [`wait_for_key`]. It does not have an answer, and so nothing is added to the [`Answers`] for
+    /// it. Since there is no answer, it cannot be asked with [`prompt_one`].
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let note = Question::note("instructions")
+    ///     .message("Answer the following questions to set up your project")
+    ///     .wait_for_key(true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`builder`]: NoteBuilder
+    /// [`wait_for_key`]: NoteBuilder::wait_for_key
+    /// [`prompt_one`]: crate::prompt_one
+    pub fn note<N: Into<String>>(name: N) -> NoteBuilder<'static> {
+        NoteBuilder::new(name.into())
+    }
+
     /// Create a [`Question`] from a custom prompt.
     ///
     /// See [`Prompt`] for more information on writing custom prompts and the various methods on the
-    /// [`builder`] for more details on each available option.
+    /// [`builder`] for more details on each available option. To use a widget built with
+    /// `requestty-ui` directly, see [`WidgetPrompt`].
     ///
     /// # Examples
     ///
@@ -510,6 +680,40 @@ impl Question<'static> {
     {
         CustomPromptBuilder::new(name.into(), Box::new(Some(prompt)))
     }
+
+    /// Prompt that asks the question built by the given function repeatedly, collecting the
+    /// answers until one of them is empty.
+    ///
+    /// The answer is an [`Answer::List`] with every answer given, which does not include the one
+    /// which stopped it, under the name of the built question. If the question is skipped the
+    /// first time, the whole question is skipped. If it is skipped later on, such as with `Esc`,
+    /// it stops like it would with an empty answer.
+    ///
+    /// A fresh question is built every time it is asked, so it can have closures such as
+    /// `validate` or `filter`.
+    ///
+    /// See the various methods on the [`builder`] for more details on each available option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| {
+    ///     Question::input("tags")
+    ///         .message("Add a tag (leave empty to finish)")
+    ///         .build()
+    /// })
+    /// .build();
+    /// ```
+    ///
+    /// [`builder`]: RepeatBuilder
+    pub fn repeat<'a, F>(question: F) -> RepeatBuilder<'a>
+    where
+        F: Fn() -> Question<'a> + 'a,
+    {
+        RepeatBuilder::new(question)
+    }
 }
 
 #[derive(Debug)]
@@ -524,54 +728,307 @@ enum QuestionKind<'a> {
     MultiSelect(multi_select::MultiSelect<'a>),
     OrderSelect(order_select::OrderSelect<'a>),
     Password(password::Password<'a>),
-    Editor(editor::Editor<'a>),
+    Editor(Box<editor::Editor<'a>>),
+    Note(note::Note),
     Custom(Box<dyn CustomPromptInteral + 'a>),
+    Repeat(repeat::Repeat<'a>),
 }
 
 impl Question<'_> {
+    pub(crate) fn name(&self) -> &str {
+        &self.opts.name
+    }
+
+    /// Evaluates `when`, storing the result so that it is not evaluated again when asked.
+    pub(crate) fn when(&mut self, answers: &Answers) -> bool {
+        let when = std::mem::replace(&mut self.opts.when, true.into()).get(answers);
+        self.opts.when = when.into();
+        when
+    }
+
+    /// Whether the question would be asked, based on `when` and `ask_if_answered`.
+    pub(crate) fn will_be_asked(&mut self, answers: &Answers) -> bool {
+        (self.opts.ask_if_answered || !answers.contains_key(&self.opts.name)) && self.when(answers)
+    }
+
+    pub(crate) fn is_ask_if_answered(&self) -> bool {
+        self.opts.ask_if_answered
+    }
+
+    pub(crate) fn is_secret(&self) -> bool {
+        self.opts.secret
+    }
+
+    /// Makes the question be asked regardless of `when`, and even if it is already answered.
+    pub(crate) fn force_ask(&mut self) {
+        self.opts.when = true.into();
+        self.opts.ask_if_answered = true;
+    }
+
+    /// Asks for confirmation with the given question before the user is allowed to interrupt the
+    /// prompt with `Ctrl+C`.
+    pub(crate) fn confirm_on_interrupt(&mut self, question: String) {
+        self.opts.interrupt_confirm = Some(question);
+    }
+
+    /// Makes `Tab` submit the question, and `Shift+Tab` return an
+    /// [`ErrorKind::Back`](ui::ErrorKind::Back) if `back` is true.
+    pub(crate) fn tab_navigation(&mut self, back: bool) {
+        self.opts.submit_on_tab = true;
+        self.opts.back_on_back_tab = back;
+    }
+
+    /// Runs the validator of the question against its default, returning `None` if the question
+    /// has no default or no validator.
+    pub(crate) fn check_default(&mut self, answers: &Answers) -> Option<Result<(), String>> {
+        match self.kind {
+            QuestionKind::Input(ref mut i) => i.check_default(answers),
+            QuestionKind::Int(ref mut i) => i.check_default(answers),
+            QuestionKind::Float(ref mut f) => f.check_default(answers),
+            QuestionKind::Editor(ref mut e) => e.check_default(answers),
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of the question, so that the same question can be asked multiple times
+    /// without building it again.
+    ///
+    /// Since closures cannot be cloned, it returns `None` if any part of the question is a
+    /// function, such as a `message` or `when` which is a function, `on_answered`, `filter`,
+    /// `validate`, `validate_on_key`, `transform`, `auto_complete`, `answer_format` or
+    /// `launcher`. It also returns `None` for [`custom`](Question::custom) prompts,
+    /// [`repeat`](Question::repeat) questions, and [`editor`](Question::editor) questions with an
+    /// editor given as a [`Command`].
+    ///
+    /// [`Command`]: std::process::Command
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let question = Question::input("item").message("Add an item").build();
+    /// let copy = question.try_clone().unwrap();
+    ///
+    /// let question = Question::input("item")
+    ///     .validate(|item, _| Ok(()))
+    ///     .build();
+    /// assert!(question.try_clone().is_none());
+    /// ```
+    pub fn try_clone(&self) -> Option<Self> {
+        let kind = match self.kind {
+            QuestionKind::Input(ref i) => QuestionKind::Input(i.try_clone()?),
+            QuestionKind::Int(ref i) => QuestionKind::Int(i.try_clone()?),
+            QuestionKind::Float(ref f) => QuestionKind::Float(f.try_clone()?),
+            QuestionKind::Confirm(ref c) => QuestionKind::Confirm(c.try_clone()?),
+            QuestionKind::Select(ref l) => QuestionKind::Select(l.try_clone()?),
+            QuestionKind::RawSelect(ref r) => QuestionKind::RawSelect(r.try_clone()?),
+            QuestionKind::Expand(ref e) => QuestionKind::Expand(e.try_clone()?),
+            QuestionKind::MultiSelect(ref c) => QuestionKind::MultiSelect(c.try_clone()?),
+            QuestionKind::OrderSelect(ref c) => QuestionKind::OrderSelect(c.try_clone()?),
+            QuestionKind::Password(ref p) => QuestionKind::Password(p.try_clone()?),
+            QuestionKind::Editor(ref e) => QuestionKind::Editor(Box::new(e.try_clone()?)),
+            QuestionKind::Note(ref n) => QuestionKind::Note(n.clone()),
+            QuestionKind::Custom(_) | QuestionKind::Repeat(_) => return None,
+        };
+
+        Some(Question::new(self.opts.try_clone()?, kind))
+    }
+
+    /// Answers the question with the given value without any terminal interaction, returning the
+    /// answer it would give if the value was entered by the user.
+    ///
+    /// Nothing is rendered and no events are read. The value goes through the same `filter` and
+    /// `validate` as an answer given interactively, while `transform` is skipped since it only
+    /// renders the answer. This is useful to test the `when`, `filter` and `validate` closures of
+    /// a question.
+    ///
+    /// The value depends on the kind of question:
+    ///
+    /// - [`input`](Question::input), [`password`](Question::password) and
+    ///   [`editor`](Question::editor): an [`Answer::String`] with the text entered. For an
+    ///   `input`, an empty string takes the default.
+    /// - [`int`](Question::int): an [`Answer::Int`].
+    /// - [`float`](Question::float): an [`Answer::Float`].
+    /// - [`confirm`](Question::confirm): an [`Answer::Bool`].
+    /// - [`expand`](Question::expand): an [`Answer::ExpandItem`] of which only the `key` is used.
+    ///   An empty key takes the default.
+    /// - [`select`](Question::select) and [`raw_select`](Question::raw_select): an
+    ///   [`Answer::ListItem`] of which only the `index` is used.
+    /// - [`multi_select`](Question::multi_select): an [`Answer::ListItems`] with the selected
+    ///   choices, of which only the `index` is used.
+    /// - [`order_select`](Question::order_select): an [`Answer::ListItems`] with every choice in
+    ///   the new order, of which only the `index` is used.
+    /// - [`repeat`](Question::repeat): an [`Answer::List`] with a value for each time the question
+    ///   is answered, which are answered as above.
+    ///
+    /// Returns `None` if the question would not be asked, based on its `when` and
+    /// `ask_if_answered`. If the value is not accepted, it fails with an [`IoError`] of kind
+    /// `InvalidInput`, with the validation error as its message.
+    ///
+    /// [`IoError`]: crate::ErrorKind::IoError
+    ///
+    /// # Panics
+    ///
+    /// It panics if the value is the wrong kind of [`Answer`] for the question, or if the question
+    /// is a [`custom`](Question::custom) prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, Question};
+    ///
+    /// let question = Question::input("name")
+    ///     .filter(|name, _| name.trim().to_owned())
+    ///     .build();
+    ///
+    /// let answer = question.answer_with("  John ".to_owned(), &Answers::default())?;
+    /// assert_eq!(answer, Some(Answer::String("John".into())));
+    /// # Result::<_, requestty::ErrorKind>::Ok(())
+    /// ```
+    pub fn answer_with<A: Into<Answer>>(
+        self,
+        value: A,
+        answers: &Answers,
+    ) -> ui::Result<Option<Answer>> {
+        if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
+            return Ok(None);
+        }
+
+        if !self.opts.when.get(answers) {
+            return Ok(None);
+        }
+
+        let value = value.into();
+
+        let answer = match self.kind {
+            QuestionKind::Input(i) => i.answer_with(value, answers)?,
+            QuestionKind::Int(i) => i.answer_with(value, answers)?,
+            QuestionKind::Float(f) => f.answer_with(value, answers)?,
+            QuestionKind::Confirm(c) => c.answer_with(value),
+            QuestionKind::Select(l) => l.answer_with(value)?,
+            QuestionKind::RawSelect(r) => r.answer_with(value)?,
+            QuestionKind::Expand(e) => e.answer_with(value)?,
+            QuestionKind::MultiSelect(c) => c.answer_with(value, answers)?,
+            QuestionKind::OrderSelect(c) => c.answer_with(value, answers)?,
+            QuestionKind::Password(p) => p.answer_with(value, answers)?,
+            QuestionKind::Editor(e) => e.answer_with(value, answers)?,
+            // A note has no answer
+            QuestionKind::Note(_) => return Ok(None),
+            QuestionKind::Custom(_) => panic!("custom prompts cannot be answered without asking"),
+            QuestionKind::Repeat(r) => r.answer_with(value, answers)?,
+        };
+
+        if let Some(on_answered) = self.opts.on_answered {
+            if self.opts.secret {
+                (on_answered.0)(&self.opts.name, &Answer::redacted());
+            } else {
+                (on_answered.0)(&self.opts.name, &answer);
+            }
+        }
+
+        Ok(Some(answer))
+    }
+
     pub(crate) fn ask<B: Backend, I: EventIterator>(
         self,
         answers: &Answers,
         b: &mut B,
         events: &mut I,
     ) -> ui::Result<Option<(String, Answer)>> {
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing_crate::debug_span!(target: "requestty", "question", name = %self.opts.name)
+                .entered();
+
         // Already asked
         if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
+            trace_event!("question.skip", reason = "answered");
             return Ok(None);
         }
 
         // Shouldn't be asked
         if !self.opts.when.get(answers) {
+            trace_event!("question.skip", reason = "when");
             return Ok(None);
         }
 
         let name = self.opts.name;
+        // A repeat question only replaces the message of the repeated question if it is given
+        let has_message = self.opts.message.is_some();
         let message = self
             .opts
             .message
             .map(|message| message.get(answers))
             .unwrap_or_else(|| name.clone() + ":");
-        let on_esc = self.opts.on_esc.get(answers);
+        let exit = ExitOptions {
+            on_esc: self.opts.on_esc.get(answers),
+            interrupt_confirm: self.opts.interrupt_confirm,
+            submit_on_tab: self.opts.submit_on_tab,
+            back_on_back_tab: self.opts.back_on_back_tab,
+            ensure_newline: self.opts.ensure_newline,
+        };
+        let silent = self.opts.silent;
+        let on_answered = self.opts.on_answered;
+        let secret = self.opts.secret;
 
         let res = match self.kind {
-            QuestionKind::Input(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Int(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Float(f) => f.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Confirm(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Select(l) => l.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::RawSelect(r) => r.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Expand(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::MultiSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::OrderSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Password(p) => p.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Editor(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Custom(mut o) => o.ask(message, answers, b, events)?,
+            QuestionKind::Input(i) => i.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Int(i) => i.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Float(f) => f.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Confirm(c) => c.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Select(l) => l.ask(message, exit, silent, answers, b, events),
+            QuestionKind::RawSelect(r) => r.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Expand(e) => e.ask(message, exit, silent, answers, b, events),
+            QuestionKind::MultiSelect(c) => c.ask(message, exit, silent, answers, b, events),
+            QuestionKind::OrderSelect(c) => c.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Password(p) => p.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Editor(e) => e.ask(message, exit, silent, answers, b, events),
+            QuestionKind::Note(n) => n.ask(message, exit, silent, b, events),
+            QuestionKind::Custom(mut o) => o.ask(message, answers, b, events),
+            QuestionKind::Repeat(r) => {
+                let message = if has_message { Some(message) } else { None };
+                r.ask(message, exit, silent, answers, b, events)
+            }
         };
 
+        #[cfg(feature = "tracing")]
+        match res {
+            Ok(Some(_)) => trace_event!("question.answered"),
+            Ok(None) => trace_event!("question.skip", reason = "prompt"),
+            Err(ref e) => trace_event!("question.error", error = %e),
+        }
+
+        let res = res?;
+
+        // The prompt has finished rendering by now, so the callback cannot interfere with it
+        if let (Some(on_answered), Some(answer)) = (on_answered, &res) {
+            if secret {
+                (on_answered.0)(&name, &Answer::redacted());
+            } else {
+                (on_answered.0)(&name, answer);
+            }
+        }
+
         Ok(res.map(|res| (name, res)))
     }
 }
 
+/// How the user can leave a prompt without answering it, along with the other options which are
+/// passed on to the [`ui::Input`] asking it.
+#[derive(Debug)]
+pub(crate) struct ExitOptions {
+    pub(crate) on_esc: ui::OnEsc,
+    /// See [`ui::Input::confirm_on_interrupt`]
+    pub(crate) interrupt_confirm: Option<String>,
+    /// See [`ui::Input::submit_on_tab`]
+    pub(crate) submit_on_tab: bool,
+    /// See [`ui::Input::back_on_back_tab`]
+    pub(crate) back_on_back_tab: bool,
+    /// See [`ui::Input::ensure_newline`]
+    pub(crate) ensure_newline: bool,
+}
+
 /// The type which needs to be returned by the [`auto_complete`] function.
 ///
 /// [`auto_complete`]: InputBuilder::auto_complete
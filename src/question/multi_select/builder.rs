@@ -2,7 +2,7 @@ use ui::{backend::Backend, widgets::Text};
 
 use super::MultiSelect;
 use crate::{
-    question::{Choice, Options},
+    question::{Choice, Options, Orientation},
     ListItem,
 };
 
@@ -87,6 +87,17 @@ impl<'a> MultiSelectBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -97,6 +108,39 @@ impl<'a> MultiSelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// The maximum height that can be taken by the list
@@ -125,6 +169,27 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Fit the page size to the height of the terminal
+    ///
+    /// The page size is recomputed whenever the prompt is rendered, so it adapts if the terminal
+    /// is resized. It leaves space for the rest of the prompt, and is still a minimum of 5.
+    ///
+    /// This is overridden by [`page_size`](Self::page_size) if it is called after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .page_size_auto()
+    ///     .build();
+    /// ```
+    pub fn page_size_auto(mut self) -> Self {
+        self.multi_select.choices.set_page_size_auto();
+        self
+    }
+
     /// Whether to wrap around when user gets to the last element.
     ///
     /// If `should_loop` is not set, it will default to `true`.
@@ -143,6 +208,82 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// The minimum number of elements to keep visible above and below the hovered element when
+    /// the list is scrolled.
+    ///
+    /// The margin is kept where possible. It is capped based on the [`page_size`], and cannot be
+    /// kept near the ends of the list if [`should_loop`] is `false`. If `scroll_margin` is not
+    /// set, it will default to 1.
+    ///
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .scroll_margin(3)
+    ///     .build();
+    /// ```
+    pub fn scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.multi_select.choices.set_scroll_margin(scroll_margin);
+        self
+    }
+
+    /// The width of the gutter on the left of every element in the list, which is where the
+    /// pointer to the hovered element is shown.
+    ///
+    /// It can be 0, 1 or 2. If it is 0, the pointer is not shown, and if it is 1, the pointer is
+    /// not followed by a space. If `gutter_width` is not set, it will default to 2.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `gutter_width` is more than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .gutter_width(0)
+    ///     .build();
+    /// ```
+    pub fn gutter_width(mut self, gutter_width: u16) -> Self {
+        self.multi_select.choices.set_gutter_width(gutter_width);
+        self
+    }
+
+    /// The direction in which the choices are laid out
+    ///
+    /// With [`Orientation::Horizontal`], the choices are shown next to each other, and wrap to the
+    /// next line if they do not fit in the terminal width. With [`Orientation::Grid`], the choices
+    /// are shown in as many equal width columns as fit in the terminal width, which is best suited
+    /// for many short choices. In both, the hovered choice can be changed with the arrow keys, only
+    /// the first line of each choice is shown, and separators are not shown.
+    ///
+    /// In the grid orientation, the page size is the number of rows shown at once. The page size
+    /// options do not apply to the horizontal orientation.
+    ///
+    /// If `orientation` is not set, it will default to [`Orientation::Vertical`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::Orientation, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choices(vec!["Mozzarella", "Cheddar", "Parmesan", "Brie"])
+    ///     .orientation(Orientation::Grid)
+    ///     .build();
+    /// ```
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.multi_select.orientation = orientation;
+        self
+    }
+
     /// Inserts a [`Choice`] with given text and its default checked state as `false`.
     ///
     /// If you want to set the default checked state, use [`choice_with_default`].
@@ -191,6 +332,35 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with a given text which is locked in the given checked state.
+    ///
+    /// A locked choice cannot be toggled, including by `<a>` and `<i>`, and is shown greyed out. If
+    /// it is checked, it is always part of the answer, even if it is removed by [`filter`]. This is
+    /// useful for mandatory items which should still be shown to the user.
+    ///
+    /// See [`multi_select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`filter`]: Self::filter
+    /// [`multi_select`]: crate::question::Question::multi_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("toppings")
+    ///     .locked_choice("Cheese", true)
+    ///     .choice("Olives")
+    ///     .build();
+    /// ```
+    pub fn locked_choice<I: Into<String>>(mut self, text: I, checked: bool) -> Self {
+        let index = self.multi_select.choices.len();
+        self.multi_select.locked.resize(index, false);
+        self.multi_select.locked.push(true);
+        self.choice_with_default(text, checked)
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`multi_select`] for more information.
@@ -379,6 +549,90 @@ impl<'a> MultiSelectBuilder<'a> {
     [bool]; multi_select
     }
 
+    /// The symbols shown before checked and unchecked choices. They are padded to the same width
+    /// so that the choices stay aligned.
+    ///
+    /// By default, `✔` from the current [`SymbolSet`] is used for both, and only its colour
+    /// changes.
+    ///
+    /// [`SymbolSet`]: crate::symbols::SymbolSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choices(vec!["Mozzarella", "Cheddar", "Parmesan"])
+    ///     .symbols("[x]", "[ ]")
+    ///     .build();
+    /// ```
+    pub fn symbols<C: Into<String>, U: Into<String>>(mut self, checked: C, unchecked: U) -> Self {
+        self.multi_select.symbols = Some((checked.into(), unchecked.into()));
+        self
+    }
+
+    /// Require at least one choice to be selected, showing the given error if the prompt is
+    /// submitted with nothing selected.
+    ///
+    /// This is checked before [`validate`], which is only called if something is selected.
+    ///
+    /// [`validate`]: Self::validate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choices(vec!["Mozzarella", "Cheddar", "Parmesan"])
+    ///     .require_at_least_one("Pick a cheese for your pizza")
+    ///     .build();
+    /// ```
+    pub fn require_at_least_one<I: Into<String>>(mut self, message: I) -> Self {
+        self.multi_select.require_at_least_one = Some(message.into());
+        self
+    }
+
+    crate::impl_hint_style_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Attributes, Color, Style}, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD))
+    ///     .build();
+    /// ```
+    multi_select
+    }
+
+    crate::impl_collapse_hint_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .collapse_hint(true)
+    ///     .build();
+    /// ```
+    multi_select
+    }
+
+    crate::impl_hide_cursor_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .hide_cursor(false)
+    ///     .build();
+    /// ```
+    multi_select
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -394,6 +648,36 @@ impl<'a> MultiSelectBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .transform_with_layout(|cheeses, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         let cheeses: Vec<_> = cheeses.iter().map(|cheese| &*cheese.text).collect();
+    ///         write!(backend, "{:>1$}", cheeses.join(", "), layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
+    [ListItem]; multi_select, reset transform_with_choices
+    }
+
+    crate::impl_transform_with_choices_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choices(vec!["Mozzarella", "Cheddar", "Parmesan"])
+    ///     .transform_with_choices(|cheeses, choices, previous_answers, backend| {
+    ///         write!(backend, "{} out of {}", cheeses.len(), choices.len())
+    ///     })
+    ///     .build();
+    /// ```
     [ListItem]; multi_select
     }
 
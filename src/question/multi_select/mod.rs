@@ -8,7 +8,10 @@ use ui::{
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Filter, Transform, Validate};
+use super::{
+    choice::{write_gutter, Orientation, Placement},
+    Choice, Filter, Transform, TransformWithChoices, Validate,
+};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::MultiSelectBuilder;
@@ -22,9 +25,45 @@ mod tests;
 pub(super) struct MultiSelect<'a> {
     choices: super::ChoiceList<Text<String>>,
     selected: Vec<bool>,
+    /// Whether the choice at an index is locked in its checked state. It may be shorter than the
+    /// choices, in which case the remaining choices are not locked.
+    locked: Vec<bool>,
+    /// The style of the hint, or the default style if `None`.
+    hint_style: Option<ui::style::Style>,
+    /// Whether the hint is hidden once the first key is pressed.
+    collapse_hint: bool,
+    /// Whether the cursor is shown while the prompt is running.
+    show_cursor: bool,
+    orientation: Orientation,
     filter: Filter<'a, Vec<bool>>,
     validate: Validate<'a, [bool]>,
+    /// The symbols for checked and unchecked choices, or `None` to use the current symbol set.
+    symbols: Option<(String, String)>,
+    /// The error to show if nothing is selected, or `None` if no selection is required.
+    require_at_least_one: Option<String>,
     transform: Transform<'a, [ListItem]>,
+    transform_with_choices: TransformWithChoices<'a, [ListItem]>,
+}
+
+impl MultiSelect<'_> {
+    /// Returns a copy of the multi select, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            choices: self.choices.clone(),
+            selected: self.selected.clone(),
+            locked: self.locked.clone(),
+            hint_style: self.hint_style,
+            collapse_hint: self.collapse_hint,
+            show_cursor: self.show_cursor,
+            orientation: self.orientation,
+            filter: self.filter.try_clone()?,
+            validate: self.validate.try_clone()?,
+            symbols: self.symbols.clone(),
+            require_at_least_one: self.require_at_least_one.clone(),
+            transform: self.transform.try_clone()?,
+            transform_with_choices: self.transform_with_choices.try_clone()?,
+        })
+    }
 }
 
 fn set_seperators_false(selected: &mut [bool], choices: &[Choice<Text<String>>]) {
@@ -37,6 +76,9 @@ struct MultiSelectPrompt<'a, 'c> {
     prompt: widgets::Prompt<&'a str>,
     select: widgets::Select<MultiSelect<'c>>,
     answers: &'a Answers,
+    /// The width available to the choices when they were last laid out. It is used to lay out the
+    /// choices to navigate them in the horizontal and grid orientations.
+    width: u16,
 }
 
 fn create_list_items(
@@ -57,11 +99,82 @@ fn create_list_items(
         .collect()
 }
 
+impl MultiSelectPrompt<'_, '_> {
+    /// Fits the page size to the terminal height, leaving space for the prompt.
+    fn fit_page_size(&mut self, layout: ui::layout::Layout) {
+        let reserved = self.prompt.height(&mut layout.clone());
+        self.select.list.choices.fit_page_size(layout, reserved);
+    }
+
+    fn placement(&self) -> Placement {
+        Placement::new(
+            &self.select.list.choices,
+            self.select.list.orientation,
+            self.select.list.checkbox_width(),
+            self.width,
+        )
+    }
+
+    fn handle_list_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(' ') => {
+                let index = self.select.get_at();
+                if self.select.list.is_locked(index) {
+                    return false;
+                }
+                self.select.list.selected[index] = !self.select.list.selected[index];
+            }
+            KeyCode::Char('i') => {
+                let list = &mut self.select.list;
+                for i in 0..list.selected.len() {
+                    if !list.is_locked(i) {
+                        list.selected[i] = !list.selected[i];
+                    }
+                }
+            }
+            KeyCode::Char('a') => {
+                let list = &mut self.select.list;
+                let select_state =
+                    (0..list.selected.len()).any(|i| !list.selected[i] && !list.is_locked(i));
+                for i in 0..list.selected.len() {
+                    if !list.is_locked(i) {
+                        list.selected[i] = select_state;
+                    }
+                }
+            }
+            _ if self.select.list.orientation == Orientation::Vertical => {
+                return self.select.handle_key(key)
+            }
+            _ => {
+                let at = self.select.get_at();
+                let should_loop = self.select.list.choices.should_loop();
+
+                match self.placement().handle_key(at, key, should_loop) {
+                    Some(at) => self.select.set_at(at),
+                    None => return false,
+                }
+            }
+        }
+
+        true
+    }
+}
+
 impl Prompt for MultiSelectPrompt<'_, '_> {
     type ValidateErr = widgets::Text<String>;
     type Output = Vec<ListItem>;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if let Some(ref message) = self.select.list.require_at_least_one {
+            let list = &self.select.list;
+            let any_selected = (0..list.selected.len())
+                .any(|i| list.selected[i] && !list.choices[i].is_separator());
+
+            if !any_selected {
+                return Err(Text::new(message.clone()));
+            }
+        }
+
         if let Validate::Sync(ref mut validate) = self.select.list.validate {
             set_seperators_false(
                 &mut self.select.list.selected,
@@ -75,6 +188,7 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
     fn finish(self) -> Self::Output {
         let MultiSelect {
             mut selected,
+            locked,
             choices,
             filter,
             ..
@@ -83,7 +197,13 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
         if let Filter::Sync(filter) = filter {
             set_seperators_false(&mut selected, &choices.choices);
 
+            let checked = selected.clone();
             selected = filter(selected, self.answers);
+
+            // locked choices cannot be changed, not even by the filter
+            for i in (0..locked.len()).filter(|&i| locked[i]) {
+                selected[i] = checked[i];
+            }
         }
 
         create_list_items(selected, choices)
@@ -92,35 +212,47 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
 
 impl Widget for MultiSelectPrompt<'_, '_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.fit_page_size(*layout);
         self.prompt.render(layout, b)?;
-        self.select.render(layout, b)
+
+        if let Orientation::Vertical = self.select.list.orientation {
+            return self.select.render(layout, b);
+        }
+
+        self.width = layout.available_width();
+
+        let list = &self.select.list;
+        self.placement().render(
+            self.select.get_at(),
+            list.choices.page_size(),
+            layout,
+            b,
+            |index, hovered, b| list.write_checkbox(index, hovered, b),
+        )
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.select.height(layout) - 1
-    }
+        self.fit_page_size(*layout);
 
-    fn handle_key(&mut self, key: KeyEvent) -> bool {
-        match key.code {
-            KeyCode::Char(' ') => {
-                let index = self.select.get_at();
-                self.select.list.selected[index] = !self.select.list.selected[index];
-            }
-            KeyCode::Char('i') => {
-                self.select.list.selected.iter_mut().for_each(|s| *s = !*s);
-            }
-            KeyCode::Char('a') => {
-                let select_state = self.select.list.selected.iter().any(|s| !s);
-                self.select
-                    .list
-                    .selected
-                    .iter_mut()
-                    .for_each(|s| *s = select_state);
-            }
-            _ => return self.select.handle_key(key),
+        if let Orientation::Vertical = self.select.list.orientation {
+            return self.prompt.height(layout) + self.select.height(layout) - 1;
         }
 
-        true
+        self.width = layout.available_width();
+
+        self.prompt.height(layout)
+            + self.placement().height(
+                self.select.get_at(),
+                self.select.list.choices.page_size(),
+                layout,
+            )
+            - 1
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        // the hint may collapse, which has to be rendered even if the key is not used
+        let collapsed = self.prompt.handle_key(key);
+        self.handle_list_key(key) || collapsed
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
@@ -136,33 +268,20 @@ impl widgets::List for MultiSelect<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
-        let symbol_set = ui::symbols::current();
         if hovered {
             b.set_fg(Color::Cyan)?;
-            write!(b, "{} ", symbol_set.pointer)?;
-        } else {
-            b.write_all(b"  ")?;
         }
 
-        if self.is_selectable(index) {
-            if self.selected[index] {
-                b.set_fg(Color::LightGreen)?;
-            } else {
-                b.set_fg(Color::DarkGrey)?;
-            }
-
-            write!(b, "{} ", symbol_set.completed)?;
+        let gutter_width = self.choices.gutter_width();
+        write_gutter(gutter_width, hovered, b)?;
 
-            if hovered {
-                b.set_fg(Color::Cyan)?;
-            } else {
-                b.set_fg(Color::Reset)?;
-            }
+        if self.is_selectable(index) {
+            self.write_checkbox(index, hovered, b)?;
         } else {
             b.set_fg(Color::DarkGrey)?;
         }
 
-        layout.offset_x += 4;
+        layout.offset_x += gutter_width + self.checkbox_width();
 
         self.choices[index].render(&mut layout, b)?;
 
@@ -174,7 +293,7 @@ impl widgets::List for MultiSelect<'_> {
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
-        layout.offset_x += 4;
+        layout.offset_x += self.choices.gutter_width() + self.checkbox_width();
         self.choices[index].height(&mut layout)
     }
 
@@ -189,9 +308,101 @@ impl widgets::List for MultiSelect<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn scroll_margin(&self) -> usize {
+        self.choices.scroll_margin()
+    }
+}
+
+impl MultiSelect<'_> {
+    /// Whether the choice at the given index is locked in its checked state.
+    fn is_locked(&self, index: usize) -> bool {
+        self.locked.get(index).copied().unwrap_or(false)
+    }
+
+    /// The width of the checkbox before a choice, including the space after it.
+    fn checkbox_width(&self) -> u16 {
+        match self.symbols {
+            Some((ref checked, ref unchecked)) => {
+                let width = ui::textwrap::core::display_width(checked)
+                    .max(ui::textwrap::core::display_width(unchecked));
+                width as u16 + 1
+            }
+            // `✔ `
+            None => 2,
+        }
+    }
+
+    /// Writes the checkbox of the choice at the given index padded to the [`checkbox_width`], and
+    /// sets the colour of the text after it. Locked choices are greyed out.
+    ///
+    /// [`checkbox_width`]: MultiSelect::checkbox_width
+    fn write_checkbox<B: Backend>(&self, index: usize, hovered: bool, b: &mut B) -> io::Result<()> {
+        let selected = self.selected[index];
+
+        if selected {
+            b.set_fg(Color::LightGreen)?;
+        } else {
+            b.set_fg(Color::DarkGrey)?;
+        }
+
+        match self.symbols {
+            Some((ref checked, ref unchecked)) => {
+                let symbol = if selected { checked } else { unchecked };
+                let padding =
+                    self.checkbox_width() as usize - ui::textwrap::core::display_width(symbol);
+                write!(b, "{}{:2$}", symbol, "", padding)?;
+            }
+            None => write!(b, "{} ", ui::symbols::current().completed)?,
+        }
+
+        if self.is_locked(index) {
+            b.set_fg(Color::DarkGrey)
+        } else if hovered {
+            b.set_fg(Color::Cyan)
+        } else {
+            b.set_fg(Color::Reset)
+        }
+    }
 }
 
 impl<'c> MultiSelect<'c> {
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let items = value
+            .try_into_list_items()
+            .expect("`multi_select` questions are answered with `ListItems`");
+
+        let mut prompt = self.into_multi_select_prompt("", answers);
+        let list = &mut prompt.select.list;
+
+        let locked: Vec<_> = (0..list.selected.len())
+            .filter(|&i| list.is_locked(i))
+            .map(|i| (i, list.selected[i]))
+            .collect();
+
+        list.selected
+            .iter_mut()
+            .for_each(|selected| *selected = false);
+
+        for item in items {
+            if item.index >= list.choices.len()
+                || list.choices[item.index].is_separator()
+                || locked.contains(&(item.index, false))
+            {
+                return Err(super::invalid_answer("Please select valid choices"));
+            }
+
+            list.selected[item.index] = true;
+        }
+
+        // locked choices which are checked are always part of the answer
+        for (i, checked) in locked {
+            list.selected[i] |= checked;
+        }
+
+        super::submit(prompt).map(Answer::ListItems)
+    }
+
     fn into_multi_select_prompt<'a>(
         self,
         message: &'a str,
@@ -199,28 +410,57 @@ impl<'c> MultiSelect<'c> {
     ) -> MultiSelectPrompt<'a, 'c> {
         MultiSelectPrompt {
             prompt: widgets::Prompt::new(message)
-                .with_hint("Press <space> to select, <a> to toggle all, <i> to invert selection"),
+                .with_hint("Press <space> to select, <a> to toggle all, <i> to invert selection")
+                .with_optional_hint_style(self.hint_style)
+                .with_collapsing_hint(self.collapse_hint),
             select: widgets::Select::new(self),
             answers,
+            width: 0,
         }
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
-        let transform = self.transform.take();
+        if !self.choices.has_selectable() {
+            return Err(super::invalid_answer(super::NO_CHOICES_ERROR));
+        }
+
+        let transform =
+            self.transform_with_choices
+                .take()
+                .into_transform(self.transform.take(), || {
+                    self.choices
+                        .choices
+                        .iter()
+                        .filter_map(|choice| match choice {
+                            Choice::Choice(text) => Some(text.text.clone()),
+                            _ => None,
+                        })
+                        .collect()
+                });
+        let show_cursor = self.show_cursor;
+
+        let mut input = ui::Input::new(self.into_multi_select_prompt(&message, answers), b)
+            .on_esc(exit.on_esc)
+            .confirm_on_interrupt(exit.interrupt_confirm)
+            .submit_on_tab(exit.submit_on_tab)
+            .back_on_back_tab(exit.back_on_back_tab)
+            .ensure_newline(exit.ensure_newline);
+
+        if !show_cursor {
+            input = input.hide_cursor();
+        }
 
-        let ans = ui::Input::new(self.into_multi_select_prompt(&message, answers), b)
-            .hide_cursor()
-            .on_esc(on_esc)
-            .run(events)?;
+        let ans = input.run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |ans| {
             b.set_fg(Color::Cyan)?;
             print_comma_separated(
                 ans.iter().map(|item| {
@@ -163,6 +163,13 @@ test_multi_select!(pagination {
     height = 17;
 });
 
+test_multi_select!(gutter_width_0 {
+    multi_select = unwrap_multi_select(
+            MultiSelectBuilder::new("name".into()).choices(choices(10)).gutter_width(0),
+        );
+    height = 12;
+});
+
 test_multi_select!(with_default {
     multi_select = unwrap_multi_select(
             MultiSelectBuilder::new("name".into()).choices_with_default(choices_with_default(10)),
@@ -0,0 +1,215 @@
+use std::io;
+
+use ui::{
+    backend::Backend,
+    events::{EventIterator, KeyEvent},
+    layout::Layout,
+    style::Stylize,
+    widgets::{self, Text},
+    Prompt, Widget,
+};
+
+use super::Options;
+use crate::Answer;
+
+#[derive(Debug, Default, Clone)]
+pub(super) struct Note {
+    wait_for_key: bool,
+}
+
+struct NotePrompt {
+    text: Text<String>,
+}
+
+impl Widget for NotePrompt {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.text.render(layout, b)?;
+        b.write_styled(&"(Press enter to continue)".dark_grey())?;
+        layout.offset_y += 1;
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let height = self.text.height(layout) + 1;
+        layout.offset_y += 1;
+        height
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        self.text.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, _: KeyEvent) -> bool {
+        false
+    }
+}
+
+impl Prompt for NotePrompt {
+    type ValidateErr = &'static str;
+    type Output = ();
+
+    fn finish(self) -> Self::Output {}
+}
+
+impl Note {
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        self,
+        message: String,
+        exit: super::ExitOptions,
+        silent: bool,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        // There is no one to press a key if the events are not interactive
+        if self.wait_for_key && events.is_interactive() {
+            let prompt = NotePrompt {
+                text: widgets::Text::new(message.clone()),
+            };
+
+            let res = ui::Input::new(prompt, b)
+                .hide_cursor()
+                .on_esc(exit.on_esc)
+                .confirm_on_interrupt(exit.interrupt_confirm)
+                .submit_on_tab(exit.submit_on_tab)
+                .back_on_back_tab(exit.back_on_back_tab)
+                .run(events)?;
+
+            // The note was skipped with `Esc`, so it is not printed
+            if res.is_none() {
+                return Ok(None);
+            }
+        }
+
+        if !silent {
+            b.write_all(message.as_bytes())?;
+            b.write_all(b"\n")?;
+        }
+
+        b.flush()?;
+
+        Ok(None)
+    }
+}
+
+/// The builder for a [`note`] pseudo-question.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let note = Question::note("instructions")
+///     .message("Answer the following questions to set up your project")
+///     .build();
+/// ```
+///
+/// [`note`]: crate::question::Question::note
+#[derive(Debug)]
+pub struct NoteBuilder<'a> {
+    opts: Options<'a>,
+    note: Note,
+}
+
+impl<'a> NoteBuilder<'a> {
+    pub(crate) fn new(name: String) -> Self {
+        NoteBuilder {
+            opts: Options::new(name),
+            note: Default::default(),
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let note = Question::note("instructions")
+    ///     .message("Answer the following questions to set up your project")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let note = Question::note("instructions")
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("first_time") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    on_esc
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, OnEsc};
+    ///
+    /// let note = Question::note("instructions")
+    ///     .wait_for_key(true)
+    ///     .on_esc(OnEsc::Terminate)
+    ///     .build();
+    /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let note = Question::note("instructions")
+    ///     .wait_for_key(true)
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+    }
+
+    /// Wait for the user to press `Enter` before continuing.
+    ///
+    /// The note is shown with a hint to press `Enter` until it is pressed, after which it is
+    /// printed like it would be without waiting. It does not wait if the events are not
+    /// interactive, such as when the input is piped. `Esc` is handled according to [`on_esc`].
+    ///
+    /// If `wait_for_key` is not set, it will default to `false`.
+    ///
+    /// [`on_esc`]: Self::on_esc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let note = Question::note("warning")
+    ///     .message("This will overwrite the existing configuration")
+    ///     .wait_for_key(true)
+    ///     .build();
+    /// ```
+    pub fn wait_for_key(mut self, wait_for_key: bool) -> Self {
+        self.note.wait_for_key = wait_for_key;
+        self
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> super::Question<'a> {
+        super::Question::new(self.opts, super::QuestionKind::Note(self.note))
+    }
+}
+
+impl<'a> From<NoteBuilder<'a>> for super::Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: NoteBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
@@ -63,6 +63,17 @@ macro_rules! builder {
             ///     .build();
             /// ```
 
+            on_answered
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::{Question, Answers};
+            ///
+            #[doc = $declare]
+            ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+            ///     .build();
+            /// ```
+
             on_esc
             /// # Examples
             ///
@@ -73,6 +84,39 @@ macro_rules! builder {
             ///     .on_esc(OnEsc::Terminate)
             ///     .build();
             /// ```
+
+            silent
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .silent(true)
+            ///     .build();
+            /// ```
+
+            secret
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .secret(true)
+            ///     .build();
+            /// ```
+
+            ensure_newline
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .ensure_newline(false)
+            ///     .build();
+            /// ```
             }
 
             /// Set a default value
@@ -153,6 +197,19 @@ macro_rules! builder {
             by val $inner_ty; inner
             }
 
+            crate::impl_on_invalid_hint_builder! {
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .on_invalid_hint("Only numbers are allowed")
+            ///     .build();
+            /// ```
+            inner
+            }
+
             crate::impl_transform_builder! {
             /// # Examples
             ///
@@ -165,6 +222,19 @@ macro_rules! builder {
             ///     })
             ///     .build();
             /// ```
+            with_layout
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .transform_with_layout(|n, previous_answers, layout, backend| {
+            ///         // Align the answer to the right of the terminal
+            ///         write!(backend, "{:>1$e}", n, layout.line_width() as usize)
+            ///     })
+            ///     .build();
+            /// ```
             by val $inner_ty; inner
             }
 
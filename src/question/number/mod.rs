@@ -29,6 +29,7 @@ pub(super) struct Float<'a> {
     validate: Validate<'a, f64>,
     validate_on_key: ValidateOnKey<'a, f64>,
     transform: Transform<'a, f64>,
+    on_invalid_hint: Option<String>,
 }
 
 #[derive(Debug, Default)]
@@ -38,9 +39,43 @@ pub(super) struct Int<'a> {
     validate: Validate<'a, i64>,
     validate_on_key: ValidateOnKey<'a, i64>,
     transform: Transform<'a, i64>,
+    on_invalid_hint: Option<String>,
 }
 
+macro_rules! impl_try_clone {
+    ($type:ident) => {
+        impl $type<'_> {
+            /// Returns a copy of the prompt, or `None` if it has a function, which cannot be
+            /// cloned.
+            pub(crate) fn try_clone(&self) -> Option<Self> {
+                Some(Self {
+                    default: self.default.clone(),
+                    filter: self.filter.try_clone()?,
+                    validate: self.validate.try_clone()?,
+                    validate_on_key: self.validate_on_key.try_clone()?,
+                    transform: self.transform.try_clone()?,
+                    on_invalid_hint: self.on_invalid_hint.clone(),
+                })
+            }
+        }
+    };
+}
+
+impl_try_clone!(Int);
+impl_try_clone!(Float);
+
 impl Int<'_> {
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let value = value
+            .as_int()
+            .expect("`int` questions are answered with an `Int`");
+
+        let mut prompt = self.into_prompt("", answers);
+        prompt.input.set_value(value.to_string());
+
+        super::submit(prompt).map(Answer::Int)
+    }
+
     fn write<B: Backend>(i: i64, b: &mut B) -> io::Result<()> {
         b.set_fg(Color::Cyan)?;
         write!(b, "{}", i)?;
@@ -61,9 +96,22 @@ impl Int<'_> {
 }
 
 impl Float<'_> {
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let value = value
+            .as_float()
+            .expect("`float` questions are answered with a `Float`");
+
+        let mut prompt = self.into_prompt("", answers);
+        prompt.input.set_value(value.to_string());
+
+        super::submit(prompt).map(Answer::Float)
+    }
+
     fn write<B: Backend>(f: f64, b: &mut B) -> io::Result<()> {
         b.set_fg(Color::Cyan)?;
-        if f.log10().abs() > 19.0 {
+        // `log10` is not finite for zero and `NaN` for negative numbers, so only the magnitude of a
+        // non-zero number is used
+        if f != 0.0 && f.abs().log10().abs() > 19.0 {
             write!(b, "{:e}", f)?;
         } else {
             write!(b, "{}", f)?;
@@ -269,6 +317,19 @@ impl_number_prompt!(FloatPrompt, Float, f64);
 macro_rules! impl_ask {
     ($t:ident, $prompt_name:ident) => {
         impl<'n> $t<'n> {
+            /// Runs `validate` against the default, if there is one.
+            pub(crate) fn check_default(
+                &mut self,
+                answers: &Answers,
+            ) -> Option<Result<(), String>> {
+                match (&self.default, &mut self.validate) {
+                    (Some((default, _)), Validate::Sync(validate)) => {
+                        Some(validate(*default, answers))
+                    }
+                    _ => None,
+                }
+            }
+
             fn into_prompt<'a>(
                 self,
                 message: &'a str,
@@ -286,20 +347,39 @@ macro_rules! impl_ask {
             pub(crate) fn ask<B: Backend, E: EventIterator>(
                 mut self,
                 message: String,
-                on_esc: ui::OnEsc,
+                exit: super::ExitOptions,
+                silent: bool,
                 answers: &Answers,
                 b: &mut B,
                 events: &mut E,
             ) -> ui::Result<Option<Answer>> {
                 let transform = self.transform.take();
+                let on_invalid_hint = self.on_invalid_hint.take();
+
+                let prompt = self.into_prompt(&message, answers);
+
+                let ans = if events.is_interactive() {
+                    ui::Input::new(prompt, b)
+                        .on_esc(exit.on_esc)
+                        .confirm_on_interrupt(exit.interrupt_confirm)
+                        .submit_on_tab(exit.submit_on_tab)
+                        .back_on_back_tab(exit.back_on_back_tab)
+                        .ensure_newline(exit.ensure_newline)
+                        .on_invalid_hint(on_invalid_hint)
+                        .run(events)?
+                } else {
+                    let has_default = prompt.number.default.is_some();
+                    Some(super::answer_piped(
+                        prompt,
+                        has_default,
+                        |prompt, line| prompt.input.set_value(line),
+                        events,
+                    )?)
+                };
 
-                let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-                    .on_esc(on_esc)
-                    .run(events)?;
-
-                crate::write_final!(transform, message, ans, answers, b, |ans| Self::write(
-                    ans, b
-                )?)
+                crate::write_final!(transform, silent, message, ans, answers, b, |ans| {
+                    Self::write(ans, b)?
+                })
             }
         }
     };
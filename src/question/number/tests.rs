@@ -98,3 +98,33 @@ macro_rules! test_numbers {
 
 test_numbers!(mod int { Int, 333 });
 test_numbers!(mod float { Float, 3.3 });
+
+#[test]
+fn test_float_write() {
+    use std::io::Write;
+
+    use ui::{backend::TestBackend, style::Color};
+
+    let cases = [
+        (-12345.0, "-12345"),
+        (-0.5, "-0.5"),
+        (0.0, "0"),
+        (-0.0, "-0"),
+        (1e25, "1e25"),
+        (-1e25, "-1e25"),
+        (-1e-25, "-1e-25"),
+    ];
+
+    for &(f, text) in cases.iter() {
+        let size = (50, 20).into();
+        let mut backend = TestBackend::new(size);
+        Float::write(f, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        expected.set_fg(Color::Cyan).unwrap();
+        expected.write_all(text.as_bytes()).unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+
+        expected.assert_eq(&backend);
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use ui::OnEsc;
 
-use crate::Answers;
+use crate::{Answer, Answers};
 
 #[derive(Debug)]
 pub(crate) struct Options<'a> {
@@ -10,7 +10,14 @@ pub(crate) struct Options<'a> {
     pub(crate) message: Option<Getter<'a, String>>,
     pub(crate) when: Getter<'a, bool>,
     pub(crate) ask_if_answered: bool,
+    pub(crate) on_answered: Option<OnAnswered<'a>>,
     pub(crate) on_esc: Getter<'a, OnEsc>,
+    pub(crate) interrupt_confirm: Option<String>,
+    pub(crate) submit_on_tab: bool,
+    pub(crate) back_on_back_tab: bool,
+    pub(crate) silent: bool,
+    pub(crate) secret: bool,
+    pub(crate) ensure_newline: bool,
 }
 
 impl<'a> Options<'a> {
@@ -20,9 +27,42 @@ impl<'a> Options<'a> {
             message: None,
             when: true.into(),
             ask_if_answered: false,
+            on_answered: None,
             on_esc: OnEsc::Ignore.into(),
+            interrupt_confirm: None,
+            submit_on_tab: false,
+            back_on_back_tab: false,
+            silent: false,
+            secret: false,
+            ensure_newline: true,
         }
     }
+
+    /// Returns a copy of the options, or `None` if any of them is a function, which cannot be
+    /// cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        if self.on_answered.is_some() {
+            return None;
+        }
+
+        Some(Options {
+            name: self.name.clone(),
+            message: match self.message {
+                Some(ref message) => Some(message.try_clone()?),
+                None => None,
+            },
+            when: self.when.try_clone()?,
+            ask_if_answered: self.ask_if_answered,
+            on_answered: None,
+            on_esc: self.on_esc.try_clone()?,
+            interrupt_confirm: self.interrupt_confirm.clone(),
+            submit_on_tab: self.submit_on_tab,
+            back_on_back_tab: self.back_on_back_tab,
+            silent: self.silent,
+            secret: self.secret,
+            ensure_newline: self.ensure_newline,
+        })
+    }
 }
 
 #[doc(hidden)]
@@ -32,8 +72,12 @@ macro_rules! impl_options_builder {
     // other docs that come from the macro invocation have appropriate spacing
     (message $(#[$message_meta:meta])*
      when $(#[$when_meta:meta])*
-     ask_if_answered $(#[$ask_if_answered_meta:meta])*
-     $(on_esc $(#[$on_esc_meta:meta])*)?) => {
+     $(ask_if_answered $(#[$ask_if_answered_meta:meta])*)?
+     $(on_answered $(#[$on_answered_meta:meta])*)?
+     $(on_esc $(#[$on_esc_meta:meta])*)?
+     $(silent $(#[$silent_meta:meta])*)?
+     $(secret $(#[$secret_meta:meta])*)?
+     $(ensure_newline $(#[$ensure_newline_meta:meta])*)?) => {
         /// The message to display when the prompt is rendered in the terminal.
         ///
         /// It can be either a [`String`] or a [`FnOnce`] that returns a [`String`]. If it is a
@@ -74,6 +118,7 @@ macro_rules! impl_options_builder {
             self
         }
 
+        $(
         /// Prompt the question even if it is answered.
         ///
         /// By default if an answer with the given `name` already exists, the question will be
@@ -92,6 +137,29 @@ macro_rules! impl_options_builder {
             self.opts.ask_if_answered = ask_if_answered;
             self
         }
+        )?
+
+        $(
+        /// A function which is called with the name of the question and its answer once it is
+        /// answered.
+        ///
+        /// This is meant to store the answers as they come in, such as in a file or a database. It
+        /// is called after the prompt has finished and its answer has been printed, so it cannot
+        /// interfere with the rendering. It is called whether the question is asked on its own or
+        /// as part of a [`PromptModule`], but not if the question is skipped.
+        ///
+        /// [`PromptModule`]: crate::PromptModule
+        ///
+        ///
+        $(#[$on_answered_meta])*
+        pub fn on_answered<F>(mut self, on_answered: F) -> Self
+        where
+            F: FnOnce(&str, &$crate::Answer) + 'a,
+        {
+            self.opts.on_answered = Some($crate::question::options::OnAnswered(Box::new(on_answered)));
+            self
+        }
+        )?
 
         $(
         /// Configure what to do when the user presses the `Esc` key.
@@ -115,9 +183,80 @@ macro_rules! impl_options_builder {
             self
         }
         )?
+
+        $(
+        /// Do not print anything once the question is answered.
+        ///
+        /// Normally, after a question is answered, the message is reprinted along with a summary of
+        /// the answer (or the output of `transform` if it is given). If `silent` is `true`, the
+        /// prompt is cleared and nothing is printed in its place, not even the `transform`. This
+        /// is useful if the answer is being shown elsewhere.
+        ///
+        /// If this is not given, it defaults to `false`.
+        ///
+        $(#[$silent_meta])*
+        pub fn silent(mut self, silent: bool) -> Self {
+            self.opts.silent = silent;
+            self
+        }
+        )?
+
+        $(
+        /// Keep the answer secret when it is shown outside of the program.
+        ///
+        /// The [`Answers`] and the returned answer still hold the real answer, but it is shown as
+        /// `****` everywhere else: the answer given to [`on_answered`], [`Answers::summary_line`],
+        /// the `Debug` output of the [`Answers`] and the answers of a [`Transcript`]. The keys
+        /// pressed for the question are also not recorded in a [`Transcript`], so its answer has
+        /// to be given again to replay it.
+        ///
+        /// If this is not given, it defaults to `false`, except for a [`password`] where it
+        /// defaults to `true`.
+        ///
+        /// [`Answers`]: crate::Answers
+        /// [`Answers::summary_line`]: crate::Answers::summary_line
+        /// [`on_answered`]: Self::on_answered
+        /// [`Transcript`]: crate::Transcript
+        /// [`password`]: crate::question::Question::password
+        ///
+        ///
+        $(#[$secret_meta])*
+        pub fn secret(mut self, secret: bool) -> Self {
+            self.opts.secret = secret;
+            self
+        }
+        )?
+
+        $(
+        /// Start the prompt on a new line if the cursor is not at the start of a line.
+        ///
+        /// If the output before the question did not end with a newline, the prompt would
+        /// otherwise overwrite the rest of that line. Set this to `false` to start the prompt
+        /// where the cursor is instead.
+        ///
+        /// If this is not given, it defaults to `true`.
+        ///
+        ///
+        $(#[$ensure_newline_meta])*
+        pub fn ensure_newline(mut self, ensure_newline: bool) -> Self {
+            self.opts.ensure_newline = ensure_newline;
+            self
+        }
+        )?
     };
 }
 
+/// A function called with the name of a question and its answer once it is answered.
+pub(crate) struct OnAnswered<'a>(pub(crate) Box<OnAnsweredFn<'a>>);
+
+type OnAnsweredFn<'a> = dyn FnOnce(&str, &Answer) + 'a;
+
+impl fmt::Debug for OnAnswered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OnAnswered(_)")
+    }
+}
+
 /// Optionally dynamically get a value.
 ///
 /// It can either be a [`FnOnce`] that results in a value, or the value itself.
@@ -147,6 +286,16 @@ impl<T> Getter<'_, T> {
     }
 }
 
+impl<T: Clone> Getter<'_, T> {
+    /// Returns a copy of the getter, or `None` if it is a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        match self {
+            Getter::Function(_) => None,
+            Getter::Value(v) => Some(Getter::Value(v.clone())),
+        }
+    }
+}
+
 macro_rules! impl_getter_from_val {
     ($T:ty, $I:ty) => {
         impl_getter_from_val!($T, $I, value => value.into());
@@ -89,6 +89,19 @@ impl<'a> OrderSelectBuilder<'a> {
         ///     .build();
         /// ```
 
+        on_answered
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::{Answers, Question};
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+        ///     //...
+        ///     .build();
+        /// ```
+
         on_esc
         /// # Examples
         ///
@@ -101,6 +114,45 @@ impl<'a> OrderSelectBuilder<'a> {
         ///     //...
         ///     .build();
         /// ```
+
+        silent
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .silent(true)
+        ///     //...
+        ///     .build();
+        /// ```
+
+        secret
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .secret(true)
+        ///     //...
+        ///     .build();
+        /// ```
+
+        ensure_newline
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .ensure_newline(false)
+        ///     //...
+        ///     .build();
+        /// ```
     }
 
     /// The maximum height that can be taken by the list
@@ -129,6 +181,27 @@ impl<'a> OrderSelectBuilder<'a> {
         self
     }
 
+    /// Fit the page size to the height of the terminal
+    ///
+    /// The page size is recomputed whenever the prompt is rendered, so it adapts if the terminal
+    /// is resized. It leaves space for the rest of the prompt, and is still a minimum of 5.
+    ///
+    /// This is overridden by [`page_size`](Self::page_size) if it is called after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("cheese")
+    ///     .page_size_auto()
+    ///     .build();
+    /// ```
+    pub fn page_size_auto(mut self) -> Self {
+        self.order_select.choices.set_page_size_auto();
+        self
+    }
+
     /// Whether to wrap around when user gets to the last element.
     ///
     /// If `should_loop` is not set, it will default to `true`.
@@ -147,6 +220,54 @@ impl<'a> OrderSelectBuilder<'a> {
         self
     }
 
+    /// The minimum number of elements to keep visible above and below the hovered element when
+    /// the list is scrolled.
+    ///
+    /// The margin is kept where possible. It is capped based on the [`page_size`], and cannot be
+    /// kept near the ends of the list if [`should_loop`] is `false`. If `scroll_margin` is not
+    /// set, it will default to 1.
+    ///
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("cheese")
+    ///     .scroll_margin(3)
+    ///     .build();
+    /// ```
+    pub fn scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.order_select.choices.set_scroll_margin(scroll_margin);
+        self
+    }
+
+    /// The width of the gutter on the left of every element in the list, which is where the
+    /// pointer to the hovered element is shown.
+    ///
+    /// It can be 0, 1 or 2. If it is 0, the pointer is not shown, and if it is 1, the pointer is
+    /// not followed by a space. If `gutter_width` is not set, it will default to 2.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `gutter_width` is more than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("cheese")
+    ///     .gutter_width(0)
+    ///     .build();
+    /// ```
+    pub fn gutter_width(mut self, gutter_width: u16) -> Self {
+        self.order_select.choices.set_gutter_width(gutter_width);
+        self
+    }
+
     /// Extends the given iterator of [`Choice`]s
     ///
     /// The choices are [`String`]s and can be multiline.
@@ -229,6 +350,32 @@ impl<'a> OrderSelectBuilder<'a> {
         [OrderSelectItem]; order_select
     }
 
+    crate::impl_hint_style_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Attributes, Color, Style}, Question};
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD))
+    ///     .build();
+    /// ```
+    order_select
+    }
+
+    crate::impl_collapse_hint_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let order_select = Question::order_select("home_tasks")
+    ///     .collapse_hint(true)
+    ///     .build();
+    /// ```
+    order_select
+    }
+
     crate::impl_transform_builder! {
         /// # Examples
         ///
@@ -246,6 +393,22 @@ impl<'a> OrderSelectBuilder<'a> {
         ///     //...
         ///     .build();
         /// ```
+        with_layout
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("items")
+        ///     //...
+        ///     .transform_with_layout(|items, previous_answers, layout, backend| {
+        ///         // Align the answer to the right of the terminal
+        ///         let items: Vec<_> = items.iter().map(|item| item.text()).collect();
+        ///         write!(backend, "{:>1$}", items.join(", "), layout.line_width() as usize)
+        ///     })
+        ///     //...
+        ///     .build();
+        /// ```
         [OrderSelectItem]; order_select
     }
 
@@ -254,7 +417,7 @@ impl<'a> OrderSelectBuilder<'a> {
     /// [`Question`]: crate::question::Question
     pub fn build(mut self) -> crate::question::Question<'a> {
         self.order_select.max_index_width =
-            (self.order_select.choices.len() as f64 + 1.0).log10() as usize + 1;
+            crate::question::number_of_digits(self.order_select.choices.len()) as usize;
 
         crate::question::Question::new(
             self.opts,
@@ -9,7 +9,7 @@ use ui::{
 };
 
 use super::{
-    choice::SelectList,
+    choice::{write_gutter, SelectList},
     handler::{Filter, Transform, Validate},
 };
 use crate::{Answer, Answers};
@@ -28,6 +28,10 @@ pub(super) struct OrderSelect<'a> {
     choices: SelectList<OrderSelectItem>,
     max_index_width: usize,
     moving: bool,
+    /// The style of the hint, or the default style if `None`.
+    hint_style: Option<ui::style::Style>,
+    /// Whether the hint is hidden once the first key is pressed.
+    collapse_hint: bool,
 
     transform: Transform<'a, [OrderSelectItem]>,
     validate: Validate<'a, [OrderSelectItem]>,
@@ -44,6 +48,8 @@ impl<'a> Default for OrderSelect<'a> {
             // because of recursion
             max_index_width: Default::default(),
             moving: Default::default(),
+            hint_style: Default::default(),
+            collapse_hint: Default::default(),
             transform: Default::default(),
             validate: Default::default(),
             filter: Default::default(),
@@ -51,6 +57,22 @@ impl<'a> Default for OrderSelect<'a> {
     }
 }
 
+impl OrderSelect<'_> {
+    /// Returns a copy of the order select, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            choices: self.choices.clone(),
+            max_index_width: self.max_index_width,
+            moving: self.moving,
+            hint_style: self.hint_style,
+            collapse_hint: self.collapse_hint,
+            transform: self.transform.try_clone()?,
+            validate: self.validate.try_clone()?,
+            filter: self.filter.try_clone()?,
+        })
+    }
+}
+
 impl widgets::List for OrderSelect<'_> {
     fn render_item<B: ui::backend::Backend>(
         &mut self,
@@ -59,8 +81,6 @@ impl widgets::List for OrderSelect<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> std::io::Result<()> {
-        let symbol_set = ui::symbols::current();
-
         if hovered {
             if self.moving {
                 b.set_bg(Color::Cyan)?;
@@ -68,12 +88,11 @@ impl widgets::List for OrderSelect<'_> {
             } else {
                 b.set_fg(Color::Cyan)?;
             }
-
-            write!(b, "{} ", symbol_set.pointer)?;
-        } else {
-            b.write_all(b"  ")?;
         }
 
+        let gutter_width = self.choices.gutter_width();
+        write_gutter(gutter_width, hovered, b)?;
+
         write!(
             b,
             "{:>width$}. ",
@@ -81,7 +100,7 @@ impl widgets::List for OrderSelect<'_> {
             width = self.max_index_width as usize
         )?;
 
-        layout.offset_x += self.max_index_width as u16 + 4;
+        layout.offset_x += self.max_index_width as u16 + gutter_width + 2;
 
         self.choices[index].render(&mut layout, b)?;
 
@@ -101,8 +120,12 @@ impl widgets::List for OrderSelect<'_> {
         self.choices.should_loop()
     }
 
+    fn scroll_margin(&self) -> usize {
+        self.choices.scroll_margin()
+    }
+
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
-        layout.offset_x += self.max_index_width as u16 + 4;
+        layout.offset_x += self.max_index_width as u16 + self.choices.gutter_width() + 2;
         self.choices[index].height(&mut layout)
     }
 
@@ -112,6 +135,31 @@ impl widgets::List for OrderSelect<'_> {
 }
 
 impl<'c> OrderSelect<'c> {
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let items = value
+            .try_into_list_items()
+            .expect("`order_select` questions are answered with `ListItems`");
+
+        let mut prompt = self.into_order_select_prompt("", answers);
+        let choices = &mut prompt.select.list.choices.choices;
+
+        // The choices are still in their initial order, so the index of each is its initial index
+        let mut initial: Vec<_> = choices.drain(..).map(Some).collect();
+
+        for item in items {
+            match initial.get_mut(item.index).and_then(Option::take) {
+                Some(choice) => choices.push(choice),
+                None => return Err(super::invalid_answer("Please order every choice once")),
+            }
+        }
+
+        if choices.len() != initial.len() {
+            return Err(super::invalid_answer("Please order every choice once"));
+        }
+
+        super::submit(prompt).map(Answer::from)
+    }
+
     fn into_order_select_prompt<'a>(
         self,
         message: &'a str,
@@ -119,7 +167,9 @@ impl<'c> OrderSelect<'c> {
     ) -> OrderSelectPrompt<'a, 'c> {
         OrderSelectPrompt {
             prompt: widgets::Prompt::new(message)
-                .with_hint("Press <space> to take and place an option"),
+                .with_hint("Press <space> to take and place an option")
+                .with_optional_hint_style(self.hint_style)
+                .with_collapsing_hint(self.collapse_hint),
             select: widgets::Select::new(self),
             answers,
         }
@@ -128,7 +178,8 @@ impl<'c> OrderSelect<'c> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -137,10 +188,14 @@ impl<'c> OrderSelect<'c> {
 
         let ans = ui::Input::new(self.into_order_select_prompt(&message, answers), b)
             .hide_cursor()
-            .on_esc(on_esc)
+            .on_esc(exit.on_esc)
+            .confirm_on_interrupt(exit.interrupt_confirm)
+            .submit_on_tab(exit.submit_on_tab)
+            .back_on_back_tab(exit.back_on_back_tab)
+            .ensure_newline(exit.ensure_newline)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |ans| {
             b.set_fg(Color::Cyan)?;
             print_comma_separated(
                 ans.iter().map(|item| {
@@ -179,6 +234,39 @@ struct OrderSelectPrompt<'a, 'c> {
     answers: &'a Answers,
 }
 
+impl OrderSelectPrompt<'_, '_> {
+    /// Fits the page size to the terminal height, leaving space for the prompt.
+    fn fit_page_size(&mut self, layout: ui::layout::Layout) {
+        let reserved = self.prompt.height(&mut layout.clone());
+        self.select.list.choices.fit_page_size(layout, reserved);
+    }
+
+    fn handle_list_key(&mut self, key: ui::events::KeyEvent) -> bool {
+        let prev_at = self.select.get_at();
+
+        if let ui::events::KeyCode::Char(' ') = key.code {
+            self.select.list.moving = !self.select.list.moving;
+        } else if self.select.handle_key(key) {
+            if self.select.list.moving {
+                let new_at = self.select.get_at();
+
+                if prev_at < new_at {
+                    self.select.list.choices.choices[prev_at..=new_at].rotate_left(1);
+                } else {
+                    self.select.list.choices.choices[new_at..=prev_at].rotate_right(1);
+                }
+
+                // The moved choice may have a different height than the ones it moved past
+                self.select.invalidate_heights();
+            }
+        } else {
+            return false;
+        }
+
+        true
+    }
+}
+
 impl Prompt for OrderSelectPrompt<'_, '_> {
     type ValidateErr = widgets::Text<String>;
     type Output = Vec<OrderSelectItem>;
@@ -211,11 +299,13 @@ impl Widget for OrderSelectPrompt<'_, '_> {
         layout: &mut ui::layout::Layout,
         backend: &mut B,
     ) -> io::Result<()> {
+        self.fit_page_size(*layout);
         self.prompt.render(layout, backend)?;
         self.select.render(layout, backend)
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.fit_page_size(*layout);
         self.prompt.height(layout) + self.select.height(layout) - 1
     }
 
@@ -224,25 +314,9 @@ impl Widget for OrderSelectPrompt<'_, '_> {
     }
 
     fn handle_key(&mut self, key: ui::events::KeyEvent) -> bool {
-        let prev_at = self.select.get_at();
-
-        if let ui::events::KeyCode::Char(' ') = key.code {
-            self.select.list.moving = !self.select.list.moving;
-        } else if self.select.handle_key(key) {
-            if self.select.list.moving {
-                let new_at = self.select.get_at();
-
-                if prev_at < new_at {
-                    self.select.list.choices.choices[prev_at..=new_at].rotate_left(1);
-                } else {
-                    self.select.list.choices.choices[new_at..=prev_at].rotate_right(1);
-                }
-            }
-        } else {
-            return false;
-        }
-
-        true
+        // the hint may collapse, which has to be rendered even if the key is not used
+        let collapsed = self.prompt.handle_key(key);
+        self.handle_list_key(key) || collapsed
     }
 }
 
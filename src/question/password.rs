@@ -7,18 +7,43 @@ use ui::{
     widgets, Validation, Widget,
 };
 
-use super::{Filter, Options, Transform, Validate, ValidateOnKey};
+use super::{Filter, Options, Transform, Validate, ValidateOnKey, REQUIRED_ERROR};
 use crate::{Answer, Answers};
 
 #[derive(Debug, Default)]
 pub(super) struct Password<'a> {
     mask: Option<char>,
+    /// The style of the hint, or the default style if `None`.
+    hint_style: Option<ui::style::Style>,
+    /// Whether an empty answer is rejected.
+    required: bool,
+    /// The style of the required marker, or the default style if `None`.
+    required_style: Option<ui::style::Style>,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
+    /// Whether to run `validate` every time the value changes.
+    validate_on_change: bool,
     transform: Transform<'a, str>,
 }
 
+impl Password<'_> {
+    /// Returns a copy of the password, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            mask: self.mask,
+            hint_style: self.hint_style,
+            required: self.required,
+            required_style: self.required_style,
+            filter: self.filter.try_clone()?,
+            validate: self.validate.try_clone()?,
+            validate_on_key: self.validate_on_key.try_clone()?,
+            validate_on_change: self.validate_on_change,
+            transform: self.transform.try_clone()?,
+        })
+    }
+}
+
 struct PasswordPrompt<'a, 'p> {
     prompt: widgets::Prompt<&'a str>,
     password: Password<'p>,
@@ -32,6 +57,10 @@ impl ui::Prompt for PasswordPrompt<'_, '_> {
     type Output = String;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if self.password.required && self.input.value().is_empty() {
+            return Err(widgets::Text::new(REQUIRED_ERROR.into()));
+        }
+
         if let Validate::Sync(ref mut validate) = self.password.validate {
             validate(self.input.value(), self.answers)?;
         }
@@ -39,6 +68,14 @@ impl ui::Prompt for PasswordPrompt<'_, '_> {
         Ok(Validation::Finish)
     }
 
+    fn validate_on_change(&mut self) -> Result<(), Self::ValidateErr> {
+        if let Validate::Sync(ref mut validate) = self.password.validate {
+            validate(self.input.value(), self.answers)?;
+        }
+
+        Ok(())
+    }
+
     fn finish(self) -> Self::Output {
         let mut ans = self.input.finish();
 
@@ -91,6 +128,17 @@ impl Widget for PasswordPrompt<'_, '_> {
 }
 
 impl<'p> Password<'p> {
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let value = value
+            .try_into_string()
+            .expect("`password` questions are answered with a `String`");
+
+        let mut prompt = self.into_prompt("", answers);
+        prompt.input.set_value(value);
+
+        super::submit(prompt).map(Answer::String)
+    }
+
     fn into_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> PasswordPrompt<'a, 'p> {
         PasswordPrompt {
             prompt: widgets::Prompt::new(message)
@@ -99,7 +147,10 @@ impl<'p> Password<'p> {
                     Some("input is hidden")
                 } else {
                     None
-                }),
+                })
+                .with_optional_hint_style(self.hint_style)
+                .with_required(self.required)
+                .with_optional_required_style(self.required_style),
             input: widgets::StringInput::default().password(self.mask),
             is_valid: true,
             password: self,
@@ -110,19 +161,38 @@ impl<'p> Password<'p> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
-
-        let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-            .on_esc(on_esc)
-            .run(events)?;
+        let validate_on_change = self.validate_on_change;
+
+        let prompt = self.into_prompt(&message, answers);
+
+        let ans = if events.is_interactive() {
+            ui::Input::new(prompt, b)
+                .on_esc(exit.on_esc)
+                .confirm_on_interrupt(exit.interrupt_confirm)
+                .submit_on_tab(exit.submit_on_tab)
+                .back_on_back_tab(exit.back_on_back_tab)
+                .ensure_newline(exit.ensure_newline)
+                .validate_on_change(validate_on_change)
+                .run(events)?
+        } else {
+            Some(super::answer_piped(
+                prompt,
+                false,
+                |prompt, line| prompt.input.set_value(line),
+                events,
+            )?)
+        };
 
         crate::write_final!(
             transform,
+            silent,
             message,
             ans [ref],
             answers,
@@ -170,8 +240,11 @@ pub struct PasswordBuilder<'a> {
 
 impl<'a> PasswordBuilder<'a> {
     pub(crate) fn new(name: String) -> Self {
+        let mut opts = Options::new(name);
+        opts.secret = true;
+
         PasswordBuilder {
-            opts: Options::new(name),
+            opts,
             password: Default::default(),
         }
     }
@@ -213,6 +286,17 @@ impl<'a> PasswordBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -223,6 +307,39 @@ impl<'a> PasswordBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .secret(false)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a mask to print instead of the characters
@@ -258,6 +375,35 @@ impl<'a> PasswordBuilder<'a> {
     String; password
     }
 
+    crate::impl_required_builder! {
+    required
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .required(true)
+    ///     .build();
+    /// ```
+
+    required_style
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{
+    ///     prompt::style::{Color, Style},
+    ///     Question,
+    /// };
+    ///
+    /// let password = Question::password("password")
+    ///     .required(true)
+    ///     .required_style(Style::new().fg(Color::Magenta))
+    ///     .build();
+    /// ```
+    password
+    }
+
     crate::impl_validate_builder! {
     /// # Examples
     ///
@@ -275,6 +421,24 @@ impl<'a> PasswordBuilder<'a> {
     str; password
     }
 
+    crate::impl_validate_on_change_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .validate(|password, previous_answers| if password.chars().count() >= 5 {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("Your password must be at least 5 characters long".to_owned())
+    ///     })
+    ///     .validate_on_change(true)
+    ///     .build();
+    /// ```
+    password
+    }
+
     crate::impl_validate_on_key_builder! {
     /// Note, this method will have no visual effect if the password is hidden instead of masked.
     ///
@@ -296,6 +460,19 @@ impl<'a> PasswordBuilder<'a> {
     str; password
     }
 
+    crate::impl_hint_style_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{prompt::style::{Attributes, Color, Style}, Question};
+    ///
+    /// let password = Question::password("password")
+    ///     .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD))
+    ///     .build();
+    /// ```
+    password
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -313,6 +490,20 @@ impl<'a> PasswordBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .transform_with_layout(|password, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         let masked = "*".repeat(password.chars().count());
+    ///         write!(backend, "{:>1$}", masked, layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
     str; password
     }
 
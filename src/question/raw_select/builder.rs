@@ -2,7 +2,7 @@ use ui::{backend::Backend, widgets::Text};
 
 use super::RawSelect;
 use crate::{
-    question::{Choice, Options},
+    question::{Choice, Format, Options, Predicate},
     ListItem,
 };
 
@@ -42,6 +42,7 @@ pub struct RawSelectBuilder<'a> {
     opts: Options<'a>,
     raw_select: RawSelect<'a>,
     choice_count: usize,
+    default_by: Predicate<'a, str>,
 }
 
 impl<'a> RawSelectBuilder<'a> {
@@ -51,6 +52,7 @@ impl<'a> RawSelectBuilder<'a> {
             raw_select: Default::default(),
             // It is one indexed for the user
             choice_count: 1,
+            default_by: Predicate::None,
         }
     }
 
@@ -91,6 +93,17 @@ impl<'a> RawSelectBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -101,6 +114,39 @@ impl<'a> RawSelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default index for the select
@@ -135,6 +181,45 @@ impl<'a> RawSelectBuilder<'a> {
     /// ```
     pub fn default(mut self, default: usize) -> Self {
         self.raw_select.choices.set_default(default);
+        self.default_by = Predicate::None;
+        self
+    }
+
+    /// Set the default to the first [`Choice`] whose text satisfies the given predicate
+    ///
+    /// The predicate is only checked against [`Choice`]s, separators are skipped. It is resolved
+    /// to an index on [`build`], so it does not matter whether the choices are added before or
+    /// after calling this. If both `default` and `default_by` are called, the last one wins.
+    ///
+    /// # Panics
+    ///
+    /// If no [`Choice`] satisfies the predicate, it will cause a panic on [`build`]
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`build`]: Self::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, DefaultSeparator};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .choices(vec![
+    ///         "Order a pizza".into(),
+    ///         "Make a reservation".into(),
+    ///         DefaultSeparator,
+    ///         "Ask for opening hours".into(),
+    ///         "Contact support".into(),
+    ///         "Talk to the receptionist".into(),
+    ///     ])
+    ///     .default_by(|choice| choice.starts_with("Contact"))
+    ///     .build();
+    /// ```
+    pub fn default_by<F>(mut self, default_by: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'a,
+    {
+        self.default_by = Predicate::Sync(Box::new(default_by));
         self
     }
 
@@ -164,6 +249,48 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// Fit the page size to the height of the terminal
+    ///
+    /// The page size is recomputed whenever the prompt is rendered, so it adapts if the terminal
+    /// is resized. It leaves space for the rest of the prompt, and is still a minimum of 5.
+    ///
+    /// This is overridden by [`page_size`](Self::page_size) if it is called after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .page_size_auto()
+    ///     .build();
+    /// ```
+    pub fn page_size_auto(mut self) -> Self {
+        self.raw_select.choices.set_page_size_auto();
+        self
+    }
+
+    /// Whether to show the answer line where the index of a choice can be typed.
+    ///
+    /// If it is `false`, the answer line is hidden and the choices can only be navigated with the
+    /// arrow keys.
+    ///
+    /// If `show_number_input` is not set, it will default to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .show_number_input(false)
+    ///     .build();
+    /// ```
+    pub fn show_number_input(mut self, show_number_input: bool) -> Self {
+        self.raw_select.hide_number_input = !show_number_input;
+        self
+    }
+
     /// Whether to wrap around when user gets to the last element.
     ///
     /// If `should_loop` is not set, it will default to `true`.
@@ -182,6 +309,52 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// The minimum number of elements to keep visible above and below the hovered element when
+    /// the list is scrolled.
+    ///
+    /// The margin is kept where possible. It is capped based on the [`page_size`], and cannot be
+    /// kept near the ends of the list if [`should_loop`] is `false`. If `scroll_margin` is not
+    /// set, it will default to 1.
+    ///
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .scroll_margin(3)
+    ///     .build();
+    /// ```
+    pub fn scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.raw_select.choices.set_scroll_margin(scroll_margin);
+        self
+    }
+
+    /// The width of the gutter on the left of every element in the list.
+    ///
+    /// It can be 0, 1 or 2. If `gutter_width` is not set, it will default to 2.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `gutter_width` is more than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .gutter_width(0)
+    ///     .build();
+    /// ```
+    pub fn gutter_width(mut self, gutter_width: u16) -> Self {
+        self.raw_select.choices.set_gutter_width(gutter_width);
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`raw_select`] for more information.
@@ -297,6 +470,34 @@ impl<'a> RawSelectBuilder<'a> {
         self
     }
 
+    /// Function to change how the answer is shown after the user selects a [`Choice`].
+    ///
+    /// It is a [`FnOnce`] that is given the selected [`ListItem`], and returns the text to show in
+    /// place of the selected choice. The text is shown in cyan, like the default.
+    ///
+    /// This is a lighter alternative to [`transform`] for when only the text needs to change. If
+    /// both are given, [`transform`] takes precedence and the `answer_format` is ignored.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`transform`]: Self::transform
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .answer_format(|choice| format!("You chose: {} (id {})", choice.text, choice.index))
+    ///     .build();
+    /// ```
+    pub fn answer_format<F>(mut self, answer_format: F) -> Self
+    where
+        F: FnOnce(&ListItem) -> String + 'a,
+    {
+        self.raw_select.answer_format = Format::Sync(Box::new(answer_format));
+        self
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -309,6 +510,35 @@ impl<'a> RawSelectBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .transform_with_layout(|choice, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         write!(backend, "{:>1$}", choice.text, layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
+    ListItem; raw_select, reset transform_with_choices
+    }
+
+    crate::impl_transform_with_choices_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .transform_with_choices(|choice, choices, previous_answers, backend| {
+    ///         write!(backend, "{} out of {}", choice.text, choices.join(", "))
+    ///     })
+    ///     .build();
+    /// ```
     ListItem; raw_select
     }
 
@@ -316,6 +546,20 @@ impl<'a> RawSelectBuilder<'a> {
     ///
     /// [`Question`]: crate::question::Question
     pub fn build(mut self) -> crate::question::Question<'a> {
+        if let Predicate::Sync(default_by) = self.default_by.take() {
+            let default = self
+                .raw_select
+                .choices
+                .choices
+                .iter()
+                .position(
+                    |choice| matches!(choice, Choice::Choice((_, text)) if default_by(&text.text)),
+                )
+                .expect("No `Choice` satisfies the `default_by` predicate");
+
+            self.raw_select.choices.set_default(default);
+        }
+
         let num_choices = self
             .raw_select
             .choices
@@ -328,7 +572,7 @@ impl<'a> RawSelectBuilder<'a> {
             })
             .unwrap_or(0);
 
-        self.raw_select.max_index_width = (num_choices as f64).log10() as u16 + 1;
+        self.raw_select.max_index_width = crate::question::number_of_digits(num_choices);
 
         crate::question::Question::new(
             self.opts,
@@ -8,7 +8,7 @@ use ui::{
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Transform};
+use super::{Choice, Format, Transform, TransformWithChoices};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::RawSelectBuilder;
@@ -23,7 +23,25 @@ mod tests;
 pub(super) struct RawSelect<'a> {
     choices: super::ChoiceList<(usize, Text<String>)>,
     transform: Transform<'a, ListItem>,
+    transform_with_choices: TransformWithChoices<'a, ListItem>,
+    answer_format: Format<'a, ListItem>,
     max_index_width: u16,
+    /// Hide the answer line, so that the choices can only be navigated with the arrow keys.
+    hide_number_input: bool,
+}
+
+impl RawSelect<'_> {
+    /// Returns a copy of the raw select, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            choices: self.choices.clone(),
+            transform: self.transform.try_clone()?,
+            transform_with_choices: self.transform_with_choices.try_clone()?,
+            answer_format: self.answer_format.try_clone()?,
+            max_index_width: self.max_index_width,
+            hide_number_input: self.hide_number_input,
+        })
+    }
 }
 
 struct RawSelectPrompt<'a> {
@@ -33,6 +51,13 @@ struct RawSelectPrompt<'a> {
 }
 
 impl RawSelectPrompt<'_> {
+    /// Fits the page size to the terminal height, leaving space for the prompt and the answer line.
+    fn fit_page_size(&mut self, layout: ui::layout::Layout) {
+        let reserved =
+            self.prompt.height(&mut layout.clone()) + !self.select.list.hide_number_input as u16;
+        self.select.list.choices.fit_page_size(layout, reserved);
+    }
+
     fn finish_index(self, index: usize) -> ListItem {
         ListItem {
             index,
@@ -71,14 +96,26 @@ const ANSWER_PROMPT: &[u8] = b"  Answer: ";
 
 impl Widget for RawSelectPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.fit_page_size(*layout);
         self.prompt.render(layout, b)?;
         self.select.render(layout, b)?;
+
+        if self.select.list.hide_number_input {
+            return Ok(());
+        }
+
         b.write_all(ANSWER_PROMPT)?;
         layout.line_offset += ANSWER_PROMPT.len() as u16;
         self.input.render(layout, b)
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.fit_page_size(*layout);
+
+        if self.select.list.hide_number_input {
+            return self.prompt.height(layout) + self.select.height(layout) - 1;
+        }
+
         // We don't need to add 1 for the answer prompt because this will over count by one
         let height = self.prompt.height(layout) + self.select.height(layout);
         layout.line_offset = ANSWER_PROMPT.len() as u16;
@@ -86,6 +123,10 @@ impl Widget for RawSelectPrompt<'_> {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.select.list.hide_number_input {
+            return self.select.handle_key(key);
+        }
+
         if self.input.handle_key(key) {
             if let Ok(n) = self.input.value().parse::<usize>() {
                 if n <= self.select.list.len() && n > 0 {
@@ -113,6 +154,10 @@ impl Widget for RawSelectPrompt<'_> {
     }
 
     fn cursor_pos(&mut self, mut layout: ui::layout::Layout) -> (u16, u16) {
+        if self.select.list.hide_number_input {
+            return self.select.cursor_pos(layout);
+        }
+
         let w = self
             .input
             .cursor_pos(layout.with_line_offset(ANSWER_PROMPT.len() as u16))
@@ -131,6 +176,8 @@ impl widgets::List for RawSelect<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
+        let gutter_width = self.choices.gutter_width();
+
         match &mut self.choices[index] {
             &mut Choice::Choice((index, ref mut text)) => {
                 if hovered {
@@ -139,12 +186,14 @@ impl widgets::List for RawSelect<'_> {
 
                 write!(
                     b,
-                    "  {:>width$}. ",
+                    "{:gutter$}{:>width$}. ",
+                    "",
                     index,
+                    gutter = gutter_width as usize,
                     width = self.max_index_width as usize
                 )?;
 
-                layout.offset_x += self.max_index_width + 4;
+                layout.offset_x += self.max_index_width + gutter_width + 2;
                 text.render(&mut layout, b)?;
 
                 if hovered {
@@ -153,8 +202,8 @@ impl widgets::List for RawSelect<'_> {
             }
             separator => {
                 b.set_fg(Color::DarkGrey)?;
-                b.write_all(b"  ")?;
-                super::get_sep_str(separator).render(&mut layout.with_line_offset(2), b)?;
+                write!(b, "{:1$}", "", gutter_width as usize)?;
+                super::render_separator(separator, &mut layout.with_line_offset(gutter_width), b)?;
                 b.set_fg(Color::Reset)?;
             }
         }
@@ -167,9 +216,11 @@ impl widgets::List for RawSelect<'_> {
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
+        let gutter_width = self.choices.gutter_width();
+
         match self.choices[index] {
             Choice::Choice((_, ref mut c)) => {
-                layout.offset_x += self.max_index_width + 4;
+                layout.offset_x += self.max_index_width + gutter_width + 2;
                 c.height(&mut layout)
             }
             _ => 1,
@@ -187,9 +238,29 @@ impl widgets::List for RawSelect<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn scroll_margin(&self) -> usize {
+        self.choices.scroll_margin()
+    }
 }
 
 impl<'a> RawSelect<'a> {
+    pub(crate) fn answer_with(self, value: Answer) -> ui::Result<Answer> {
+        let index = value
+            .try_into_list_item()
+            .expect("`raw_select` questions are answered with a `ListItem`")
+            .index;
+
+        if index >= self.choices.len() || self.choices[index].is_separator() {
+            return Err(super::invalid_answer("Please enter a valid choice"));
+        }
+
+        let mut prompt = self.into_prompt("");
+        prompt.select.set_at(index);
+
+        super::submit(prompt).map(Answer::ListItem)
+    }
+
     fn into_prompt(self, message: &'a str) -> RawSelectPrompt<'a> {
         let mut select = widgets::Select::new(self);
 
@@ -213,23 +284,53 @@ impl<'a> RawSelect<'a> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
-        let transform = self.transform.take();
-
-        let ans = ui::Input::new(self.into_prompt(&message), b)
-            .on_esc(on_esc)
-            .run(events)?;
-
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
-                .lines()
-                .next()
-                .expect("There must be at least one line in a `str`")
-                .cyan()
-        )?)
+        let transform =
+            self.transform_with_choices
+                .take()
+                .into_transform(self.transform.take(), || {
+                    self.choices
+                        .choices
+                        .iter()
+                        .filter_map(|choice| match choice {
+                            Choice::Choice((_, text)) => Some(text.text.clone()),
+                            _ => None,
+                        })
+                        .collect()
+                });
+        let answer_format = self.answer_format.take();
+        let hide_number_input = self.hide_number_input;
+
+        let mut input = ui::Input::new(self.into_prompt(&message), b)
+            .on_esc(exit.on_esc)
+            .confirm_on_interrupt(exit.interrupt_confirm)
+            .submit_on_tab(exit.submit_on_tab)
+            .back_on_back_tab(exit.back_on_back_tab)
+            .ensure_newline(exit.ensure_newline);
+
+        // There is no answer line to put the cursor on
+        if hide_number_input {
+            input = input.hide_cursor();
+        }
+
+        let ans = input.run(events)?;
+
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |ans| {
+            match answer_format {
+                Format::Sync(answer_format) => b.write_styled(&answer_format(ans).cyan())?,
+                Format::None => b.write_styled(
+                    &ans.text
+                        .lines()
+                        .next()
+                        .expect("There must be at least one line in a `str`")
+                        .cyan(),
+                )?,
+            }
+        })
     }
 }
@@ -96,6 +96,46 @@ fn test_height() {
     }
 }
 
+#[test]
+fn test_page_size_auto() {
+    let size = (50, 10).into();
+    let base_layout = Layout::new(0, size);
+
+    let mut raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .choices(choices(20))
+            .page_size_auto(),
+    )
+    .into_prompt("message");
+
+    // The prompt line and the answer line are left along with the page
+    let mut layout = base_layout;
+    assert_eq!(raw_select.height(&mut layout), 10);
+    assert_eq!(layout, base_layout.with_offset(0, 9).with_line_offset(10));
+}
+
+#[test]
+fn test_hide_number_input() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+
+    let mut raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .choices(choices(10))
+            .show_number_input(false),
+    )
+    .into_prompt("message");
+
+    // There is no answer line after the choices
+    let mut layout = base_layout;
+    assert_eq!(raw_select.height(&mut layout), 11);
+    assert_eq!(layout, base_layout.with_offset(0, 11));
+
+    assert!(!raw_select.handle_key(KeyCode::Char('6').into()));
+    assert!(raw_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(raw_select.select.get_at(), 3);
+}
+
 #[test]
 fn test_cursor_pos() {
     let size = (50, 20).into();
@@ -126,3 +166,66 @@ fn test_cursor_pos() {
         assert_eq!(select.cursor_pos(layout), (line_offset, 12));
     }
 }
+
+#[test]
+fn test_max_index_width() {
+    for &(len, width) in [(1, 1), (9, 1), (10, 2), (99, 2), (100, 3), (101, 3)].iter() {
+        let raw_select = unwrap_select(
+            RawSelectBuilder::new("name".into()).choices((0..len).map(|i| i.to_string())),
+        );
+        assert_eq!(raw_select.max_index_width, width, "{} choices", len);
+    }
+
+    // separators are not numbered
+    let raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .choices((0..9).map(|i| i.to_string()))
+            .default_separator(),
+    );
+    assert_eq!(raw_select.max_index_width, 1);
+}
+
+fn default_by_choices() -> Vec<Choice<String>> {
+    vec![
+        Choice::Separator("Pizza".into()),
+        "Pasta".to_owned().into(),
+        Choice::DefaultSeparator,
+        "Pizza".to_owned().into(),
+        "Salad".to_owned().into(),
+    ]
+}
+
+#[test]
+fn test_default_by() {
+    let raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .choices(default_by_choices())
+            .default_by(|choice| choice == "Pizza"),
+    );
+    assert_eq!(raw_select.choices.default(), Some(3));
+
+    // default_by is resolved on build, so choices can be added later
+    let raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .default_by(|choice| choice.starts_with('S'))
+            .choices(default_by_choices()),
+    );
+    assert_eq!(raw_select.choices.default(), Some(4));
+
+    let raw_select = unwrap_select(
+        RawSelectBuilder::new("name".into())
+            .choices(default_by_choices())
+            .default_by(|choice| choice == "Pizza")
+            .default(1),
+    );
+    assert_eq!(raw_select.choices.default(), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "No `Choice` satisfies the `default_by` predicate")]
+fn test_default_by_no_match() {
+    RawSelectBuilder::new("name".into())
+        .choices(default_by_choices())
+        .default_by(|choice| choice == "Soup")
+        .build();
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+
+use ui::{backend::Backend, events::EventIterator};
+
+use super::{ExitOptions, Options, Predicate, Question, QuestionKind};
+use crate::{Answer, Answers};
+
+pub(super) struct Repeat<'a> {
+    question: Box<dyn Fn() -> Question<'a> + 'a>,
+    stop_when: Predicate<'a, Answer>,
+}
+
+impl fmt::Debug for Repeat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Repeat")
+            .field("question", &"Fn() -> Question")
+            .field("stop_when", &self.stop_when)
+            .finish()
+    }
+}
+
+impl<'a> Repeat<'a> {
+    /// Builds the question to ask next, with the options of the repeat question applied to it.
+    fn next_question(
+        &self,
+        message: &Option<String>,
+        exit: &ExitOptions,
+        silent: bool,
+    ) -> Question<'a> {
+        let mut question = (self.question)();
+
+        // Whether the repeat question should be asked has already been checked
+        question.opts.ask_if_answered = true;
+
+        if let Some(ref message) = message {
+            question.opts.message = Some(message.clone().into());
+        }
+        if let Some(ref interrupt_confirm) = exit.interrupt_confirm {
+            question.opts.interrupt_confirm = Some(interrupt_confirm.clone());
+        }
+        question.opts.submit_on_tab |= exit.submit_on_tab;
+        question.opts.back_on_back_tab |= exit.back_on_back_tab;
+        question.opts.silent |= silent;
+        question.opts.ensure_newline &= exit.ensure_newline;
+
+        question
+    }
+
+    fn should_stop(&self, answer: &Answer) -> bool {
+        match self.stop_when {
+            Predicate::Sync(ref stop_when) => stop_when(answer),
+            Predicate::None => match answer {
+                Answer::String(s) => s.is_empty(),
+                Answer::ListItems(items) => items.is_empty(),
+                _ => false,
+            },
+        }
+    }
+
+    pub(crate) fn ask<B: Backend, E: EventIterator>(
+        self,
+        message: Option<String>,
+        exit: ExitOptions,
+        silent: bool,
+        answers: &Answers,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let mut items = Vec::new();
+
+        loop {
+            let question = self.next_question(&message, &exit, silent);
+
+            let answer = match question.ask(answers, b, events)? {
+                Some((_, answer)) => answer,
+                // Nothing was collected, so the whole question is skipped
+                None if items.is_empty() => return Ok(None),
+                None => break,
+            };
+
+            if self.should_stop(&answer) {
+                break;
+            }
+
+            items.push(answer);
+        }
+
+        Ok(Some(Answer::List(items)))
+    }
+
+    pub(crate) fn answer_with(self, value: Answer, answers: &Answers) -> ui::Result<Answer> {
+        let values = value
+            .try_into_list()
+            .expect("repeat questions can only be answered with an `Answer::List`");
+
+        let mut items = Vec::with_capacity(values.len());
+
+        for value in values {
+            if let Some(item) = (self.question)().answer_with(value, answers)? {
+                items.push(item);
+            }
+        }
+
+        Ok(Answer::List(items))
+    }
+}
+
+/// The builder for a [`repeat`] question.
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// let tags = Question::repeat(|| Question::input("tags").message("Add a tag").build())
+///     .build();
+/// ```
+///
+/// [`repeat`]: crate::question::Question::repeat
+#[derive(Debug)]
+pub struct RepeatBuilder<'a> {
+    opts: Options<'a>,
+    repeat: Repeat<'a>,
+}
+
+impl<'a> RepeatBuilder<'a> {
+    pub(crate) fn new<F>(question: F) -> Self
+    where
+        F: Fn() -> Question<'a> + 'a,
+    {
+        let (name, secret) = {
+            let question = question();
+            (question.name().to_owned(), question.is_secret())
+        };
+
+        let mut opts = Options::new(name);
+        opts.secret = secret;
+
+        RepeatBuilder {
+            opts,
+            repeat: Repeat {
+                question: Box::new(question),
+                stop_when: Predicate::None,
+            },
+        }
+    }
+
+    crate::impl_options_builder! {
+    message
+    /// If it is given, it replaces the message of every question asked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").build())
+    ///     .message("Add a tag")
+    ///     .build();
+    /// ```
+
+    when
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").build())
+    ///     .when(|previous_answers: &Answers| match previous_answers.get("add_tags") {
+    ///         Some(ans) => ans.as_bool().unwrap(),
+    ///         None => true,
+    ///     })
+    ///     .build();
+    /// ```
+
+    ask_if_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").build())
+    ///     .ask_if_answered(true)
+    ///     .build();
+    /// ```
+
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").build())
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").build())
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").build())
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
+    }
+
+    /// Stop asking once an answer satisfies the given predicate. The answer which stops the
+    /// question is not collected.
+    ///
+    /// If `stop_when` is not set, it stops on an empty answer, that is an [`Answer::String`] with
+    /// no text, or an [`Answer::ListItems`] with no items.
+    ///
+    /// [`Answer::String`]: crate::Answer::String
+    /// [`Answer::ListItems`]: crate::Answer::ListItems
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let tags = Question::repeat(|| Question::input("tags").message("Add a tag").build())
+    ///     .stop_when(|tag| tag.as_string() == Some("done"))
+    ///     .build();
+    /// ```
+    pub fn stop_when<F>(mut self, stop_when: F) -> Self
+    where
+        F: Fn(&Answer) -> bool + 'a,
+    {
+        self.repeat.stop_when = Predicate::Sync(Box::new(stop_when));
+        self
+    }
+
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    pub fn build(self) -> Question<'a> {
+        Question::new(self.opts, QuestionKind::Repeat(self.repeat))
+    }
+}
+
+impl<'a> From<RepeatBuilder<'a>> for Question<'a> {
+    /// Consumes the builder returning a [`Question`]
+    ///
+    /// [`Question`]: crate::question::Question
+    fn from(builder: RepeatBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
@@ -1,8 +1,8 @@
 use ui::{backend::Backend, widgets::Text};
 
-use super::Select;
+use super::{Group, Orientation, Select};
 use crate::{
-    question::{Choice, Options},
+    question::{Choice, Compare, Format, FormatByVal, Options, PointerPosition, Predicate},
     ListItem,
 };
 
@@ -41,6 +41,10 @@ use crate::{
 pub struct SelectBuilder<'a> {
     opts: Options<'a>,
     select: Select<'a>,
+    default_by: Predicate<'a, str>,
+    sort_by: Compare<'a, str>,
+    none: Option<String>,
+    other: Option<String>,
 }
 
 impl<'a> SelectBuilder<'a> {
@@ -48,6 +52,10 @@ impl<'a> SelectBuilder<'a> {
         SelectBuilder {
             opts: Options::new(name),
             select: Default::default(),
+            default_by: Predicate::None,
+            sort_by: Compare::None,
+            none: None,
+            other: None,
         }
     }
 
@@ -88,6 +96,17 @@ impl<'a> SelectBuilder<'a> {
     ///     .build();
     /// ```
 
+    on_answered
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let select = Question::select("theme")
+    ///     .on_answered(|name, answer| println!("{}: {:?}", name, answer))
+    ///     .build();
+    /// ```
+
     on_esc
     /// # Examples
     ///
@@ -98,6 +117,39 @@ impl<'a> SelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    silent
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .silent(true)
+    ///     .build();
+    /// ```
+
+    secret
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .secret(true)
+    ///     .build();
+    /// ```
+
+    ensure_newline
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .ensure_newline(false)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default index for the select
@@ -132,6 +184,55 @@ impl<'a> SelectBuilder<'a> {
     /// ```
     pub fn default(mut self, default: usize) -> Self {
         self.select.choices.set_default(default);
+        self.default_by = Predicate::None;
+        self
+    }
+
+    /// Set the default to the first [`Choice`] whose text satisfies the given predicate
+    ///
+    /// The predicate is only checked against [`Choice`]s, separators are skipped. It is resolved
+    /// to an index on [`build`], so it does not matter whether the choices are added before or
+    /// after calling this. If both `default` and `default_by` are called, the last one wins.
+    ///
+    /// # Panics
+    ///
+    /// If no [`Choice`] satisfies the predicate, it will cause a panic on [`build`]
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`build`]: Self::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, DefaultSeparator};
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec![
+    ///         "Order a pizza".into(),
+    ///         "Make a reservation".into(),
+    ///         DefaultSeparator,
+    ///         "Ask for opening hours".into(),
+    ///         "Contact support".into(),
+    ///         "Talk to the receptionist".into(),
+    ///     ])
+    ///     .default_by(|choice| choice.starts_with("Contact"))
+    ///     .build();
+    /// ```
+    pub fn default_by<F>(mut self, default_by: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'a,
+    {
+        self.default_by = Predicate::Sync(Box::new(default_by));
+        self
+    }
+
+    /// Remove any default set with [`default`] or [`default_by`].
+    ///
+    /// [`default`]: Self::default
+    /// [`default_by`]: Self::default_by
+    pub(super) fn clear_default(mut self) -> Self {
+        self.select.choices.clear_default();
+        self.default_by = Predicate::None;
         self
     }
 
@@ -161,6 +262,27 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Fit the page size to the height of the terminal
+    ///
+    /// The page size is recomputed whenever the prompt is rendered, so it adapts if the terminal
+    /// is resized. It leaves space for the rest of the prompt, and is still a minimum of 5.
+    ///
+    /// This is overridden by [`page_size`](Self::page_size) if it is called after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .page_size_auto()
+    ///     .build();
+    /// ```
+    pub fn page_size_auto(mut self) -> Self {
+        self.select.choices.set_page_size_auto();
+        self
+    }
+
     /// Whether to wrap around when user gets to the last element.
     ///
     /// If `should_loop` is not set, it will default to `true`.
@@ -179,6 +301,137 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// The minimum number of elements to keep visible above and below the hovered element when
+    /// the list is scrolled.
+    ///
+    /// The margin is kept where possible. It is capped based on the [`page_size`], and cannot be
+    /// kept near the ends of the list if [`should_loop`] is `false`. If `scroll_margin` is not
+    /// set, it will default to 1.
+    ///
+    /// [`page_size`]: Self::page_size
+    /// [`should_loop`]: Self::should_loop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .scroll_margin(3)
+    ///     .build();
+    /// ```
+    pub fn scroll_margin(mut self, scroll_margin: usize) -> Self {
+        self.select.choices.set_scroll_margin(scroll_margin);
+        self
+    }
+
+    /// Pressing a digit from 1 to 9 moves to the choice with that number
+    ///
+    /// The choices are numbered from 1 in the order they are shown, skipping the separators.
+    /// Unlike [`Question::raw_select`], the numbers are not shown, and there is no answer field,
+    /// so the choice still has to be submitted with `Enter`. Digits without a choice are ignored.
+    ///
+    /// If `number_shortcuts` is not set, it will default to `false`.
+    ///
+    /// [`Question::raw_select`]: crate::question::Question::raw_select
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .number_shortcuts(true)
+    ///     .build();
+    /// ```
+    pub fn number_shortcuts(mut self, number_shortcuts: bool) -> Self {
+        self.select.number_shortcuts = number_shortcuts;
+        self
+    }
+
+    /// The width of the gutter on the left of every element in the list, which is where the
+    /// pointer to the hovered element is shown.
+    ///
+    /// It can be 0, 1 or 2. If it is 0, the pointer is not shown, and if it is 1, the pointer is
+    /// not followed by a space. If `gutter_width` is not set, it will default to 2.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `gutter_width` is more than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .gutter_width(0)
+    ///     .build();
+    /// ```
+    pub fn gutter_width(mut self, gutter_width: u16) -> Self {
+        self.select.choices.set_gutter_width(gutter_width);
+        self
+    }
+
+    /// Where the pointer to the hovered choice is shown
+    ///
+    /// With [`PointerPosition::Right`], the pointer is right aligned to the edge of the terminal,
+    /// and the gutter on the left is left empty. With [`PointerPosition::Both`], the pointer is
+    /// shown on both sides, bracketing the hovered choice. The pointer on the right takes up
+    /// [`gutter_width`] columns on the right, so the choices wrap before it. The pointer is not
+    /// shown in the [horizontal] and [grid] orientations.
+    ///
+    /// If `pointer_position` is not set, it will default to [`PointerPosition::Left`].
+    ///
+    /// [`gutter_width`]: Self::gutter_width
+    /// [horizontal]: Orientation::Horizontal
+    /// [grid]: Orientation::Grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::PointerPosition, Question};
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .pointer_position(PointerPosition::Both)
+    ///     .build();
+    /// ```
+    pub fn pointer_position(mut self, pointer_position: PointerPosition) -> Self {
+        self.select.choices.set_pointer_position(pointer_position);
+        self
+    }
+
+    /// The direction in which the choices are laid out
+    ///
+    /// With [`Orientation::Horizontal`], the choices are shown next to each other as radio buttons,
+    /// and wrap to the next line if they do not fit in the terminal width. This is best suited for
+    /// a few short choices. With [`Orientation::Grid`], the choices are shown as radio buttons in
+    /// as many equal width columns as fit in the terminal width, which is best suited for many
+    /// short choices. In both, the hovered choice can be changed with the arrow keys, only the
+    /// first line of each choice is shown, and separators are not shown.
+    ///
+    /// In the grid orientation, the page size is the number of rows shown at once. The page size
+    /// options do not apply to the horizontal orientation.
+    ///
+    /// If `orientation` is not set, it will default to [`Orientation::Vertical`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::Orientation, Question};
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .orientation(Orientation::Horizontal)
+    ///     .build();
+    /// ```
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.select.orientation = orientation;
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`select`] for more information.
@@ -203,6 +456,69 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with the given text, and an icon shown before it.
+    ///
+    /// The icons are shown in a column between the pointer and the text of the choices. The column
+    /// is as wide as the widest icon, so the text of the choices stays aligned even if the icons
+    /// have different widths, such as emojis which take up two columns. Choices without an icon
+    /// have a blank space in its place.
+    ///
+    /// Icons are not shown in the [horizontal] and [grid] orientations.
+    ///
+    /// See [`select`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`select`]: crate::question::Question::select
+    /// [horizontal]: Orientation::Horizontal
+    /// [grid]: Orientation::Grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choice_with_icon("🍕", "Order a Pizza")
+    ///     .choice_with_icon("☎", "Contact support")
+    ///     .build();
+    /// ```
+    pub fn choice_with_icon<S: Into<String>, I: Into<String>>(mut self, icon: S, text: I) -> Self {
+        let index = self.select.choices.len();
+        self.select.set_icon(index, icon.into());
+        self.choice(text)
+    }
+
+    /// Extends the given iterator of icons and texts as [`Choice`]s with an icon shown before them
+    ///
+    /// See [`choice_with_icon`] for more information.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`choice_with_icon`]: Self::choice_with_icon
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices_with_icons(vec![
+    ///         ("🍕", "Order a pizza"),
+    ///         ("📅", "Make a reservation"),
+    ///         ("☎", "Contact support"),
+    ///     ])
+    ///     .build();
+    /// ```
+    pub fn choices_with_icons<I, S, T>(self, choices: I) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+        I: IntoIterator<Item = (S, T)>,
+    {
+        choices
+            .into_iter()
+            .fold(self, |this, (icon, text)| this.choice_with_icon(icon, text))
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`select`] for more information.
@@ -284,6 +600,251 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Inserts a group of [`Choice`]s shown below a header with the given label
+    ///
+    /// The group can be collapsed so that only its header is shown, which keeps long lists of
+    /// choices short. The header is hovered like a choice, but cannot be selected. Instead,
+    /// `Enter` or `Space` on the header expands or collapses the group, and the right and left
+    /// arrow keys expand and collapse it. The left arrow key on one of the choices of the group
+    /// moves back to its header. If `expanded` is false, the group starts collapsed, unless the
+    /// [`default`] is in the group.
+    ///
+    /// The choices of the group are indented below the header. Their indices, and so those of the
+    /// answer, are counted as if the header was a [`Separator`] followed by the choices, whether
+    /// the group is collapsed or not. In the [horizontal] and [grid] orientations, the headers are
+    /// not shown and the groups are always expanded.
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`Separator`]: crate::question::Choice::Separator
+    /// [`default`]: Self::default
+    /// [horizontal]: Orientation::Horizontal
+    /// [grid]: Orientation::Grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choice("Default")
+    ///     .group("Dark themes", vec!["Dracula", "Monokai", "Nord"], false)
+    ///     .group("Light themes", vec!["Solarized Light", "GitHub Light"], false)
+    ///     .build();
+    /// ```
+    pub fn group<L, I, T>(mut self, label: L, choices: I, expanded: bool) -> Self
+    where
+        L: Into<String>,
+        T: Into<Choice<String>>,
+        I: IntoIterator<Item = T>,
+    {
+        let header = self.select.choices.len();
+        self = self.separator(label).choices(choices);
+
+        self.select.groups.push(Group {
+            header,
+            len: self.select.choices.len() - header - 1,
+            expanded,
+        });
+        self
+    }
+
+    /// Adds a "none of the above" [`Choice`] with the given text
+    ///
+    /// The choice is always shown last, after all the other choices. If it is selected, the answer
+    /// is a [`ListItem`] whose index is [`ListItem::NONE_INDEX`], which can be checked with
+    /// [`ListItem::is_none`]. Its text is the given text.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .with_none("None of the above")
+    ///     .build();
+    /// ```
+    pub fn with_none<I: Into<String>>(mut self, text: I) -> Self {
+        self.none = Some(text.into());
+        self
+    }
+
+    /// Adds an "other" [`Choice`] with the given text, which asks for a custom answer
+    ///
+    /// The choice is shown after all the other choices, but before the one added by
+    /// [`with_none`]. If it is selected, the choices are replaced by a text input where the
+    /// custom answer is typed, and `Esc` goes back to the choices. The answer is then a
+    /// [`ListItem`] whose index is [`ListItem::OTHER_INDEX`], which can be checked with
+    /// [`ListItem::is_other`]. Its text is the typed answer, which cannot be empty.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`with_none`]: Self::with_none
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .with_other("Other (please specify)")
+    ///     .build();
+    /// ```
+    pub fn with_other<I: Into<String>>(mut self, text: I) -> Self {
+        self.other = Some(text.into());
+        self
+    }
+
+    /// Show the given message if there are no [`Choice`]s to select
+    ///
+    /// If the select only has separators, or no choices at all, the message is shown below the
+    /// question instead of the choices. The question can then only be skipped with `Esc`, which
+    /// is handled according to [`on_esc`], except that [`OnEsc::Ignore`] skips the question
+    /// instead. If this is not set, asking a question without any choices to select returns an
+    /// [`IoError`].
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`on_esc`]: Self::on_esc
+    /// [`OnEsc::Ignore`]: crate::OnEsc::Ignore
+    /// [`IoError`]: crate::ErrorKind::IoError
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// # fn get_themes() -> Vec<String> { Vec::new() }
+    /// let select = Question::select("theme")
+    ///     .choices(get_themes())
+    ///     .empty_message("No themes are installed")
+    ///     .build();
+    /// ```
+    pub fn empty_message<I: Into<String>>(mut self, empty_message: I) -> Self {
+        self.select.empty_message = Some(empty_message.into());
+        self
+    }
+
+    /// Sort the [`Choice`]s alphabetically by their text when the question is built.
+    ///
+    /// See [`sorted_by`] for how the choices are sorted.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`sorted_by`]: Self::sorted_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Solarized", "Dark", "Light"])
+    ///     .sorted()
+    ///     .build();
+    /// ```
+    pub fn sorted(self) -> Self {
+        self.sorted_by(|a, b| a.cmp(b))
+    }
+
+    /// Sort the [`Choice`]s by their text with the given comparison when the question is built.
+    ///
+    /// Separators stay in place, and the choices are only sorted within each group between two
+    /// separators, or within each [`group`]. The choices added by [`with_other`] and
+    /// [`with_none`] are not sorted, and stay last.
+    ///
+    /// The [`default`] is the index of the choice before it is sorted, and the index of the
+    /// answer is that of the choice after it is sorted.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`group`]: Self::group
+    /// [`with_other`]: Self::with_other
+    /// [`with_none`]: Self::with_none
+    /// [`default`]: Self::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Solarized", "dark", "Light"])
+    ///     .sorted_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()))
+    ///     .build();
+    /// ```
+    pub fn sorted_by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&str, &str) -> std::cmp::Ordering + 'a,
+    {
+        self.sort_by = Compare::Sync(Box::new(compare));
+        self
+    }
+
+    /// Function to change how the answer is shown after the user selects a [`Choice`].
+    ///
+    /// It is a [`FnOnce`] that is given the selected [`ListItem`], and returns the text to show in
+    /// place of the selected choice. The text is shown in cyan, like the default.
+    ///
+    /// This is a lighter alternative to [`transform`] for when only the text needs to change. If
+    /// both are given, [`transform`] takes precedence and the `answer_format` is ignored.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`transform`]: Self::transform
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .answer_format(|choice| format!("You chose: {} (id {})", choice.text, choice.index))
+    ///     .build();
+    /// ```
+    pub fn answer_format<F>(mut self, answer_format: F) -> Self
+    where
+        F: FnOnce(&ListItem) -> String + 'a,
+    {
+        self.select.answer_format = Format::Sync(Box::new(answer_format));
+        self
+    }
+
+    /// Function to change the hint shown below the choices when they do not all fit in the page.
+    ///
+    /// It is given the number of choices which are not visible, and returns the text to show in
+    /// place of the default `(Move up and down to reveal more choices)`. This is useful to
+    /// localize the hint. The hint takes a single line, and is cut short if it is too wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .scroll_hint_format(|hidden| format!("(Weitere {})", hidden))
+    ///     .build();
+    /// ```
+    pub fn scroll_hint_format<F>(mut self, scroll_hint_format: F) -> Self
+    where
+        F: Fn(usize) -> String + 'a,
+    {
+        self.select.scroll_hint_format = FormatByVal::Sync(Box::new(scroll_hint_format));
+        self
+    }
+
+    crate::impl_hide_cursor_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .hide_cursor(false)
+    ///     .build();
+    /// ```
+    select
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -296,13 +857,92 @@ impl<'a> SelectBuilder<'a> {
     ///     })
     ///     .build();
     /// ```
+    with_layout
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .transform_with_layout(|choice, previous_answers, layout, backend| {
+    ///         // Align the answer to the right of the terminal
+    ///         write!(backend, "{:>1$}", choice.text, layout.line_width() as usize)
+    ///     })
+    ///     .build();
+    /// ```
+    ListItem; select, reset transform_with_choices
+    }
+
+    crate::impl_transform_with_choices_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .choices(vec!["Dark", "Light"])
+    ///     .transform_with_choices(|choice, choices, previous_answers, backend| {
+    ///         write!(backend, "{} out of {}", choice.text, choices.join(", "))
+    ///     })
+    ///     .build();
+    /// ```
     ListItem; select
     }
 
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
-    pub fn build(self) -> crate::question::Question<'a> {
+    pub fn build(mut self) -> crate::question::Question<'a> {
+        if let Compare::Sync(compare) = self.sort_by.take() {
+            // The choices after a group are not sorted along with its choices
+            let breaks: Vec<_> = self
+                .select
+                .groups
+                .iter()
+                .map(|group| group.header + group.len + 1)
+                .collect();
+            let order = self
+                .select
+                .choices
+                .sort_by(&breaks, |a, b| compare(&a.text, &b.text));
+
+            if !self.select.icons.is_empty() {
+                let icons = std::mem::take(&mut self.select.icons);
+                self.select.icons = order
+                    .iter()
+                    .map(|&i| icons.get(i).cloned().flatten())
+                    .collect();
+            }
+        }
+
+        if let Some(other) = self.other.take() {
+            self.select.other_index = Some(self.select.choices.len());
+            self.select
+                .choices
+                .choices
+                .push(Choice::Choice(Text::new(other)));
+        }
+
+        if let Some(none) = self.none.take() {
+            self.select.none_index = Some(self.select.choices.len());
+            self.select
+                .choices
+                .choices
+                .push(Choice::Choice(Text::new(none)));
+        }
+
+        if let Predicate::Sync(default_by) = self.default_by.take() {
+            let default = self
+                .select
+                .choices
+                .choices
+                .iter()
+                .position(|choice| matches!(choice, Choice::Choice(text) if default_by(&text.text)))
+                .expect("No `Choice` satisfies the `default_by` predicate");
+
+            self.select.choices.set_default(default);
+        }
+
         if let Some(default) = self.select.choices.default() {
             if self.select.choices[default].is_separator() {
                 panic!("Invalid default '{}' is not a `Choice`", default);
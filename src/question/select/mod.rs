@@ -2,40 +2,219 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
-    style::Stylize,
+    events::{EventIterator, KeyCode, KeyEvent},
+    layout::Layout,
+    style::{Color, Stylize},
     widgets::{self, Text},
-    Prompt, Widget,
+    Prompt, Validation, Widget,
 };
 
-use super::Transform;
+use super::{
+    choice::{Orientation, Placement},
+    Choice, Format, FormatByVal, Transform, TransformWithChoices,
+};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::SelectBuilder;
+pub use typed::{AsChoices, TypedSelect, TypedSelectBuilder};
 
 mod builder;
+mod typed;
 
 #[cfg(test)]
 mod tests;
 
+/// The width of the `(•) ` before a choice in the horizontal and grid orientations.
+const RADIO_WIDTH: u16 = 4;
+
+/// The width of the `+ ` before the header of a group, by which its choices are also indented.
+const GROUP_INDENT: u16 = 2;
+
+/// Choices which are shown below a header, and can be collapsed so that only the header is shown.
+#[derive(Debug, Clone)]
+struct Group {
+    /// The index of the header, which is followed by the choices of the group.
+    header: usize,
+    /// The number of choices in the group.
+    len: usize,
+    expanded: bool,
+}
+
 #[derive(Debug, Default)]
 pub(super) struct Select<'a> {
     choices: super::ChoiceList<Text<String>>,
     transform: Transform<'a, ListItem>,
+    transform_with_choices: TransformWithChoices<'a, ListItem>,
+    answer_format: Format<'a, ListItem>,
+    /// Formats the hint shown while the list is scrollable, given the number of hidden choices.
+    scroll_hint_format: FormatByVal<'a, usize>,
+    /// The index of the choice added by `with_none`, if any.
+    none_index: Option<usize>,
+    /// The index of the choice added by `with_other`, if any.
+    other_index: Option<usize>,
+    orientation: Orientation,
+    /// The icon of each choice, if any. It is empty if none of the choices have an icon, and may
+    /// be shorter than the choices otherwise.
+    icons: Vec<Option<String>>,
+    /// The width of the icon column, or 0 if none of the choices have an icon.
+    icon_width: u16,
+    /// Whether pressing a digit from 1 to 9 moves to the choice with that number.
+    number_shortcuts: bool,
+    /// Whether the cursor is shown while the prompt is running.
+    show_cursor: bool,
+    /// The message shown instead of the choices if none of them can be selected.
+    empty_message: Option<String>,
+    groups: Vec<Group>,
+    /// The indices of the choices which are shown in the list, leaving out the choices of
+    /// collapsed groups. It is only used if there are groups.
+    rows: Vec<usize>,
+}
+
+impl Select<'_> {
+    /// Returns a copy of the select, or `None` if it has a function, which cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            choices: self.choices.clone(),
+            transform: self.transform.try_clone()?,
+            transform_with_choices: self.transform_with_choices.try_clone()?,
+            answer_format: self.answer_format.try_clone()?,
+            scroll_hint_format: self.scroll_hint_format.try_clone()?,
+            none_index: self.none_index,
+            other_index: self.other_index,
+            orientation: self.orientation,
+            icons: self.icons.clone(),
+            icon_width: self.icon_width,
+            number_shortcuts: self.number_shortcuts,
+            show_cursor: self.show_cursor,
+            empty_message: self.empty_message.clone(),
+            groups: self.groups.clone(),
+            rows: self.rows.clone(),
+        })
+    }
 }
 
 struct SelectPrompt<'a> {
     prompt: widgets::Prompt<&'a str>,
     select: widgets::Select<Select<'a>>,
+    /// The width available to the choices when they were last laid out. It is used to lay out the
+    /// choices to navigate them in the horizontal and grid orientations.
+    width: u16,
+    /// The custom answer being typed after the choice added by `with_other` is submitted.
+    other: Option<widgets::StringInput>,
 }
 
 impl SelectPrompt<'_> {
+    /// The index of the hovered choice.
+    fn at(&self) -> usize {
+        self.select.list.index(self.select.get_at())
+    }
+
+    /// Hovers the choice at `index`, expanding its group if it is collapsed.
+    fn set_at(&mut self, index: usize) {
+        if let Some(header) = self.select.list.group_of(index).map(|group| group.header) {
+            self.set_expanded(header, true);
+        }
+
+        let row = self.select.list.row(index);
+        self.select.set_at(row);
+    }
+
+    /// Expands or collapses the group with the header at `header`. Returns whether it changed.
+    fn set_expanded(&mut self, header: usize, expanded: bool) -> bool {
+        let list = &mut self.select.list;
+
+        match list.groups.iter_mut().find(|group| group.header == header) {
+            Some(group) if group.expanded != expanded => group.expanded = expanded,
+            _ => return false,
+        }
+
+        // The rows before the header do not change, so the header stays at the same row
+        let row = list.row(header);
+        list.update_rows();
+        self.select.update_list();
+        self.select.set_at(row);
+        true
+    }
+
+    /// Handles the keys which expand and collapse the groups in the vertical orientation.
+    fn handle_group_key(&mut self, key: KeyEvent) -> bool {
+        let at = self.at();
+
+        if self.select.list.is_header(at) {
+            let expanded = self.select.list.group_of_header(at).expanded;
+
+            return match key.code {
+                KeyCode::Char(' ') => self.set_expanded(at, !expanded),
+                KeyCode::Right => self.set_expanded(at, true),
+                KeyCode::Left => self.set_expanded(at, false),
+                _ => false,
+            };
+        }
+
+        match (key.code, self.select.list.group_of(at)) {
+            // Goes back to the header, so that the group can be collapsed
+            (KeyCode::Left, Some(group)) => {
+                let header = group.header;
+                self.set_at(header);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Fits the page size to the terminal height, leaving space for the prompt.
+    fn fit_page_size(&mut self, layout: ui::layout::Layout) {
+        let reserved = self.prompt.height(&mut layout.clone());
+        self.select.list.choices.fit_page_size(layout, reserved);
+    }
+
+    fn placement(&self) -> Placement {
+        Placement::new(
+            &self.select.list.choices,
+            self.select.list.orientation,
+            RADIO_WIDTH,
+            self.width,
+        )
+    }
+
+    fn render_placement<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.width = layout.available_width();
+
+        let symbol_set = ui::symbols::current();
+        let at = self.at();
+        self.placement().render(
+            at,
+            self.select.list.choices.page_size(),
+            layout,
+            b,
+            |_, hovered, b| {
+                if hovered {
+                    b.set_fg(Color::Cyan)?;
+                    write!(b, "({}) ", symbol_set.middle_dot)
+                } else {
+                    b.write_all(b"( ) ")
+                }
+            },
+        )
+    }
+
+    fn height_placement(&mut self, layout: &mut Layout) -> u16 {
+        self.width = layout.available_width();
+
+        self.placement()
+            .height(self.at(), self.select.list.choices.page_size(), layout)
+    }
+
     fn finish_index(self, index: usize) -> ListItem {
+        let mut select = self.select.into_inner();
+
         ListItem {
-            index,
-            text: self
-                .select
-                .into_inner()
+            index: if select.none_index == Some(index) {
+                ListItem::NONE_INDEX
+            } else {
+                index
+            },
+            text: select
                 .choices
                 .choices
                 .swap_remove(index)
@@ -49,52 +228,374 @@ impl Prompt for SelectPrompt<'_> {
     type ValidateErr = &'static str;
     type Output = ListItem;
 
-    fn finish(self) -> Self::Output {
-        let index = self.select.get_at();
-        self.finish_index(index)
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        match self.other {
+            Some(ref other) if other.value().is_empty() => Err("Please enter a value"),
+            Some(_) => Ok(Validation::Finish),
+            // Submitting the header of a group expands or collapses it instead
+            None if self.select.list.is_header(self.at()) => {
+                let at = self.at();
+                let expanded = self.select.list.group_of_header(at).expanded;
+                self.set_expanded(at, !expanded);
+                Ok(Validation::Continue)
+            }
+            None if self.select.list.other_index == Some(self.at()) => {
+                self.other = Some(widgets::StringInput::default());
+                Ok(Validation::Continue)
+            }
+            None => Ok(Validation::Finish),
+        }
+    }
+
+    fn shows_cursor(&self) -> bool {
+        // The cursor is needed to type the custom answer, even if it is hidden while choosing
+        self.other.is_some() || self.select.list.show_cursor
+    }
+
+    fn finish(mut self) -> Self::Output {
+        match self.other.take() {
+            Some(other) => ListItem {
+                index: ListItem::OTHER_INDEX,
+                text: other.finish(),
+            },
+            None => {
+                let index = self.at();
+                self.finish_index(index)
+            }
+        }
     }
 }
 
 impl Widget for SelectPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
-        self.prompt.render(layout, b)?;
-        self.select.render(layout, b)
+        if let Some(ref mut other) = self.other {
+            self.prompt.render(layout, b)?;
+            return other.render(layout, b);
+        }
+
+        match self.select.list.orientation {
+            Orientation::Vertical => {
+                self.fit_page_size(*layout);
+                self.prompt.render(layout, b)?;
+                self.select.render(layout, b)
+            }
+            Orientation::Horizontal | Orientation::Grid => {
+                self.fit_page_size(*layout);
+                self.prompt.render(layout, b)?;
+                self.render_placement(layout, b)
+            }
+        }
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.select.height(layout) - 1
+        if let Some(ref mut other) = self.other {
+            return self.prompt.height(layout) + other.height(layout) - 1;
+        }
+
+        match self.select.list.orientation {
+            Orientation::Vertical => {
+                self.fit_page_size(*layout);
+                self.prompt.height(layout) + self.select.height(layout) - 1
+            }
+            Orientation::Horizontal | Orientation::Grid => {
+                self.fit_page_size(*layout);
+                self.prompt.height(layout) + self.height_placement(layout) - 1
+            }
+        }
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-        self.select.cursor_pos(layout)
+        match self.other {
+            Some(ref mut other) => {
+                other.cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+            }
+            None => self.select.cursor_pos(layout),
+        }
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        self.select.handle_key(key)
+        if let Some(ref mut other) = self.other {
+            // `Esc` goes back to the choices, unless it is handled by `on_esc`
+            if key.code == KeyCode::Esc {
+                self.other = None;
+                return true;
+            }
+
+            return other.handle_key(key);
+        }
+
+        if self.select.list.number_shortcuts {
+            if let KeyCode::Char(c @ '1'..='9') = key.code {
+                return match self.select.list.nth_selectable(c as usize - '1' as usize) {
+                    Some(at) => {
+                        self.set_at(at);
+                        true
+                    }
+                    None => false,
+                };
+            }
+        }
+
+        match self.select.list.orientation {
+            Orientation::Vertical => self.handle_group_key(key) || self.select.handle_key(key),
+            Orientation::Horizontal | Orientation::Grid => {
+                let at = self.at();
+                let should_loop = self.select.list.choices.should_loop();
+
+                match self.placement().handle_key(at, key, should_loop) {
+                    Some(at) => {
+                        self.set_at(at);
+                        true
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+/// The prompt shown instead of the choices if none of them can be selected. It can only be left
+/// with `Esc`.
+struct EmptyPrompt<'a> {
+    prompt: widgets::Prompt<&'a str>,
+    message: Text<String>,
+}
+
+impl Widget for EmptyPrompt<'_> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, b)?;
+
+        // The prompt leaves the layout on its last line, so the message starts on the next one
+        layout.offset_y += 1;
+        layout.line_offset = 0;
+        b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+        b.set_fg(Color::DarkGrey)?;
+        self.message.render(layout, b)?;
+        b.set_fg(Color::Reset)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let height = self.prompt.height(layout);
+        layout.offset_y += 1;
+        layout.line_offset = 0;
+        height + self.message.height(layout)
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        self.prompt.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, _: KeyEvent) -> bool {
+        false
+    }
+}
+
+impl Prompt for EmptyPrompt<'_> {
+    type ValidateErr = &'static str;
+    type Output = ();
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        // There is nothing to submit
+        Ok(Validation::Continue)
+    }
+
+    fn finish(self) -> Self::Output {}
+}
+
+impl Select<'_> {
+    /// The index of the `n`th selectable choice in the list, counting from 0. The headers of the
+    /// groups and the choices of collapsed groups are not counted.
+    fn nth_selectable(&self, n: usize) -> Option<usize> {
+        (0..widgets::List::len(self))
+            .map(|row| self.index(row))
+            .filter(|&i| widgets::List::is_selectable(&self.choices, i))
+            .nth(n)
+    }
+
+    /// The index of the choice shown at `row` in the list.
+    fn index(&self, row: usize) -> usize {
+        if self.groups.is_empty() {
+            row
+        } else {
+            self.rows[row]
+        }
+    }
+
+    /// The row in the list at which the choice at `index` is shown. The choice must not be in a
+    /// collapsed group.
+    fn row(&self, index: usize) -> usize {
+        if self.groups.is_empty() {
+            index
+        } else {
+            self.rows
+                .iter()
+                .position(|&i| i == index)
+                .expect("the choice is not in a collapsed group")
+        }
+    }
+
+    /// The text of the choices, leaving out separators and the headers of groups.
+    fn choice_texts(&self) -> Vec<String> {
+        self.choices
+            .choices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, choice)| match choice {
+                Choice::Choice(text) if !self.is_header(i) => Some(text.text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn is_header(&self, index: usize) -> bool {
+        self.groups.iter().any(|group| group.header == index)
+    }
+
+    fn group_of_header(&self, header: usize) -> &Group {
+        self.groups
+            .iter()
+            .find(|group| group.header == header)
+            .expect("the index is the header of a group")
+    }
+
+    /// The group which the choice at `index` is in, if any.
+    fn group_of(&self, index: usize) -> Option<&Group> {
+        self.groups
+            .iter()
+            .find(|group| group.header < index && index <= group.header + group.len)
+    }
+
+    /// Renders the header of a group, after a `+` if it is collapsed or a `-` if it is expanded.
+    fn render_header<B: Backend>(
+        &mut self,
+        header: usize,
+        hovered: bool,
+        mut layout: Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        if hovered {
+            b.set_fg(Color::Cyan)?;
+        }
+
+        self.choices.write_left_gutter(hovered, b)?;
+
+        if self.group_of_header(header).expanded {
+            b.write_all(b"- ")?;
+        } else {
+            b.write_all(b"+ ")?;
+        }
+
+        let line = layout.offset_y;
+        layout.offset_x += self.choices.gutter_width() + GROUP_INDENT;
+        layout.width -= self.choices.right_gutter_width();
+        self.choices[header].render(&mut layout, b)?;
+
+        self.choices.write_right_gutter(hovered, layout, line, b)?;
+
+        b.set_fg(Color::Reset)
+    }
+
+    /// Computes the rows of the list, leaving out the choices of collapsed groups.
+    fn update_rows(&mut self) {
+        let mut rows = Vec::with_capacity(self.choices.len());
+        let mut index = 0;
+
+        while index < self.choices.len() {
+            rows.push(index);
+
+            index += match self.groups.iter().find(|group| group.header == index) {
+                Some(group) if !group.expanded => group.len + 1,
+                _ => 1,
+            };
+        }
+
+        self.rows = rows;
+    }
+
+    fn set_icon(&mut self, index: usize, icon: String) {
+        if self.icons.len() <= index {
+            self.icons.resize(index + 1, None);
+        }
+
+        self.icon_width = self
+            .icon_width
+            .max(ui::textwrap::core::display_width(&icon) as u16 + 1);
+        self.icons[index] = Some(icon);
     }
 }
 
 impl widgets::List for Select<'_> {
     fn render_item<B: Backend>(
         &mut self,
-        index: usize,
+        row: usize,
         hovered: bool,
-        layout: ui::layout::Layout,
-        backend: &mut B,
+        mut layout: ui::layout::Layout,
+        b: &mut B,
     ) -> io::Result<()> {
-        self.choices.render_item(index, hovered, layout, backend)
+        let index = self.index(row);
+
+        if self.is_header(index) {
+            return self.render_header(index, hovered, layout, b);
+        }
+
+        if self.group_of(index).is_some() {
+            layout.offset_x += GROUP_INDENT;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        if self.icon_width == 0 || !self.choices.is_selectable(index) {
+            return self.choices.render_item(index, hovered, layout, b);
+        }
+
+        if hovered {
+            b.set_fg(Color::Cyan)?;
+        }
+
+        self.choices.write_left_gutter(hovered, b)?;
+
+        if let Some(Some(icon)) = self.icons.get(index) {
+            b.write_all(icon.as_bytes())?;
+        }
+
+        // Terminals may not agree on the width of some icons, so the cursor is moved to the text
+        // instead of padding the icon with spaces
+        let line = layout.offset_y;
+        layout.offset_x += self.choices.gutter_width() + self.icon_width;
+        layout.width -= self.choices.right_gutter_width();
+        b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+        self.choices[index].render(&mut layout, b)?;
+
+        self.choices.write_right_gutter(hovered, layout, line, b)?;
+
+        b.set_fg(Color::Reset)
     }
 
-    fn is_selectable(&self, index: usize) -> bool {
-        self.choices.is_selectable(index)
+    fn is_selectable(&self, row: usize) -> bool {
+        let index = self.index(row);
+        self.is_header(index) || self.choices.is_selectable(index)
     }
 
-    fn height_at(&mut self, index: usize, layout: ui::layout::Layout) -> u16 {
+    fn height_at(&mut self, row: usize, mut layout: ui::layout::Layout) -> u16 {
+        let index = self.index(row);
+
+        if self.group_of(index).is_some() {
+            layout.offset_x += GROUP_INDENT;
+        }
+
+        if self.choices.is_selectable(index) {
+            layout.offset_x += self.icon_width;
+        }
         self.choices.height_at(index, layout)
     }
 
     fn len(&self) -> usize {
-        self.choices.len()
+        if self.groups.is_empty() {
+            self.choices.len()
+        } else {
+            self.rows.len()
+        }
     }
 
     fn page_size(&self) -> usize {
@@ -104,41 +605,157 @@ impl widgets::List for Select<'_> {
     fn should_loop(&self) -> bool {
         self.choices.should_loop()
     }
+
+    fn scroll_margin(&self) -> usize {
+        self.choices.scroll_margin()
+    }
+
+    fn scroll_hint(&self, hidden: usize) -> Option<String> {
+        match self.scroll_hint_format {
+            FormatByVal::Sync(ref scroll_hint_format) => Some(scroll_hint_format(hidden)),
+            FormatByVal::None => None,
+        }
+    }
 }
 
 impl<'a> Select<'a> {
-    fn into_prompt(self, message: &'a str) -> SelectPrompt<'a> {
-        let mut select = widgets::Select::new(self);
-        if let Some(default) = select.list.choices.default() {
-            select.set_at(default);
+    pub(crate) fn answer_with(self, value: Answer) -> ui::Result<Answer> {
+        let item = value
+            .try_into_list_item()
+            .expect("`select` questions are answered with a `ListItem`");
+        let mut index = item.index;
+
+        if index == ListItem::OTHER_INDEX {
+            if self.other_index.is_none() || item.text.is_empty() {
+                return Err(super::invalid_answer("Please select a valid choice"));
+            }
+
+            let mut other = widgets::StringInput::default();
+            other.set_value(item.text);
+
+            let mut prompt = self.into_prompt("");
+            prompt.other = Some(other);
+
+            return super::submit(prompt).map(Answer::ListItem);
         }
 
-        SelectPrompt {
+        if index == ListItem::NONE_INDEX {
+            index = self.none_index.unwrap_or(index);
+        }
+
+        if index >= self.choices.len() || self.choices[index].is_separator() {
+            return Err(super::invalid_answer("Please select a valid choice"));
+        }
+
+        let mut prompt = self.into_prompt("");
+        prompt.set_at(index);
+
+        super::submit(prompt).map(Answer::ListItem)
+    }
+
+    fn into_prompt(mut self, message: &'a str) -> SelectPrompt<'a> {
+        // The headers of the groups are not shown in the other orientations
+        if self.orientation != Orientation::Vertical {
+            self.groups
+                .iter_mut()
+                .for_each(|group| group.expanded = true);
+        }
+        if !self.groups.is_empty() {
+            self.update_rows();
+        }
+
+        let default = self.choices.default();
+        let mut prompt = SelectPrompt {
             prompt: widgets::Prompt::new(message),
-            select,
+            select: widgets::Select::new(self),
+            width: 0,
+            other: None,
+        };
+
+        if let Some(default) = default {
+            prompt.set_at(default);
         }
+
+        prompt
+    }
+
+    /// Shows the `empty_message` until the question is skipped with `Esc`, since there is no
+    /// choice to select.
+    fn ask_empty<B: Backend, E: EventIterator>(
+        self,
+        message: String,
+        exit: super::ExitOptions,
+        b: &mut B,
+        events: &mut E,
+    ) -> ui::Result<Option<Answer>> {
+        let empty_message = self
+            .empty_message
+            .ok_or_else(|| super::invalid_answer(super::NO_CHOICES_ERROR))?;
+
+        // `Esc` is the only way to leave the prompt other than `Ctrl+C`
+        let (on_esc, hint) = match exit.on_esc {
+            ui::OnEsc::Terminate => (ui::OnEsc::Terminate, "Press <esc> to exit"),
+            _ => (ui::OnEsc::SkipQuestion, "Press <esc> to skip"),
+        };
+
+        ui::Input::new(
+            EmptyPrompt {
+                prompt: widgets::Prompt::new(&*message)
+                    .with_hint(hint)
+                    .with_delim(widgets::Delimiter::None),
+                message: Text::new(empty_message),
+            },
+            b,
+        )
+        .hide_cursor()
+        .on_esc(on_esc)
+        .confirm_on_interrupt(exit.interrupt_confirm)
+        .submit_on_tab(exit.submit_on_tab)
+        .back_on_back_tab(exit.back_on_back_tab)
+        .ensure_newline(exit.ensure_newline)
+        .run(events)?;
+
+        Ok(None)
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        exit: super::ExitOptions,
+        silent: bool,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
-        let transform = self.transform.take();
+        if !self.choices.has_selectable() {
+            return self.ask_empty(message, exit, b, events);
+        }
+
+        let transform = self
+            .transform_with_choices
+            .take()
+            .into_transform(self.transform.take(), || self.choice_texts());
+        let answer_format = self.answer_format.take();
+
         let ans = ui::Input::new(self.into_prompt(&message), b)
-            .hide_cursor()
-            .on_esc(on_esc)
+            .on_esc(exit.on_esc)
+            .confirm_on_interrupt(exit.interrupt_confirm)
+            .submit_on_tab(exit.submit_on_tab)
+            .back_on_back_tab(exit.back_on_back_tab)
+            .ensure_newline(exit.ensure_newline)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
-            &ans.text
-                .lines()
-                .next()
-                .expect("There must be at least one line in a `str`")
-                .cyan()
-        )?)
+        crate::write_final!(transform, silent, message, ans [ref], answers, b, |ans| {
+            match answer_format {
+                Format::Sync(answer_format) => b.write_styled(&answer_format(ans).cyan())?,
+                Format::None => b.write_styled(
+                    &ans.text
+                        .lines()
+                        .next()
+                        .expect("There must be at least one line in a `str`")
+                        .cyan(),
+                )?,
+            }
+        })
     }
 }
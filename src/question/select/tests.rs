@@ -128,3 +128,231 @@ test_select!(pagination {
         );
     height = 16;
 });
+
+test_select!(gutter_width_0 {
+    select = unwrap_select(
+            SelectBuilder::new("name".into()).choices(choices(10)).gutter_width(0),
+        );
+    height = 11;
+});
+
+test_select!(gutter_width_2 {
+    select = unwrap_select(
+            SelectBuilder::new("name".into()).choices(choices(10)).gutter_width(2),
+        );
+    height = 11;
+});
+
+#[test]
+fn test_page_size_auto() {
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(choices(20))
+            .page_size_auto(),
+    )
+    .into_prompt("message");
+
+    // The prompt line is left along with the page, so it takes the whole terminal
+    for &size in [(50, 8), (50, 12)].iter() {
+        let base_layout = Layout::new(0, size.into());
+
+        let mut layout = base_layout;
+        assert_eq!(select.height(&mut layout), size.1);
+        assert_eq!(layout, base_layout.with_offset(0, size.1));
+
+        let mut backend = TestBackend::new_with_layout(size.into(), base_layout);
+        let mut layout = base_layout;
+        assert!(select.render(&mut layout, &mut backend).is_ok());
+        assert_eq!(layout, base_layout.with_offset(0, size.1));
+        ui::assert_backend_snapshot!(backend);
+
+        for _ in 0..8 {
+            assert!(select.handle_key(KeyCode::Down.into()));
+        }
+    }
+}
+
+fn default_by_choices() -> Vec<Choice<String>> {
+    vec![
+        Choice::Separator("Pizza".into()),
+        "Pasta".to_owned().into(),
+        Choice::DefaultSeparator,
+        "Pizza".to_owned().into(),
+        "Salad".to_owned().into(),
+    ]
+}
+
+#[test]
+fn test_default_by() {
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(default_by_choices())
+            .default_by(|choice| choice == "Pizza"),
+    );
+    assert_eq!(select.choices.default(), Some(3));
+
+    // default_by is resolved on build, so choices can be added later
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .default_by(|choice| choice.starts_with('S'))
+            .choices(default_by_choices()),
+    );
+    assert_eq!(select.choices.default(), Some(4));
+
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(default_by_choices())
+            .default_by(|choice| choice == "Pizza")
+            .default(1),
+    );
+    assert_eq!(select.choices.default(), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "No `Choice` satisfies the `default_by` predicate")]
+fn test_default_by_no_match() {
+    SelectBuilder::new("name".into())
+        .choices(default_by_choices())
+        .default_by(|choice| choice == "Soup")
+        .build();
+}
+
+#[test]
+fn test_groups() {
+    let layout = Layout::new(0, (50, 20).into());
+    let new_select = || {
+        unwrap_select(
+            SelectBuilder::new("name".into())
+                .choice("a")
+                .group("group", vec!["b", "c", "d"], false)
+                .choice("e"),
+        )
+        .into_prompt("message")
+    };
+    let press = |select: &mut SelectPrompt<'_>, key: KeyCode| {
+        select.height(&mut layout.clone());
+        assert!(select.handle_key(key.into()));
+    };
+    let visible = |select: &SelectPrompt<'_>| widgets::List::len(&select.select.list);
+
+    let mut select = new_select();
+    assert_eq!(visible(&select), 3);
+
+    // The collapsed group is skipped over by its header
+    press(&mut select, KeyCode::Down);
+    assert_eq!(select.at(), 1);
+    press(&mut select, KeyCode::Down);
+    assert_eq!(select.at(), 5);
+
+    press(&mut select, KeyCode::Up);
+    press(&mut select, KeyCode::Char(' '));
+    assert_eq!(visible(&select), 6);
+    assert_eq!(select.at(), 1);
+
+    press(&mut select, KeyCode::Down);
+    press(&mut select, KeyCode::Down);
+    assert_eq!(select.at(), 3);
+
+    // Left goes back to the header, and then collapses the group
+    press(&mut select, KeyCode::Left);
+    assert_eq!(select.at(), 1);
+    press(&mut select, KeyCode::Left);
+    assert_eq!(visible(&select), 3);
+    assert!(!select.handle_key(KeyCode::Left.into()));
+
+    // Submitting the header expands it instead
+    assert_eq!(select.validate(), Ok(Validation::Continue));
+    assert_eq!(visible(&select), 6);
+
+    press(&mut select, KeyCode::Down);
+    press(&mut select, KeyCode::Down);
+    press(&mut select, KeyCode::Down);
+    press(&mut select, KeyCode::Down);
+    assert_eq!(select.validate(), Ok(Validation::Finish));
+    assert_eq!(
+        select.finish(),
+        ListItem {
+            index: 5,
+            text: "e".into()
+        }
+    );
+
+    // The indices of the choices in the group are the same whether it is collapsed or not
+    let mut select = new_select();
+    press(&mut select, KeyCode::Down);
+    press(&mut select, KeyCode::Right);
+    press(&mut select, KeyCode::Down);
+    press(&mut select, KeyCode::Down);
+    assert_eq!(
+        select.finish(),
+        ListItem {
+            index: 3,
+            text: "c".into()
+        }
+    );
+
+    // A default in a collapsed group expands it
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .group("group", vec!["b", "c", "d"], false)
+            .default(2),
+    )
+    .into_prompt("message");
+    assert_eq!(visible(&select), 4);
+    assert_eq!(select.at(), 2);
+}
+
+#[test]
+fn test_other_cursor() {
+    let layout = Layout::new(0, (50, 20).into());
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(vec!["Red", "Blue"])
+            .with_other("Other"),
+    )
+    .into_prompt("message");
+
+    // The cursor is hidden while choosing
+    assert!(!select.shows_cursor());
+
+    select.height(&mut layout.clone());
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.validate(), Ok(Validation::Continue));
+    assert!(select.shows_cursor());
+
+    // The cursor follows the custom answer as it is typed after the message
+    assert_eq!(select.cursor_pos(layout), (12, 0));
+    for c in "Teal".chars() {
+        assert!(select.handle_key(KeyCode::Char(c).into()));
+    }
+    assert_eq!(select.cursor_pos(layout), (16, 0));
+    assert!(select.handle_key(KeyCode::Left.into()));
+    assert_eq!(select.cursor_pos(layout), (15, 0));
+
+    // `Esc` goes back to the choices, which hides the cursor again
+    assert!(select.handle_key(KeyCode::Esc.into()));
+    assert!(!select.shows_cursor());
+}
+
+#[test]
+fn test_groups_sorted() {
+    let select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choice("z")
+            .group("group", vec!["y", "b"], true)
+            .choices(vec!["x", "a"])
+            .sorted(),
+    );
+
+    let texts: Vec<_> = select
+        .choices
+        .choices
+        .iter()
+        .map(|choice| match choice {
+            Choice::Choice(text) => text.text.as_str(),
+            Choice::Separator(label) => label.as_str(),
+            Choice::DefaultSeparator => unreachable!(),
+        })
+        .collect();
+    assert_eq!(texts, vec!["z", "group", "b", "y", "a", "x"]);
+}
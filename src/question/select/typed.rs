@@ -0,0 +1,421 @@
+use std::fmt::Display;
+
+use ui::{backend::Backend, events::EventIterator};
+
+use super::{Orientation, SelectBuilder};
+use crate::question::{Predicate, Question};
+
+/// The builder for a [`typed_select`] prompt.
+///
+/// It is the same as a [`select`] prompt, except that each choice carries a value of type `T`, and
+/// the value of the selected choice is returned instead of a [`ListItem`]. As the answer is not
+/// an [`Answer`], it is prompted on its own with [`TypedSelect::prompt`] instead of through a
+/// [`PromptModule`].
+///
+/// See the various methods for more details on each available option.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::Question;
+///
+/// #[derive(Debug)]
+/// enum Size {
+///     Small,
+///     Medium,
+///     Large,
+/// }
+///
+/// let select = Question::typed_select("size")
+///     .message("What size do you want?")
+///     .choice("Small", Size::Small)
+///     .choice("Medium", Size::Medium)
+///     .choice("Large", Size::Large)
+///     .build();
+/// ```
+///
+/// [`typed_select`]: crate::question::Question::typed_select
+/// [`select`]: crate::question::Question::select
+/// [`ListItem`]: crate::ListItem
+/// [`Answer`]: crate::Answer
+/// [`PromptModule`]: crate::PromptModule
+#[derive(Debug)]
+pub struct TypedSelectBuilder<'a, T> {
+    select: SelectBuilder<'a>,
+    /// The value of each choice, or `None` for separators.
+    values: Vec<Option<T>>,
+    default_matching: Predicate<'a, T>,
+}
+
+impl<'a, T> TypedSelectBuilder<'a, T> {
+    pub(crate) fn new(name: String) -> Self {
+        TypedSelectBuilder {
+            select: SelectBuilder::new(name),
+            values: Vec::new(),
+            default_matching: Predicate::None,
+        }
+    }
+
+    /// The message to display when the prompt is rendered in the terminal.
+    ///
+    /// See [`SelectBuilder::message`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .message("What size do you want?")
+    ///     .choice("Small", 1)
+    ///     .build();
+    /// ```
+    pub fn message<M>(mut self, message: M) -> Self
+    where
+        M: Into<crate::question::options::Getter<'a, String>>,
+    {
+        self.select = self.select.message(message);
+        self
+    }
+
+    /// Set a default index for the select
+    ///
+    /// See [`SelectBuilder::default`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", 1)
+    ///     .choice("Medium", 2)
+    ///     .default(1)
+    ///     .build();
+    /// ```
+    pub fn default(mut self, default: usize) -> Self {
+        self.select = self.select.default(default);
+        self.default_matching = Predicate::None;
+        self
+    }
+
+    /// Set the default to the first [`Choice`] whose value satisfies the given predicate
+    ///
+    /// The predicate is resolved to an index on [`build`], so it does not matter whether the
+    /// choices are added before or after calling this. If multiple values satisfy the predicate,
+    /// the first one is the default. If none do, there is no default and the first [`Choice`] is
+    /// highlighted. If both `default` and `default_matching` are called, the last one wins.
+    ///
+    /// To match against the text of the choices instead, see [`SelectBuilder::default_by`].
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    /// [`build`]: Self::build
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let previous = "MEDIUM";
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", "small")
+    ///     .choice("Medium", "medium")
+    ///     .choice("Large", "large")
+    ///     .default_matching(move |size| size.eq_ignore_ascii_case(previous))
+    ///     .build();
+    /// ```
+    pub fn default_matching<F>(mut self, default_matching: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.default_matching = Predicate::Sync(Box::new(default_matching));
+        self
+    }
+
+    /// The maximum height that can be taken by the list
+    ///
+    /// See [`SelectBuilder::page_size`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// It will panic if the `page_size` is less than 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", 1)
+    ///     .page_size(10)
+    ///     .build();
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.select = self.select.page_size(page_size);
+        self
+    }
+
+    /// Whether to wrap around when user gets to the last element.
+    ///
+    /// See [`SelectBuilder::should_loop`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", 1)
+    ///     .should_loop(false)
+    ///     .build();
+    /// ```
+    pub fn should_loop(mut self, should_loop: bool) -> Self {
+        self.select = self.select.should_loop(should_loop);
+        self
+    }
+
+    /// The direction in which the choices are laid out.
+    ///
+    /// See [`SelectBuilder::orientation`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::Orientation, Question};
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", 1)
+    ///     .choice("Large", 2)
+    ///     .orientation(Orientation::Horizontal)
+    ///     .build();
+    /// ```
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.select = self.select.orientation(orientation);
+        self
+    }
+
+    /// Hide the cursor of the terminal while the prompt is running.
+    ///
+    /// See [`SelectBuilder::hide_cursor`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", 1)
+    ///     .hide_cursor(false)
+    ///     .build();
+    /// ```
+    pub fn hide_cursor(mut self, hide_cursor: bool) -> Self {
+        self.select = self.select.hide_cursor(hide_cursor);
+        self
+    }
+
+    /// Inserts a [`Choice`] with the given text, whose `value` is returned if it is selected.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choice("Small", 1)
+    ///     .build();
+    /// ```
+    pub fn choice<I: Into<String>>(mut self, text: I, value: T) -> Self {
+        self.select = self.select.choice(text);
+        self.values.push(Some(value));
+        self
+    }
+
+    /// Extends the given iterator of texts and values as [`Choice`]s
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .choices(vec![("Small", 1), ("Medium", 2), ("Large", 3)])
+    ///     .build();
+    /// ```
+    pub fn choices<I, S>(self, choices: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = (S, T)>,
+    {
+        choices
+            .into_iter()
+            .fold(self, |this, (text, value)| this.choice(text, value))
+    }
+
+    /// Extends the [`Choice`]s with every value of `T`, in the order given by
+    /// [`AsChoices::choices`]. The text of each choice is the value formatted with [`Display`].
+    ///
+    /// This is useful for enums without fields, where the selected variant is returned.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt;
+    ///
+    /// use requestty::{question::AsChoices, Question};
+    ///
+    /// #[derive(Debug)]
+    /// enum Size {
+    ///     Small,
+    ///     Large,
+    /// }
+    ///
+    /// impl AsChoices for Size {
+    ///     fn choices() -> Vec<Self> {
+    ///         vec![Size::Small, Size::Large]
+    ///     }
+    /// }
+    ///
+    /// impl fmt::Display for Size {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self {
+    ///             Size::Small => f.write_str("Small"),
+    ///             Size::Large => f.write_str("Large"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let select = Question::typed_select::<Size, _>("size")
+    ///     .all_choices()
+    ///     .build();
+    /// ```
+    pub fn all_choices(self) -> Self
+    where
+        T: AsChoices + Display,
+    {
+        T::choices()
+            .into_iter()
+            .fold(self, |this, value| this.choice(value.to_string(), value))
+    }
+
+    /// Inserts a [`Separator`] with the given text
+    ///
+    /// [`Separator`]: crate::question::Choice::Separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .separator("-- custom separator text --")
+    ///     .choice("Small", 1)
+    ///     .build();
+    /// ```
+    pub fn separator<I: Into<String>>(mut self, text: I) -> Self {
+        self.select = self.select.separator(text);
+        self.values.push(None);
+        self
+    }
+
+    /// Inserts a [`DefaultSeparator`]
+    ///
+    /// [`DefaultSeparator`]: crate::question::Choice::DefaultSeparator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::typed_select("size")
+    ///     .default_separator()
+    ///     .choice("Small", 1)
+    ///     .build();
+    /// ```
+    pub fn default_separator(mut self) -> Self {
+        self.select = self.select.default_separator();
+        self.values.push(None);
+        self
+    }
+
+    /// Consumes the builder returning a [`TypedSelect`]
+    pub fn build(mut self) -> TypedSelect<'a, T> {
+        if let Predicate::Sync(default_matching) = self.default_matching.take() {
+            let default = self
+                .values
+                .iter()
+                .position(|value| matches!(value, Some(value) if default_matching(value)));
+
+            self.select = match default {
+                Some(default) => self.select.default(default),
+                None => self.select.clear_default(),
+            };
+        }
+
+        TypedSelect {
+            question: self.select.build(),
+            values: self.values,
+        }
+    }
+}
+
+/// A type whose values can be listed as the choices of a [`typed_select`] prompt, such as an enum
+/// without fields.
+///
+/// See [`TypedSelectBuilder::all_choices`] for an example.
+///
+/// [`typed_select`]: crate::question::Question::typed_select
+pub trait AsChoices: Sized {
+    /// Returns every value of the type, in the order they should be shown.
+    fn choices() -> Vec<Self>;
+}
+
+/// A [`select`] prompt whose choices carry values of type `T`, created with
+/// [`Question::typed_select`].
+///
+/// [`select`]: crate::question::Question::select
+#[derive(Debug)]
+pub struct TypedSelect<'a, T> {
+    question: Question<'a>,
+    values: Vec<Option<T>>,
+}
+
+impl<T> TypedSelect<'_, T> {
+    /// Prompt the question with the default [`Backend`] and [`EventIterator`], returning the value
+    /// of the selected choice.
+    ///
+    /// [`EventIterator`]: crate::prompt::EventIterator
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+    pub fn prompt(self) -> ui::Result<T> {
+        let stdout = std::io::stdout();
+        let mut stdout = ui::backend::get_backend(stdout.lock());
+        let mut events = ui::events::get_events();
+
+        self.prompt_with(&mut stdout, &mut events)
+    }
+
+    /// Prompt the question with the given [`Backend`] and [`EventIterator`], returning the value of
+    /// the selected choice.
+    ///
+    /// [`EventIterator`]: crate::prompt::EventIterator
+    pub fn prompt_with<B: Backend, E: EventIterator>(
+        mut self,
+        backend: &mut B,
+        events: &mut E,
+    ) -> ui::Result<T> {
+        let item = crate::prompt_one_with(self.question, backend, events)?
+            .try_into_list_item()
+            .expect("select prompts always answer with a `ListItem`");
+
+        Ok(self.values[item.index]
+            .take()
+            .expect("separators cannot be selected"))
+    }
+}
@@ -0,0 +1,219 @@
+use std::{io, time::Duration, time::Instant};
+
+use ui::events::{EventIterator, KeyCode, KeyEvent, TestEvents};
+
+use crate::{Answers, Question};
+
+/// A record of a session of questions, created by [`PromptModule::record_with`].
+///
+/// It contains every key pressed, grouped by the question it was pressed for, and the final
+/// answers. It can be used to reproduce a session by replaying the keys with [`events`], for
+/// example to attach to a bug report or to use as a regression test.
+///
+/// With the `serde` feature, the transcript can be (de)serialized. Its format is:
+///
+/// ```json
+/// {
+///   "questions": [
+///     {
+///       "name": "name",
+///       "keys": [
+///         { "key": { "code": { "Char": "a" }, "modifiers": 0 }, "elapsed": { "secs": 0, "nanos": 0 } }
+///       ],
+///       "secret": false
+///     }
+///   ],
+///   "answers": { "name": { "String": "a" } }
+/// }
+/// ```
+///
+/// `modifiers` are the bits of [`KeyModifiers`], and `elapsed` is the time since the start of the
+/// session.
+///
+/// The answers to [`secret`] questions are redacted, and the keys pressed for them are not
+/// recorded. Their answers have to be given again to replay the transcript, with
+/// [`events_with`].
+///
+/// [`PromptModule::record_with`]: crate::PromptModule::record_with
+/// [`events`]: Transcript::events
+/// [`events_with`]: Transcript::events_with
+/// [`KeyModifiers`]: ui::events::KeyModifiers
+/// [`secret`]: crate::question::InputBuilder::secret
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Transcript {
+    /// The questions which were shown, in the order they were shown.
+    pub questions: Vec<TranscriptQuestion>,
+    /// The answers at the end of the session.
+    pub answers: Answers,
+}
+
+impl Transcript {
+    /// Returns an [`EventIterator`] which replays all the keys in the transcript.
+    ///
+    /// Passing it to [`PromptModule::prompt_all_with`] along with the same questions will
+    /// reproduce the session. The keys of [secret] questions are not recorded, so nothing is
+    /// replayed for them. If there are any, use [`events_with`] instead.
+    ///
+    /// [`PromptModule::prompt_all_with`]: crate::PromptModule::prompt_all_with
+    /// [secret]: TranscriptQuestion::secret
+    /// [`events_with`]: Transcript::events_with
+    pub fn events(&self) -> TestEvents<std::vec::IntoIter<KeyEvent>> {
+        self.events_with(|_| None)
+    }
+
+    /// Returns an [`EventIterator`] which replays all the keys in the transcript, typing the answer
+    /// returned by `secret` for each [secret] question.
+    ///
+    /// `secret` is called with the name of each secret question. If it returns an answer, it is
+    /// typed and submitted with `Enter` in place of the keys which were not recorded. Otherwise,
+    /// nothing is replayed for the question.
+    ///
+    /// [secret]: TranscriptQuestion::secret
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    /// use requestty::prompt::{backend::TestBackend, events::{KeyCode, TestEvents}};
+    ///
+    /// let questions = || vec![Question::password("password").build()];
+    /// let mut backend = TestBackend::new((50, 10).into());
+    /// let mut events = TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+    ///
+    /// let transcript = PromptModule::new(questions()).record_with(&mut backend, &mut events)?;
+    ///
+    /// let mut events = transcript.events_with(|name| match name {
+    ///     "password" => Some("hunter2".to_owned()),
+    ///     _ => None,
+    /// });
+    /// let answers = PromptModule::new(questions()).prompt_all_with(&mut backend, &mut events)?;
+    /// assert_eq!(answers["password"].as_string(), Some("hunter2"));
+    /// # Result::<_, requestty::ErrorKind>::Ok(())
+    /// ```
+    pub fn events_with<F>(&self, mut secret: F) -> TestEvents<std::vec::IntoIter<KeyEvent>>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut keys = Vec::new();
+
+        for question in &self.questions {
+            if !question.secret {
+                keys.extend(question.keys.iter().map(|key| key.key));
+            } else if let Some(answer) = secret(&question.name) {
+                keys.extend(answer.chars().map(|c| KeyEvent::from(KeyCode::Char(c))));
+                keys.push(KeyCode::Enter.into());
+            }
+        }
+
+        TestEvents::new(keys)
+    }
+}
+
+/// A question shown in a [`Transcript`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct TranscriptQuestion {
+    /// The name of the question.
+    pub name: String,
+    /// The keys pressed while the question was shown. It is empty if the question is
+    /// [secret](TranscriptQuestion::secret).
+    pub keys: Vec<TranscriptKey>,
+    /// Whether the question is [secret], in which case the keys pressed for it are not recorded.
+    ///
+    /// [secret]: crate::question::InputBuilder::secret
+    pub secret: bool,
+}
+
+/// A key pressed in a [`Transcript`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize, serde_crate::Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct TranscriptKey {
+    /// The key which was pressed.
+    pub key: KeyEvent,
+    /// The time since the start of the session when the key was pressed.
+    pub elapsed: Duration,
+}
+
+/// An [`EventIterator`] which records the keys it yields.
+pub(crate) struct Recorder<'e, E> {
+    events: &'e mut E,
+    start: Instant,
+    /// The name of the question which was just reached, but has not received a key yet.
+    pending: Option<String>,
+    /// Whether the question being asked is secret, so the keys pressed are not recorded.
+    secret: bool,
+    pub(crate) questions: Vec<TranscriptQuestion>,
+}
+
+impl<'e, E: EventIterator> Recorder<'e, E> {
+    pub(crate) fn new(events: &'e mut E) -> Self {
+        Self {
+            events,
+            start: Instant::now(),
+            pending: None,
+            secret: false,
+            questions: Vec::new(),
+        }
+    }
+
+    /// Marks that the given question is about to be asked. It is only added to the transcript if
+    /// it receives a key.
+    pub(crate) fn start_question(&mut self, question: &Question<'_>) {
+        self.pending = Some(question.name().to_owned());
+        self.secret = question.is_secret();
+    }
+}
+
+impl<E: EventIterator> EventIterator for Recorder<'_, E> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        let key = self.events.next_event()?;
+
+        if let Some(name) = self.pending.take() {
+            self.questions.push(TranscriptQuestion {
+                name,
+                keys: Vec::new(),
+                secret: self.secret,
+            });
+        }
+
+        let recorded = TranscriptKey {
+            key,
+            elapsed: self.start.elapsed(),
+        };
+
+        match self.questions.last_mut() {
+            Some(question) if question.secret => {}
+            Some(question) => question.keys.push(recorded),
+            // The key cannot be attributed to any question
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "key received before any question was asked",
+                ))
+            }
+        }
+
+        Ok(key)
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.events.is_interactive()
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        self.events.next_line()
+    }
+}
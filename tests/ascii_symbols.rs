@@ -0,0 +1,31 @@
+use requestty::{PromptModule, Question};
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_ascii_symbols() {
+    let select = || {
+        Question::select("name")
+            .message("message")
+            .choices(vec![
+                "foo".into(),
+                requestty::DefaultSeparator,
+                "bar".into(),
+            ])
+            .build()
+    };
+    let events = || TestEvents::new(vec![KeyCode::Down.into(), KeyCode::Enter.into()]);
+
+    for &ascii_symbols in &[false, true] {
+        let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+
+        PromptModule::new(vec![select()])
+            .ascii_symbols(ascii_symbols)
+            .prompt_all_with(&mut backend, &mut events())
+            .unwrap();
+    }
+
+    // The symbols are only used while the questions of the module are asked
+    assert_eq!(requestty::symbols::current(), requestty::symbols::UNICODE);
+}
@@ -1,7 +1,9 @@
+use std::{collections::VecDeque, io, time::Duration};
+
 use requestty::{Answer, Question};
 use ui::{
-    events::{KeyCode, TestEvents},
-    style::Stylize,
+    events::{EventIterator, KeyCode, KeyEvent, TestEvents},
+    style::{Attributes, Color, Style, Stylize},
 };
 
 mod helpers;
@@ -72,6 +74,52 @@ fn test_validate() {
     assert_eq!(ans, Answer::Bool(false));
 }
 
+#[test]
+fn test_accept_words() {
+    let size = (50, 20).into();
+
+    let confirm = || {
+        Question::confirm("name").message("message").accept_words(&[
+            ("yes", true),
+            ("no", false),
+            ("true", true),
+            ("false", false),
+        ])
+    };
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('Y').into(),
+        KeyCode::Char('e').into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(confirm(), &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Bool(true));
+
+    // `x` is not one of the words, while `n` is short for `no`
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('x').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('n').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(confirm(), &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Bool(false));
+
+    // Enter still takes the default
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans =
+        requestty::prompt_one_with(confirm().default(true), &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Bool(true));
+}
+
 #[test]
 fn test_transform() {
     let size = (50, 20).into();
@@ -91,6 +139,25 @@ fn test_transform() {
     assert_eq!(ans, Answer::Bool(true));
 }
 
+#[test]
+fn test_hint_style() {
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(
+        Question::confirm("name")
+            .message("message")
+            .default(false)
+            .hint_style(Style::new().fg(Color::Yellow).attributes(Attributes::BOLD)),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::Bool(false));
+}
+
 #[test]
 fn test_on_esc() {
     let size = (50, 20).into();
@@ -125,3 +192,83 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+/// Events which can be polled, where `None` is a poll which timed out. The time that has passed is
+/// the sum of the timeouts of those polls.
+struct ClockEvents {
+    events: VecDeque<Option<KeyEvent>>,
+    elapsed: Duration,
+}
+
+impl ClockEvents {
+    fn new<I: IntoIterator<Item = Option<KeyEvent>>>(events: I) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            elapsed: Duration::from_secs(0),
+        }
+    }
+}
+
+impl EventIterator for ClockEvents {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        Ok(self
+            .events
+            .pop_front()
+            .flatten()
+            .expect("Expected a key, but the time was meant to pass"))
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        let e = self.events.pop_front().expect("Events ran out");
+        if e.is_none() {
+            self.elapsed += timeout;
+        }
+        Ok(e)
+    }
+}
+
+impl Drop for ClockEvents {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            assert!(
+                self.events.is_empty(),
+                "Events remaining: {:?}",
+                self.events
+            );
+        }
+    }
+}
+
+#[test]
+fn test_auto_answer() {
+    let size = (50, 20).into();
+    let confirm = || {
+        Question::confirm("name")
+            .message("message")
+            .default(true)
+            .auto_answer(Duration::from_secs(3), false)
+    };
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = ClockEvents::new(vec![None, None, None]);
+
+    let ans = requestty::prompt_one_with(confirm(), &mut backend, &mut events).unwrap();
+
+    // the auto answer is given instead of the default once the time runs out
+    assert_eq!(ans, Answer::Bool(false));
+    assert_eq!(events.elapsed, Duration::from_secs(3));
+    drop(events);
+
+    // any key cancels the countdown, even if the prompt does not handle it
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = ClockEvents::new(vec![
+        None,
+        Some(KeyCode::Char('x').into()),
+        Some(KeyCode::Enter.into()),
+    ]);
+
+    let ans = requestty::prompt_one_with(confirm(), &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::Bool(true));
+    assert_eq!(events.elapsed, Duration::from_secs(1));
+}
@@ -36,7 +36,7 @@ fn test_tranform() {
         .try_into_expand_item()
         .unwrap();
 
-    assert_eq!(ans.key, 'b');
+    assert_eq!(ans.key, "b");
 }
 
 #[test]
@@ -63,7 +63,7 @@ fn test_default() {
         .try_into_expand_item()
         .unwrap();
 
-    assert_eq!(ans.key, 'd');
+    assert_eq!(ans.key, "d");
 
     let expand = Question::expand("name")
         .message("message")
@@ -82,7 +82,7 @@ fn test_default() {
         .try_into_expand_item()
         .unwrap();
 
-    assert_eq!(ans.key, 'd');
+    assert_eq!(ans.key, "d");
 }
 
 #[test]
@@ -125,3 +125,54 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_expand_key() {
+    let size = (50, 20).into();
+
+    let expand = Question::expand("name")
+        .message("message")
+        .choices(('a'..='g').map(|key| (key, format!("Choice {}", key.to_ascii_uppercase()))));
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    // The help option expands the list, and submitting it again should not finish the prompt
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('h').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(expand, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans.expand_key(), Some("c"));
+    assert_eq!(ans.as_expand_item().unwrap().text, "Choice C");
+}
+
+#[test]
+fn test_columns() {
+    let size = (50, 20).into();
+
+    let expand = Question::expand("name")
+        .message("message")
+        .choices(
+            ('a'..='m')
+                .filter(|&key| key != 'h')
+                .map(|key| (key, format!("Choice {}", key.to_ascii_uppercase()))),
+        )
+        .columns(2);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('h').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('k').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(expand, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans.expand_key(), Some("k"));
+    assert_eq!(ans.as_expand_item().unwrap().text, "Choice K");
+}
@@ -1,7 +1,10 @@
+use std::io::Write;
+
 use requestty::{question::Completions, Answer, Question};
 use ui::{
+    backend::Backend,
     events::{KeyCode, TestEvents},
-    style::Color,
+    style::{Color, Stylize},
 };
 
 mod helpers;
@@ -57,6 +60,31 @@ fn test_validate_on_key() {
     assert_eq!(ans, Answer::String("str".into()));
 }
 
+#[test]
+fn test_validate_on_change() {
+    let prompt = Question::input("name")
+        .message("message")
+        .validate(|s, _| {
+            if s.contains('@') {
+                Ok(())
+            } else {
+                Err("Please enter a valid email".into())
+            }
+        })
+        .validate_on_change(true);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    // The error is shown after `a`, and cleared after `@`
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Char('@').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("a@".into()));
+}
+
 #[test]
 fn test_filter() {
     let prompt = Question::input("name")
@@ -97,6 +125,109 @@ fn test_transform() {
     assert_eq!(ans, Answer::String("str".into()));
 }
 
+#[test]
+fn test_transform_styled() {
+    let size = (50, 20).into();
+    let prompt = Question::input("name")
+        .message("message")
+        .transform(|s, _, b| {
+            b.set_fg(Color::Green)?;
+            write!(b, "{}", s)?;
+            b.set_fg(Color::Reset)
+        });
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('s').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::Char('r').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("str".into()));
+
+    let mut expected = ui::backend::TestBackend::new(size);
+    ui::widgets::Prompt::write_finished_message(&"message", false, &mut expected).unwrap();
+    expected.write_styled(&"str".green()).unwrap();
+    expected.write_all(b"\n").unwrap();
+
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_transform_with_layout() {
+    let size = (30, 20).into();
+    let prompt = Question::input("name")
+        .message("message")
+        .transform_with_layout(|s, _, layout, b| {
+            // `✔ message · ` is before the answer
+            assert_eq!(layout.line_offset, 12);
+            write!(b, "{:>1$}", s, layout.line_width() as usize)
+        });
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('s').into(),
+        KeyCode::Char('t').into(),
+        KeyCode::Char('r').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("str".into()));
+
+    // The answer is aligned to the right edge of the terminal
+    let mut expected = ui::backend::TestBackend::new(size);
+    ui::widgets::Prompt::write_finished_message(&"message", false, &mut expected).unwrap();
+    expected.write_all(" ".repeat(15).as_bytes()).unwrap();
+    expected.write_all(b"str\n").unwrap();
+
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_initial_value() {
+    let prompt = Question::input("name")
+        .message("message")
+        .initial_value("initial");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Backspace.into(),
+        KeyCode::Char('L').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("initiaL".into()));
+
+    let prompt = Question::input("name")
+        .message("message")
+        .initial_value("initial")
+        .default("default");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("initial".into()));
+
+    let prompt = Question::input("name")
+        .message("message")
+        .initial_value("ab")
+        .default("default");
+
+    let mut events = TestEvents::new(vec![
+        KeyCode::Backspace.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("default".into()));
+}
+
 #[test]
 fn test_default() {
     let prompt = Question::input("name")
@@ -144,6 +275,67 @@ fn test_default() {
     assert_eq!(ans, Answer::String("default".into()));
 }
 
+#[test]
+fn test_default_env() {
+    const VAR: &str = "REQUESTTY_TEST_DEFAULT_ENV";
+    std::env::remove_var(VAR);
+
+    let prompt = Question::input("name")
+        .message("message")
+        .default_env(VAR)
+        .default("default");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("default".into()));
+
+    let prompt = Question::input("name")
+        .message("message")
+        .default_env(VAR)
+        .default("default");
+
+    // The variable is read when the question is asked, not when it is built
+    std::env::set_var(VAR, "from env");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("from env".into()));
+
+    std::env::remove_var(VAR);
+}
+
+#[test]
+fn test_required() {
+    let prompt = Question::input("name").message("message").required(true);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    // the empty submit is rejected before any text is typed
+    let mut events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("a".into()));
+
+    // a default is used instead of rejecting the empty submit
+    let prompt = Question::input("name")
+        .message("message")
+        .required(true)
+        .default("default");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("default".into()));
+}
+
 #[test]
 fn test_auto_complete() {
     let prompt = Question::input("name")
@@ -211,3 +403,82 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn test_normalize() {
+    use requestty::question::NormalizationForm;
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    // "café" with the accent as a combining character
+    let mut events = TestEvents::new(
+        "cafe\u{301}"
+            .chars()
+            .map(|c| KeyCode::Char(c).into())
+            .chain(Some(KeyCode::Enter.into())),
+    );
+
+    let ans = requestty::prompt_one_with(
+        Question::input("name")
+            .normalize(NormalizationForm::Nfc)
+            .validate(|name, _| {
+                if name == "caf\u{e9}" {
+                    Ok(())
+                } else {
+                    Err("Not normalized".into())
+                }
+            }),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(ans, Answer::String("caf\u{e9}".into()));
+}
+
+#[test]
+fn test_counter() {
+    let prompt = Question::input("name")
+        .message("message")
+        .show_counter(true)
+        .max_length(3);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    // `é` is a single character even though it takes two bytes, and the answer is rejected while
+    // it is too long
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('é').into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("éab".into()));
+}
+
+#[test]
+fn test_ensure_newline() {
+    for &ensure_newline in &[true, false] {
+        let prompt = Question::input("name")
+            .message("message")
+            .ensure_newline(ensure_newline);
+
+        let mut backend = ui::backend::TestBackend::new((50, 20).into());
+        // The output before the prompt does not end with a newline
+        write!(backend, "Loading...").unwrap();
+        let mut events = TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+
+        requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+
+        let screen = backend.to_plain_string();
+        let mut lines = screen.lines().map(str::trim_end);
+        if ensure_newline {
+            assert_eq!(lines.next(), Some("Loading..."));
+        }
+        assert_eq!(lines.next(), Some("✔ message · a"));
+    }
+}
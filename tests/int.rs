@@ -33,6 +33,26 @@ fn test_validate() {
     assert_eq!(ans, Answer::Int(32));
 }
 
+#[test]
+fn test_on_invalid_hint() {
+    let prompt = Question::int("name")
+        .message("message")
+        .on_invalid_hint("Only digits are allowed");
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('1').into(),
+        // rejected, so the hint is shown below the prompt
+        KeyCode::Char('a').into(),
+        // accepted, so the hint is cleared
+        KeyCode::Char('2').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(12));
+}
+
 #[test]
 fn test_validate_on_key() {
     let prompt = Question::int("name")
@@ -24,6 +24,41 @@ fn choices(len: usize) -> impl Iterator<Item = Choice<String>> {
     })
 }
 
+#[test]
+fn test_grid() {
+    // (width, columns)
+    let cases = [(15, 1), (20, 2), (30, 3)];
+
+    for &(width, columns) in &cases {
+        let multi_select = requestty::Question::multi_select("name")
+            .message("tags")
+            .choices((0..6).map(|i| format!("Tag {}", i)))
+            .default_separator()
+            .choice("Tag 6")
+            .orientation(requestty::question::Orientation::Grid);
+
+        let mut backend = helpers::SnapshotOnFlushBackend::new((width, 20).into());
+        let mut events = TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Right),
+            KeyCode::Char(' ').into(),
+            KeyCode::Down.into(),
+            KeyCode::Char(' ').into(),
+            KeyCode::Enter.into(),
+        ]);
+
+        let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_items()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.index)
+            .collect();
+
+        // down moves to the choice in the same column of the next row
+        assert_eq!(ans, [1, 1 + columns]);
+    }
+}
+
 #[test]
 fn test_validate() {
     let multi_select = requestty::Question::multi_select("name")
@@ -63,6 +98,158 @@ fn test_validate() {
     assert_eq!(ans, [3, 9]);
 }
 
+#[test]
+fn test_require_at_least_one() {
+    let multi_select = requestty::Question::multi_select("name")
+        .require_at_least_one("Choose at least one item")
+        .validate(|checked, _| {
+            if checked[9] {
+                Err("Choice 9 cannot be checked".into())
+            } else {
+                Ok(())
+            }
+        })
+        .message("multi select")
+        .choices(choices(10));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Enter),
+        KeyCode::End.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Up.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.index)
+        .collect();
+
+    assert_eq!(ans, [8]);
+}
+
+#[test]
+fn test_symbols() {
+    // (checked, unchecked, orientation)
+    let cases = [
+        ("[x]", "[ ]", requestty::question::Orientation::Vertical),
+        ("✅", "·", requestty::question::Orientation::Vertical),
+        ("✅", "·", requestty::question::Orientation::Grid),
+    ];
+
+    for &(checked, unchecked, orientation) in &cases {
+        let multi_select = requestty::Question::multi_select("name")
+            .message("tags")
+            .choices((0..4).map(|i| format!("Tag {}", i)))
+            .symbols(checked, unchecked)
+            .orientation(orientation);
+
+        let mut backend = helpers::SnapshotOnFlushBackend::new((30, 20).into());
+        let mut events = TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Char(' ')),
+            KeyCode::Right.into(),
+            KeyCode::Down.into(),
+            KeyCode::Enter.into(),
+        ]);
+
+        let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_items()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.index)
+            .collect();
+
+        assert_eq!(ans, [0]);
+    }
+}
+
+#[test]
+fn test_locked_choice() {
+    let multi_select = requestty::Question::multi_select("name")
+        .message("toppings")
+        .locked_choice("Cheese", true)
+        .choice("Olives")
+        .locked_choice("Anchovies", false)
+        .choice("Peppers");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // toggling, inverting and toggling all skip the locked choices
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyCode::Char('i').into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.index)
+        .collect();
+
+    assert_eq!(ans, [0, 1]);
+
+    // the filter cannot remove a locked choice
+    let multi_select = requestty::Question::multi_select("name")
+        .message("toppings")
+        .locked_choice("Cheese", true)
+        .choice_with_default("Olives", true)
+        .filter(|checked, _| vec![false; checked.len()]);
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(Some(KeyEvent::from(KeyCode::Enter)));
+
+    let ans = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap();
+
+    assert_eq!(ans.len(), 1);
+    assert_eq!(ans[0].index, 0);
+    assert_eq!(ans[0].text, "Cheese");
+}
+
+#[test]
+fn test_collapse_hint() {
+    let multi_select = requestty::Question::multi_select("name")
+        .message("tags")
+        .choices((0..4).map(|i| format!("Tag {}", i)))
+        .collapse_hint(true);
+
+    // the hint wraps onto 3 lines before the first key is pressed
+    let mut backend = helpers::SnapshotOnFlushBackend::new((30, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char(' ')),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.index)
+        .collect();
+
+    assert_eq!(ans, [0]);
+}
+
 #[test]
 fn test_filter() {
     let multi_select = requestty::Question::multi_select("name")
@@ -0,0 +1,59 @@
+use requestty::Question;
+use ui::events::{KeyCode, TestEvents};
+
+mod helpers;
+
+#[test]
+fn test_note() {
+    let questions = vec![
+        Question::note("instructions")
+            .message("Answer the following questions to set up your project")
+            .build(),
+        Question::input("name").message("Project name").build(),
+    ];
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers.len(), 1);
+    assert!(!answers.contains_key("instructions"));
+    assert_eq!(answers["name"].as_string(), Some("a"));
+}
+
+#[test]
+fn test_wait_for_key() {
+    let questions = vec![Question::note("warning")
+        .message("This will overwrite the existing configuration")
+        .wait_for_key(true)
+        .build()];
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // Only enter continues
+    let mut events = TestEvents::new(vec![KeyCode::Char('y').into(), KeyCode::Enter.into()]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert!(answers.is_empty());
+}
+
+#[test]
+fn test_prompt_one() {
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::empty();
+
+    // A note has no answer to return
+    let res = requestty::prompt_one_with(
+        Question::note("instructions").message("Read carefully"),
+        &mut backend,
+        &mut events,
+    );
+
+    assert!(matches!(
+        res,
+        Err(requestty::ErrorKind::IoError(e)) if e.kind() == std::io::ErrorKind::InvalidInput
+    ));
+}
@@ -0,0 +1,91 @@
+use requestty::{Answer, ErrorKind, Question};
+use ui::{backend::TestBackend, events::PipedEvents};
+
+#[test]
+fn test_answers() {
+    let questions = vec![
+        Question::input("name")
+            .filter(|name, _| name.to_uppercase())
+            .build(),
+        Question::int("age").build(),
+        Question::password("password").build(),
+        // The input runs out, so the default is used
+        Question::float("rating").default(4.5).build(),
+    ];
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = PipedEvents::new("john\n42\r\nhunter2\n".as_bytes());
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers["name"], Answer::String("JOHN".into()));
+    assert_eq!(answers["age"], Answer::Int(42));
+    assert_eq!(answers["password"], Answer::String("hunter2".into()));
+    assert_eq!(answers["rating"], Answer::Float(4.5));
+}
+
+#[test]
+fn test_errors() {
+    let mut backend = TestBackend::new((50, 20).into());
+
+    let res = requestty::prompt_one_with(
+        Question::int("age").validate(|age, _| {
+            if age > 0 {
+                Ok(())
+            } else {
+                Err("Age must be positive".into())
+            }
+        }),
+        &mut backend,
+        &mut PipedEvents::new("-3\n".as_bytes()),
+    );
+    assert!(matches!(res, Err(ErrorKind::IoError(e)) if e.to_string() == "Age must be positive"));
+
+    // There is no default to fall back to
+    let res = requestty::prompt_one_with(
+        Question::input("name"),
+        &mut backend,
+        &mut PipedEvents::new("".as_bytes()),
+    );
+    assert!(matches!(res, Err(ErrorKind::Eof)));
+
+    // Prompts which do not take text cannot be answered
+    let res = requestty::prompt_one_with(
+        Question::confirm("continue"),
+        &mut backend,
+        &mut PipedEvents::new("y\n".as_bytes()),
+    );
+    assert!(matches!(res, Err(ErrorKind::Eof)));
+}
+
+#[test]
+fn test_select() {
+    let questions = vec![
+        Question::input("name").build(),
+        Question::select("colour")
+            .choices(vec!["red", "green"])
+            .build(),
+    ];
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = PipedEvents::new("john\ngreen\n".as_bytes());
+
+    // The select cannot be answered from a line, but the answers before it are kept
+    let err = requestty::PromptModule::new(questions)
+        .prompt_all_with_partial(&mut backend, &mut events)
+        .unwrap_err();
+
+    assert!(matches!(err.error, ErrorKind::Eof));
+    assert_eq!(err.answers["name"], Answer::String("john".into()));
+    assert!(!err.answers.contains_key("colour"));
+}
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+#[test]
+fn test_default_events_not_piped() {
+    use ui::events::EventIterator;
+
+    // Stdin is not a terminal while testing, but piped events are opt-in
+    assert!(ui::events::get_events().is_interactive());
+    assert!(ui::events::get_events_allowing_piped(false).is_interactive());
+}
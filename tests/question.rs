@@ -44,7 +44,9 @@ fn test_ask_if_answered() {
     let mut prompted_1 = false;
     let mut prompted_2 = false;
 
-    prompt_all(vec![
+    // The second question is skipped since its name is already answered, which is only allowed
+    // with `allow_duplicate_names`
+    requestty::PromptModule::new(vec![
         custom_prompt("name", "message", &mut prompted_0)
             .message("message")
             .build(),
@@ -55,13 +57,384 @@ fn test_ask_if_answered() {
             .message("message")
             .ask_if_answered(true)
             .build(),
-    ]);
+    ])
+    .allow_duplicate_names(true)
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
 
     assert!(prompted_0);
     assert!(!prompted_1);
     assert!(prompted_2);
 }
 
+#[test]
+fn test_duplicate_names() {
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+
+    let res = requestty::prompt_with(
+        vec![
+            custom_prompt("name", "message", &mut prompted_0)
+                .message("message")
+                .build(),
+            // Questions which are not asked can share a name
+            custom_prompt("name", "message", &mut prompted_1)
+                .message("message")
+                .when(false)
+                .build(),
+            Question::confirm("name").build(),
+        ],
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    );
+
+    match res {
+        Err(requestty::ErrorKind::IoError(e)) => {
+            assert_eq!(e.to_string(), "Duplicate question name 'name'")
+        }
+        res => panic!("expected a duplicate name error, got {:?}", res),
+    }
+
+    assert!(prompted_0);
+    assert!(!prompted_1);
+
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+
+    // Questions which are asked again are allowed
+    prompt_all(vec![
+        custom_prompt("name", "message", &mut prompted_0)
+            .message("message")
+            .build(),
+        custom_prompt("name", "message", &mut prompted_1)
+            .message("message")
+            .ask_if_answered(true)
+            .build(),
+    ]);
+
+    assert!(prompted_0);
+    assert!(prompted_1);
+}
+
+#[test]
+fn test_partial_answers() {
+    use ui::events::{KeyCode, KeyEvent, KeyModifiers};
+
+    let module = requestty::PromptModule::new(vec![
+        Question::input("first").build(),
+        Question::input("second").build(),
+        Question::input("third").build(),
+    ]);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+    ]);
+
+    let err = module
+        .prompt_all_with_partial(&mut backend, &mut events)
+        .unwrap_err();
+
+    assert!(matches!(err.error, requestty::ErrorKind::Interrupted));
+    assert_eq!(err.answers.len(), 1);
+    assert_eq!(err.answers["first"].as_string(), Some("a"));
+}
+
+#[test]
+fn test_check_defaults() {
+    let non_empty = |s: &str, _: &requestty::Answers| {
+        if s.is_empty() {
+            Err("cannot be empty".to_owned())
+        } else {
+            Ok(())
+        }
+    };
+
+    let mut module = requestty::PromptModule::new(vec![
+        Question::input("valid")
+            .default("default")
+            .validate(non_empty)
+            .build(),
+        Question::input("invalid")
+            .default("")
+            .validate(non_empty)
+            .build(),
+        // No default to validate
+        Question::input("no_default").validate(non_empty).build(),
+        Question::float("no_validate").default(1.0).build(),
+    ]);
+
+    assert_eq!(
+        module.check_defaults(),
+        Err(vec![("invalid".to_owned(), "cannot be empty".to_owned())])
+    );
+
+    // The questions can still be asked afterwards
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(Some(ui::events::KeyCode::Enter.into()));
+
+    let answer = module.prompt_with(&mut backend, &mut events).unwrap();
+    assert_eq!(answer.unwrap().as_string(), Some("default"));
+}
+
+#[test]
+fn test_summary_line() {
+    use ui::events::KeyCode;
+
+    let questions = vec![
+        Question::input("name").build(),
+        Question::int("age").build(),
+        Question::multi_select("toppings")
+            .choices(vec!["Cheese", "Olives", "Mushrooms"])
+            .build(),
+        Question::confirm("delivery").default(true).build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('A').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('3').into(),
+        KeyCode::Char('0').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        answers.summary_line(),
+        "name: A | age: 30 | toppings: Cheese, Mushrooms | delivery: true"
+    );
+}
+
+#[test]
+fn test_summary_line_deref_mut() {
+    use requestty::{Answer, Answers};
+
+    let mut answers: Answers = vec![
+        ("b".to_owned(), Answer::Int(1)),
+        ("a".to_owned(), Answer::Int(2)),
+    ]
+    .into_iter()
+    .collect();
+
+    // answers changed through the underlying map are sorted after the ordered ones
+    answers.remove("b");
+    answers.insert("d".to_owned(), Answer::Int(3));
+    answers.insert("c".to_owned(), Answer::Int(4));
+    assert_eq!(answers.summary_line(), "a: 2 | c: 4 | d: 3");
+
+    // an answer added again keeps the position it was first given in
+    answers.extend(vec![
+        ("b".to_owned(), Answer::Int(5)),
+        ("a".to_owned(), Answer::Int(6)),
+    ]);
+    assert_eq!(answers.summary_line(), "b: 5 | a: 6 | c: 4 | d: 3");
+}
+
+#[test]
+fn test_secret() {
+    use std::cell::RefCell;
+
+    use requestty::Answer;
+    use ui::events::KeyCode;
+
+    let answered = RefCell::new(Vec::new());
+    let on_answered = |name: &str, answer: &Answer| {
+        answered
+            .borrow_mut()
+            .push((name.to_owned(), answer.clone()))
+    };
+
+    let questions = vec![
+        Question::input("name").on_answered(on_answered).build(),
+        Question::password("password")
+            .on_answered(on_answered)
+            .build(),
+        Question::int("pin").secret(true).build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('A').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('p').into(),
+        KeyCode::Char('w').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('4').into(),
+        KeyCode::Char('2').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    // the real answers are kept for the program
+    assert_eq!(answers["password"], Answer::String("pw".into()));
+    assert_eq!(answers["pin"], Answer::Int(42));
+
+    assert_eq!(
+        answers.summary_line(),
+        "name: A | password: **** | pin: ****"
+    );
+    assert!(!format!("{:?}", answers).contains("pw"));
+    assert_eq!(
+        *answered.borrow(),
+        [
+            ("name".to_owned(), Answer::String("A".into())),
+            ("password".to_owned(), Answer::String("****".into())),
+        ]
+    );
+}
+
+#[test]
+fn test_answer_with() {
+    use requestty::{Answer, Answers, ErrorKind, ListItem};
+
+    let answers = Answers::default();
+
+    let answer = Question::input("name")
+        .default("Anonymous")
+        .filter(|name, _| name.to_uppercase())
+        .build()
+        .answer_with(String::new(), &answers)
+        .unwrap();
+    assert_eq!(answer, Some(Answer::String("ANONYMOUS".into())));
+
+    let res = Question::int("age")
+        .validate(|age, _| {
+            if age > 0 {
+                Ok(())
+            } else {
+                Err("Age must be positive".into())
+            }
+        })
+        .build()
+        .answer_with(-3_i64, &answers);
+    assert!(matches!(res, Err(ErrorKind::IoError(e)) if e.to_string() == "Age must be positive"));
+
+    let answer = Question::multi_select("toppings")
+        .choices(vec!["Cheese", "Olives", "Mushrooms"])
+        .filter(|mut selected, _| {
+            // Cheese is always included
+            selected[0] = true;
+            selected
+        })
+        .build()
+        .answer_with(vec![ListItem::from((2, "Mushrooms"))], &answers)
+        .unwrap();
+    assert_eq!(
+        answer,
+        Some(Answer::ListItems(vec![
+            (0, "Cheese").into(),
+            (2, "Mushrooms").into()
+        ]))
+    );
+
+    let answer = Question::order_select("home_tasks")
+        .choices(vec!["Dishes", "Laundry"])
+        .build()
+        .answer_with(
+            vec![ListItem::from((1, "")), ListItem::from((0, ""))],
+            &answers,
+        )
+        .unwrap();
+    assert_eq!(
+        answer,
+        Some(Answer::ListItems(vec![
+            (1, "Laundry").into(),
+            (0, "Dishes").into()
+        ]))
+    );
+
+    // Separators cannot be selected
+    let res = Question::select("theme")
+        .choices(vec![
+            "Light".into(),
+            requestty::DefaultSeparator,
+            "Dark".into(),
+        ])
+        .build()
+        .answer_with(ListItem::from((1, "")), &answers);
+    assert!(matches!(res, Err(ErrorKind::IoError(_))));
+
+    // Questions which would not be asked are not answered
+    let answer = Question::confirm("continue")
+        .when(false)
+        .build()
+        .answer_with(true, &answers)
+        .unwrap();
+    assert_eq!(answer, None);
+}
+
+#[test]
+fn test_on_answered() {
+    use requestty::Answer;
+    use std::cell::RefCell;
+    use ui::events::KeyCode;
+
+    let stored = RefCell::new(Vec::new());
+    let store =
+        |name: &str, answer: &Answer| stored.borrow_mut().push((name.to_owned(), answer.clone()));
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events =
+        ui::events::TestEvents::new(vec![KeyCode::Char('a').into(), KeyCode::Enter.into()]);
+
+    requestty::prompt_one_with(
+        Question::input("name").on_answered(store),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(
+        stored.take(),
+        vec![("name".to_owned(), Answer::String("a".into()))]
+    );
+
+    let questions = vec![
+        Question::input("name").on_answered(store).build(),
+        Question::confirm("skipped")
+            .when(false)
+            .on_answered(store)
+            .build(),
+        Question::int("age").on_answered(store).build(),
+        Question::confirm("continue").on_answered(store).build(),
+    ];
+
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('3').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        stored.take(),
+        vec![
+            ("name".to_owned(), Answer::String("b".into())),
+            ("age".to_owned(), Answer::Int(3)),
+            ("continue".to_owned(), Answer::Bool(true)),
+        ]
+    );
+}
+
 #[test]
 fn test_when() {
     let mut prompted_0 = false;
@@ -82,6 +455,251 @@ fn test_when() {
     assert!(!prompted_1);
 }
 
+#[test]
+fn test_force_skip_and_ask() {
+    let mut prompted_0 = false;
+    let mut prompted_1 = false;
+    let mut prompted_2 = false;
+    let mut prompted_3 = false;
+
+    let mut answers = requestty::Answers::default();
+    answers.insert("answered".to_owned(), Answer::Int(1));
+
+    let answers = requestty::PromptModule::new(vec![
+        custom_prompt("skipped", "message", &mut prompted_0)
+            .message("message")
+            .when(true)
+            .build(),
+        custom_prompt("asked", "message", &mut prompted_1)
+            .message("message")
+            .when(false)
+            .build(),
+        // force_skip takes precedence over force_ask
+        custom_prompt("both", "message", &mut prompted_2)
+            .message("message")
+            .build(),
+        custom_prompt("answered", "message", &mut prompted_3)
+            .message("message")
+            .build(),
+    ])
+    .with_answers(answers)
+    .force_skip(vec!["skipped", "both"])
+    .force_ask(vec!["asked", "both", "answered"])
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
+
+    assert!(!prompted_0);
+    assert!(prompted_1);
+    assert!(!prompted_2);
+    assert!(prompted_3);
+
+    assert!(!answers.contains_key("skipped"));
+    assert!(answers.contains_key("asked"));
+    assert_eq!(answers["answered"], Answer::Int(0));
+}
+
+#[test]
+fn test_confirm_on_interrupt() {
+    use ui::events::{KeyCode, KeyEvent, KeyModifiers, TestEvents};
+
+    let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+    let module = || {
+        requestty::PromptModule::new(vec![
+            Question::input("name").build(),
+            Question::input("address").build(),
+        ])
+        .confirm_on_interrupt(true)
+    };
+
+    // `n` stays in the question
+    let answers = module()
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Enter.into(),
+                ctrl_c,
+                KeyCode::Char('n').into(),
+                KeyCode::Char('b').into(),
+                KeyCode::Enter.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(answers["name"], Answer::String("a".into()));
+    assert_eq!(answers["address"], Answer::String("b".into()));
+
+    // `y` and a second `Ctrl+C` abort with the answers so far
+    for &key in &[KeyCode::Char('y').into(), ctrl_c] {
+        let err = module()
+            .prompt_all_with_partial(
+                &mut ui::backend::TestBackend::new((50, 20).into()),
+                &mut TestEvents::new(vec![
+                    KeyCode::Char('a').into(),
+                    KeyCode::Enter.into(),
+                    ctrl_c,
+                    key,
+                ]),
+            )
+            .unwrap_err();
+
+        assert!(matches!(err.error, requestty::ErrorKind::Interrupted));
+        assert_eq!(err.answers["name"], Answer::String("a".into()));
+        assert!(!err.answers.contains_key("address"));
+    }
+}
+
+#[test]
+fn test_tab_navigation() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let module = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("address").build(),
+    ])
+    .tab_navigation(true);
+
+    // `Shift+Tab` goes back to the name, which is answered again
+    let answers = module
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Tab.into(),
+                KeyCode::Char('b').into(),
+                KeyCode::BackTab.into(),
+                KeyCode::Char('c').into(),
+                KeyCode::Tab.into(),
+                KeyCode::Char('d').into(),
+                KeyCode::Tab.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(answers["name"], Answer::String("c".into()));
+    assert_eq!(answers["address"], Answer::String("d".into()));
+    assert_eq!(answers.summary_line(), "name: c | address: d");
+}
+
+#[test]
+fn test_tab_navigation_skipped() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let module = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("nickname")
+            .when(|answers: &requestty::Answers| answers["name"].as_string() == Some("b"))
+            .build(),
+        Question::input("address").build(),
+    ])
+    .tab_navigation(true);
+
+    // The skipped nickname could be asked after changing the name, but its `when` cannot be
+    // evaluated again. So it cannot be gone back past, and `Shift+Tab` is ignored.
+    let answers = module
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Tab.into(),
+                KeyCode::BackTab.into(),
+                KeyCode::Char('c').into(),
+                KeyCode::Tab.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(answers.summary_line(), "name: a | address: c");
+
+    let module = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::input("nickname").when(false).build(),
+        Question::input("address").build(),
+    ])
+    .tab_navigation(true);
+
+    // Skipped questions which can be copied are gone back past
+    let answers = module
+        .prompt_all_with(
+            &mut ui::backend::TestBackend::new((50, 20).into()),
+            &mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Tab.into(),
+                KeyCode::BackTab.into(),
+                KeyCode::Char('b').into(),
+                KeyCode::Tab.into(),
+                KeyCode::Char('c').into(),
+                KeyCode::Tab.into(),
+            ]),
+        )
+        .unwrap();
+
+    assert_eq!(answers.summary_line(), "name: b | address: c");
+}
+
+#[test]
+fn test_title() {
+    use ui::{
+        backend::Backend,
+        events::{KeyCode, TestEvents},
+    };
+
+    let mut module = requestty::PromptModule::new(vec![
+        Question::input("name").build(),
+        Question::confirm("skipped").when(false).build(),
+        Question::input("address").build(),
+    ])
+    .title("Setup");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    backend.set_title("shell").unwrap();
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    module.prompt_with(&mut backend, &mut events).unwrap();
+    assert_eq!(backend.title(), Some("Setup — Step 1"));
+
+    // the skipped question does not count as a step
+    module.prompt_with(&mut backend, &mut events).unwrap();
+    assert_eq!(backend.title(), Some("Setup — Step 2"));
+
+    // the title is restored once there are no questions left
+    assert!(module
+        .prompt_with(&mut backend, &mut events)
+        .unwrap()
+        .is_none());
+    assert_eq!(backend.title(), Some("shell"));
+}
+
+#[test]
+fn test_spacing() {
+    let size = (50, 20).into();
+    let module = requestty::PromptModule::new(vec![
+        Question::note("first").message("a").build(),
+        Question::note("skipped").message("-").when(false).build(),
+        Question::note("second").message("b").build(),
+    ])
+    .spacing(2);
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    module
+        .prompt_all_with(&mut backend, &mut ui::events::TestEvents::empty())
+        .unwrap();
+
+    // the skipped question does not add to the spacing, and there is none after the last question
+    backend.assert_eq(&ui::backend::TestBackend::from_lines(
+        &["a", "", "", "b", ""],
+        size,
+    ));
+}
+
 #[test]
 fn test_message() {
     let mut prompted_0 = false;
@@ -97,3 +715,254 @@ fn test_message() {
     assert!(prompted_0);
     assert!(prompted_1);
 }
+
+#[test]
+fn test_silent() {
+    let size = (50, 20).into();
+
+    let questions = vec![
+        Question::input("name")
+            .silent(true)
+            .transform(|name, _, b| write!(b, "Hello {}", name))
+            .build(),
+        Question::select("theme")
+            .choices(vec!["Light", "Dark"])
+            .silent(true)
+            .build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = ui::events::TestEvents::new(vec![
+        ui::events::KeyCode::Char('a').into(),
+        ui::events::KeyCode::Enter.into(),
+        ui::events::KeyCode::Down.into(),
+        ui::events::KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+    assert_eq!(answers["name"].as_string(), Some("a"));
+    assert_eq!(answers["theme"].as_list_item().unwrap().index, 1);
+
+    // The prompts should be cleared, with nothing written after they are answered
+    backend.assert_eq(&ui::backend::TestBackend::new(size));
+}
+
+#[test]
+fn test_widget_prompt() {
+    use ui::{
+        events::{KeyCode, KeyEvent},
+        layout::Layout,
+        widgets, Widget,
+    };
+
+    /// Counts the number of times `Up` is pressed
+    #[derive(Debug)]
+    struct Counter {
+        prompt: widgets::Prompt<String>,
+        count: i64,
+    }
+
+    impl Widget for Counter {
+        fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> std::io::Result<()> {
+            self.prompt.render(layout, b)?;
+            write!(b, "{}", self.count)
+        }
+
+        fn height(&mut self, layout: &mut Layout) -> u16 {
+            self.prompt.height(layout)
+        }
+
+        fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+            self.prompt.cursor_pos(layout)
+        }
+
+        fn handle_key(&mut self, key: KeyEvent) -> bool {
+            self.count += (key.code == KeyCode::Up) as i64;
+            key.code == KeyCode::Up
+        }
+    }
+
+    impl ui::Prompt for Counter {
+        type ValidateErr = &'static str;
+        type Output = i64;
+
+        fn finish(self) -> i64 {
+            self.count
+        }
+    }
+
+    let questions = vec![
+        Question::input("name").build(),
+        Question::custom(
+            "count",
+            requestty::question::WidgetPrompt::new(|message, answers: &Answers| {
+                assert_eq!(answers["name"].as_string(), Some("a"));
+                Counter {
+                    prompt: widgets::Prompt::new(message),
+                    count: 0,
+                }
+            }),
+        )
+        .message("count")
+        .build(),
+        Question::confirm("skipped")
+            .when(|answers: &Answers| answers["count"].as_int() != Some(2))
+            .build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = ui::events::TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Up.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(questions, &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers.len(), 2);
+    assert_eq!(answers["count"].as_int(), Some(2));
+}
+
+#[test]
+fn test_try_clone() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let question = Question::multi_select("toppings")
+        .message("toppings")
+        .choices_with_default(vec![("Cheese", true), ("Olives", false)])
+        .build();
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+
+    // the clone is asked with the configuration of the question, and the question can still be
+    // asked afterwards
+    for events in [
+        vec![KeyCode::Enter.into()],
+        vec![KeyCode::Char('i').into(), KeyCode::Enter.into()],
+    ] {
+        let items = requestty::prompt_one_with(
+            question.try_clone().unwrap(),
+            &mut backend,
+            &mut TestEvents::new(events),
+        )
+        .unwrap()
+        .try_into_list_items()
+        .unwrap();
+
+        assert_eq!(items.len(), 1);
+    }
+
+    let items = requestty::prompt_one_with(
+        question,
+        &mut backend,
+        &mut TestEvents::new(Some(KeyCode::Enter.into())),
+    )
+    .unwrap()
+    .try_into_list_items()
+    .unwrap();
+    assert_eq!(items[0].text, "Cheese");
+
+    // closures cannot be cloned
+    assert!(Question::input("name")
+        .filter(|name, _| name)
+        .build()
+        .try_clone()
+        .is_none());
+    assert!(Question::confirm("confirm")
+        .message(|_: &Answers| "confirm".to_owned())
+        .build()
+        .try_clone()
+        .is_none());
+}
+
+/// Records whether the cursor was hidden while prompting
+struct CursorBackend {
+    backend: ui::backend::TestBackend,
+    hidden: bool,
+}
+
+impl std::io::Write for CursorBackend {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.backend.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.backend.flush()
+    }
+}
+
+impl Backend for CursorBackend {
+    fn enable_raw_mode(&mut self) -> std::io::Result<()> {
+        self.backend.enable_raw_mode()
+    }
+    fn disable_raw_mode(&mut self) -> std::io::Result<()> {
+        self.backend.disable_raw_mode()
+    }
+    fn hide_cursor(&mut self) -> std::io::Result<()> {
+        self.hidden = true;
+        self.backend.hide_cursor()
+    }
+    fn show_cursor(&mut self) -> std::io::Result<()> {
+        self.backend.show_cursor()
+    }
+    fn get_cursor_pos(&mut self) -> std::io::Result<(u16, u16)> {
+        self.backend.get_cursor_pos()
+    }
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> std::io::Result<()> {
+        self.backend.move_cursor_to(x, y)
+    }
+    fn scroll(&mut self, dist: i16) -> std::io::Result<()> {
+        self.backend.scroll(dist)
+    }
+    fn set_attributes(&mut self, attributes: ui::style::Attributes) -> std::io::Result<()> {
+        self.backend.set_attributes(attributes)
+    }
+    fn set_fg(&mut self, color: ui::style::Color) -> std::io::Result<()> {
+        self.backend.set_fg(color)
+    }
+    fn set_bg(&mut self, color: ui::style::Color) -> std::io::Result<()> {
+        self.backend.set_bg(color)
+    }
+    fn clear(&mut self, clear_type: ui::backend::ClearType) -> std::io::Result<()> {
+        self.backend.clear(clear_type)
+    }
+    fn size(&self) -> std::io::Result<ui::backend::Size> {
+        self.backend.size()
+    }
+}
+
+#[test]
+fn test_hide_cursor() {
+    let cases = vec![
+        // prompts which do not take text hide the cursor by default
+        (Question::select("a").choice("a").build(), true),
+        (Question::multi_select("a").choice("a").build(), true),
+        (
+            Question::expand("a").choice('a', "a").default('a').build(),
+            true,
+        ),
+        (Question::input("a").default("a").build(), false),
+        // but it can be changed per prompt
+        (
+            Question::select("a").choice("a").hide_cursor(false).build(),
+            false,
+        ),
+        (
+            Question::input("a").default("a").hide_cursor(true).build(),
+            true,
+        ),
+    ];
+
+    for (question, hidden) in cases {
+        let mut backend = CursorBackend {
+            backend: ui::backend::TestBackend::new((50, 20).into()),
+            hidden: false,
+        };
+        let mut events = ui::events::TestEvents::new(Some(ui::events::KeyCode::Enter.into()));
+
+        requestty::prompt_one_with(question, &mut backend, &mut events).unwrap();
+        assert_eq!(backend.hidden, hidden);
+    }
+}
@@ -56,6 +56,53 @@ fn test_tranform() {
     assert_eq!(ans.index, 8);
 }
 
+#[test]
+fn test_show_number_input() {
+    let size = (50, 20).into();
+
+    let raw_select = Question::raw_select("name")
+        .message("message")
+        .choices(choices(10))
+        .show_number_input(false);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // Digits are not typed anywhere, so they do not move the highlight
+    let mut events = TestEvents::new(vec![
+        KeyCode::Down.into(),
+        KeyCode::Char('6').into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(raw_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 4);
+    assert_eq!(ans.text, "Choice 4");
+}
+
+#[test]
+fn test_answer_format() {
+    let size = (50, 20).into();
+
+    let raw_select = Question::raw_select("name")
+        .message("message")
+        .choices(choices(10))
+        .answer_format(|ans| format!("{} (id {})", ans.text, ans.index));
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyCode::Char('3').into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(raw_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 4);
+}
+
 #[test]
 fn test_default() {
     let size = (50, 20).into();
@@ -141,3 +188,27 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_many_choices() {
+    // the indices are right aligned past 9 and 99
+    let raw_select = Question::raw_select("name")
+        .message("message")
+        .choices((1..=105).map(|i| format!("Choice {}", i)))
+        .page_size(12);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('1').into(),
+        KeyCode::Char('0').into(),
+        KeyCode::Char('0').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(raw_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 99);
+}
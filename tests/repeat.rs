@@ -0,0 +1,181 @@
+use requestty::{Answer, Answers, Question};
+use ui::events::{KeyCode, TestEvents};
+
+#[test]
+fn test_repeat() {
+    let question =
+        Question::repeat(|| Question::input("tags").message("Add a tag").build()).build();
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Enter.into(),
+        // the empty answer stops the question
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(question, &mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        ans,
+        Answer::List(vec![
+            Answer::String("a".into()),
+            Answer::String("b".into()),
+            Answer::String("c".into()),
+        ])
+    );
+}
+
+#[test]
+fn test_stop_when() {
+    let question = Question::repeat(|| Question::int("scores").build())
+        .stop_when(|score| score.as_int() == Some(0))
+        .build();
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('5').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('0').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_with(Some(question), &mut backend, &mut events).unwrap();
+
+    assert_eq!(answers["scores"], Answer::List(vec![Answer::Int(5)]));
+    assert_eq!(answers.summary_line(), "scores: 5");
+}
+
+#[test]
+fn test_repeat_validate() {
+    let question = Question::repeat(|| {
+        Question::input("tags")
+            .validate(|tag, _| {
+                if tag.contains(' ') {
+                    Err("Tags cannot have spaces".into())
+                } else {
+                    Ok(())
+                }
+            })
+            .build()
+    })
+    .build();
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Char(' ').into(),
+        // the tag is not accepted
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(question, &mut backend, &mut events).unwrap();
+
+    assert_eq!(ans, Answer::List(vec![Answer::String("a".into())]));
+}
+
+#[test]
+fn test_repeat_tab_navigation() {
+    let module =
+        requestty::PromptModule::new(vec![
+            Question::repeat(|| Question::input("tags").build()).build()
+        ])
+        .tab_navigation(true);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Tab.into(),
+        KeyCode::Tab.into(),
+    ]);
+
+    let answers = module.prompt_all_with(&mut backend, &mut events).unwrap();
+
+    assert_eq!(
+        answers["tags"],
+        Answer::List(vec![Answer::String("a".into())])
+    );
+}
+
+#[test]
+fn test_answer_with_nested() {
+    let question =
+        || Question::repeat(|| Question::repeat(|| Question::input("tags").build()).build());
+
+    let value = Answer::List(vec![
+        Answer::List(vec![Answer::String("a".into()), Answer::String("b".into())]),
+        Answer::List(vec![]),
+        Answer::List(vec![Answer::String("c".into())]),
+    ]);
+
+    let ans = question()
+        .build()
+        .answer_with(value.clone(), &Answers::default())
+        .unwrap();
+
+    assert_eq!(ans, Some(value));
+
+    // The inner questions are answered as if they were asked, so they are filtered
+    let question = Question::repeat(|| {
+        Question::repeat(|| {
+            Question::input("tags")
+                .filter(|tag, _| tag.to_uppercase())
+                .build()
+        })
+        .build()
+    })
+    .build();
+
+    let ans = question
+        .answer_with(
+            Answer::List(vec![Answer::List(vec![Answer::String("a".into())])]),
+            &Answers::default(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        ans,
+        Some(Answer::List(vec![Answer::List(vec![Answer::String(
+            "A".into()
+        )])]))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_record_replay() {
+    let questions = || vec![Question::repeat(|| Question::input("tags").build()).build()];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('b').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let transcript = requestty::PromptModule::new(questions())
+        .record_with(&mut backend, &mut events)
+        .unwrap();
+
+    let tags = Answer::List(vec![Answer::String("a".into()), Answer::String("b".into())]);
+    assert_eq!(transcript.answers["tags"], tags);
+
+    let json = serde_json::to_string(&transcript).unwrap();
+    let transcript: requestty::Transcript = serde_json::from_str(&json).unwrap();
+    assert_eq!(transcript.answers["tags"], tags);
+
+    let answers = requestty::PromptModule::new(questions())
+        .prompt_all_with(&mut backend, &mut transcript.events())
+        .unwrap();
+
+    assert_eq!(answers, transcript.answers);
+}
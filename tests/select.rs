@@ -51,6 +51,564 @@ fn test_transform() {
     assert_eq!(ans.index, 8);
 }
 
+#[test]
+fn test_transform_with_choices() {
+    let select = requestty::Question::select("name")
+        .transform_with_choices(|item, choices, _, b| {
+            let position = choices.iter().position(|&choice| choice == item.text);
+            write!(
+                b,
+                "{} ({} of {})",
+                item.text,
+                position.unwrap() + 1,
+                choices.len()
+            )
+        })
+        .message("select")
+        .choices(vec![
+            "Pizza".into(),
+            "Pasta".into(),
+            requestty::DefaultSeparator,
+            "Soup".into(),
+        ]);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // the separator is not counted
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+
+    // the last transform is used
+    let select = requestty::Question::select("name")
+        .transform_with_choices(|_, _, _, b| write!(b, "With choices"))
+        .transform(|_, _, b| write!(b, "Without choices"))
+        .message("select")
+        .choices(vec!["Pizza", "Pasta"]);
+
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Enter)]);
+
+    requestty::prompt_one_with(select, &mut backend, &mut events).unwrap();
+}
+
+#[test]
+fn test_answer_format() {
+    let select = requestty::Question::select("name")
+        .answer_format(|item| format!("You chose: {} (id {})", item.text, item.index))
+        .message("select")
+        .choices(choices(10));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Down), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+}
+
+#[test]
+fn test_answer_format_with_transform() {
+    // transform takes precedence over answer_format
+    let select = requestty::Question::select("name")
+        .answer_format(|item| format!("You chose: {} (id {})", item.text, item.index))
+        .transform(|item, _, b| write!(b, "Transformed {}", item.index))
+        .message("select")
+        .choices(choices(10));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Enter)]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 0);
+}
+
+#[test]
+fn test_scroll_hint_format() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices((0..10).map(|i| format!("Choice {}", i)))
+        .page_size(5)
+        .scroll_hint_format(|hidden| format!("(Weitere {})", hidden));
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Down), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 1);
+}
+
+#[test]
+fn test_with_none() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["Choice 1", "Choice 2"])
+        .with_none("None of the above");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // loop around to the last choice
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Up), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert!(ans.is_none());
+    assert_eq!(ans.index, requestty::ListItem::NONE_INDEX);
+    assert_eq!(ans.text, "None of the above");
+}
+
+#[test]
+fn test_number_shortcuts() {
+    let select = || {
+        requestty::Question::select("name")
+            .message("select")
+            .choice("Red")
+            .separator("Dark")
+            .choices(vec!["Black", "Navy"])
+            .default_separator()
+            .choice("Blue")
+    };
+
+    let size = (50, 20).into();
+    let mut backend = ui::backend::TestBackend::new(size);
+    // `9` has no choice, so it is ignored
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('3')),
+        KeyCode::Char('9').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans =
+        requestty::prompt_one_with(select().number_shortcuts(true), &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+    // the separators are not counted
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "Navy");
+
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('4')),
+        KeyCode::Char('1').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans =
+        requestty::prompt_one_with(select().number_shortcuts(true), &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+    assert_eq!(ans.index, 0);
+
+    // the digits do nothing unless enabled
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('3')),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select(), &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 0);
+}
+
+#[test]
+fn test_empty_message() {
+    let select = || {
+        requestty::Question::select("name")
+            .message("select")
+            .separator("Colours")
+            .default_separator()
+    };
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // `Enter` does nothing, and `Esc` skips the question
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Enter), KeyCode::Esc.into()]);
+
+    let answers = requestty::prompt_with(
+        Some(select().empty_message("No options available").build()),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+    assert!(!answers.contains_key("name"));
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Esc)]);
+    let ans = requestty::prompt_one_with(
+        select()
+            .empty_message("No options available")
+            .on_esc(requestty::OnEsc::Terminate),
+        &mut backend,
+        &mut events,
+    );
+    assert!(matches!(ans, Err(requestty::ErrorKind::Aborted)));
+
+    // it is an error to ask without any choice to select if there is no message
+    let res = requestty::prompt_one_with(
+        requestty::Question::select("name").message("select"),
+        &mut backend,
+        &mut TestEvents::empty(),
+    );
+    assert!(
+        matches!(res, Err(requestty::ErrorKind::IoError(e)) if e.to_string() == "There are no choices to select")
+    );
+}
+
+#[test]
+fn test_with_other() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["Red", "Blue"])
+        .with_other("Other (please specify)")
+        .with_none("None of the above");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+        // an empty answer is not accepted
+        KeyCode::Enter.into(),
+        KeyCode::Char('T').into(),
+        KeyCode::Char('e').into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Char('l').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert!(ans.is_other());
+    assert_eq!(ans.index, requestty::ListItem::OTHER_INDEX);
+    assert_eq!(ans.text, "Teal");
+
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["Red", "Blue"])
+        .with_other("Other (please specify)");
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    // `Esc` goes back to the choices
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Up),
+        KeyCode::Enter.into(),
+        KeyCode::Char('x').into(),
+        KeyCode::Esc.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 1);
+    assert_eq!(ans.text, "Blue");
+}
+
+#[test]
+fn test_sorted() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["Pear", "Apple"])
+        .separator("Vegetables")
+        .choices(vec!["Leek", "Carrot"])
+        .default(0)
+        .with_none("Nothing")
+        .sorted();
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // the default stays on `Pear`, and the separator is skipped over to `Carrot`
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Down), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "Carrot");
+
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choices(vec!["b", "C", "a"])
+        .sorted_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Up), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 2);
+    assert_eq!(ans.text, "C");
+}
+
+#[test]
+fn test_icons() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choice_with_icon("🍕", "Order a pizza")
+        .choice("No icon")
+        .separator("Support")
+        .choices_with_icons(vec![("☎", "Contact support"), ("📅", "Make a reservation")]);
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "Contact support");
+}
+
+#[test]
+fn test_typed_select() {
+    #[derive(Debug, PartialEq)]
+    enum Size {
+        Small,
+        Medium,
+        Large,
+    }
+
+    let select = requestty::Question::typed_select("size")
+        .message("size")
+        .choice("Small", Size::Small)
+        .default_separator()
+        .choices(vec![("Medium", Size::Medium), ("Large", Size::Large)])
+        .build();
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    // skips over the separator
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let size = select.prompt_with(&mut backend, &mut events).unwrap();
+    assert_eq!(size, Size::Large);
+}
+
+#[test]
+fn test_typed_select_default_matching() {
+    let select = |previous: &'static str| {
+        requestty::Question::typed_select("size")
+            .message("size")
+            .choice("Small", "small".to_owned())
+            .default_separator()
+            .choices(vec![
+                ("Medium", "medium".to_owned()),
+                ("Large", "large".to_owned()),
+                ("Also large", "LARGE".to_owned()),
+            ])
+            .default(4)
+            .default_matching(move |size: &String| size.eq_ignore_ascii_case(previous))
+            .build()
+    };
+
+    let cases = [
+        // case-insensitive match
+        ("MEDIUM", "medium"),
+        // the first match wins
+        ("Large", "large"),
+        // no match leaves no default, not even the earlier one
+        ("huge", "small"),
+    ];
+
+    for (previous, expected) in cases {
+        let mut backend = ui::backend::TestBackend::new((50, 20).into());
+        let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Enter)]);
+
+        let size = select(previous)
+            .prompt_with(&mut backend, &mut events)
+            .unwrap();
+        assert_eq!(size, expected);
+    }
+
+    // default_matching can be overridden by a later default
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyEvent::from(KeyCode::Enter)]);
+
+    let size = requestty::Question::typed_select("size")
+        .choices(vec![("Small", 1), ("Medium", 2)])
+        .default_matching(|&size| size == 2)
+        .default(0)
+        .build()
+        .prompt_with(&mut backend, &mut events)
+        .unwrap();
+    assert_eq!(size, 1);
+}
+
+#[test]
+fn test_typed_select_all_choices() {
+    use std::fmt;
+
+    #[derive(Debug, PartialEq)]
+    enum Size {
+        Small,
+        Medium,
+        Large,
+    }
+
+    impl requestty::question::AsChoices for Size {
+        fn choices() -> Vec<Self> {
+            vec![Size::Small, Size::Medium, Size::Large]
+        }
+    }
+
+    impl fmt::Display for Size {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Size::Small => f.write_str("Small"),
+                Size::Medium => f.write_str("Medium"),
+                Size::Large => f.write_str("Large"),
+            }
+        }
+    }
+
+    let select = requestty::Question::typed_select::<Size, _>("size")
+        .message("size")
+        .all_choices()
+        .build();
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let size = select.prompt_with(&mut backend, &mut events).unwrap();
+    assert_eq!(size, Size::Large);
+}
+
+#[test]
+fn test_horizontal() {
+    // (size, events, expected index)
+    let cases: [((u16, u16), Vec<KeyEvent>, usize); 2] = [
+        // fits on a single line
+        (
+            (50, 10),
+            vec![KeyCode::Right.into(), KeyCode::Enter.into()],
+            1,
+        ),
+        // wraps to the next line, and loops around to the last choice
+        (
+            (20, 10),
+            vec![KeyCode::Left.into(), KeyCode::Enter.into()],
+            3,
+        ),
+    ];
+
+    for (size, events, index) in cases {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .choices(vec![
+                "Yes".into(),
+                "No".into(),
+                requestty::DefaultSeparator,
+                "Maybe".into(),
+            ])
+            .orientation(requestty::question::Orientation::Horizontal);
+
+        let mut backend = helpers::SnapshotOnFlushBackend::new(size.into());
+        let mut events = TestEvents::new(events);
+
+        let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+        assert_eq!(ans.index, index);
+    }
+}
+
+#[test]
+fn test_grid_navigation() {
+    // (events, expected index) with the choices in 3 columns:
+    // Tag 0  Tag 1  Tag 2
+    // Tag 3  Tag 4  Tag 5
+    // Tag 6
+    let cases: [(Vec<KeyEvent>, usize); 4] = [
+        (vec![KeyCode::Right.into(), KeyCode::Down.into()], 4),
+        // left wraps to the end of the previous row
+        (vec![KeyCode::Down.into(), KeyCode::Left.into()], 2),
+        // up loops around to the last choice in the column
+        (vec![KeyCode::Up.into()], 6),
+        (vec![KeyCode::Right.into(), KeyCode::Up.into()], 4),
+    ];
+
+    for (events, index) in cases {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .choices((0..7).map(|i| format!("Tag {}", i)))
+            .orientation(requestty::question::Orientation::Grid);
+
+        let mut backend = ui::backend::TestBackend::new((40, 10).into());
+        let mut events = TestEvents::new(
+            events
+                .into_iter()
+                .chain(Some(KeyEvent::from(KeyCode::Enter))),
+        );
+
+        let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+        assert_eq!(ans.index, index);
+    }
+}
+
 #[test]
 fn test_on_esc() {
     let size = (50, 20).into();
@@ -87,3 +645,93 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_pointer_position() {
+    use requestty::question::PointerPosition;
+
+    for pointer_position in [
+        PointerPosition::Left,
+        PointerPosition::Right,
+        PointerPosition::Both,
+    ] {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .choices(vec![
+                "Open".into(),
+                "A long choice which wraps before the pointer".into(),
+                requestty::DefaultSeparator,
+                "打开文件".into(),
+            ])
+            .pointer_position(pointer_position);
+
+        let size = (30, 10).into();
+        let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+        let mut events = TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Down),
+            KeyCode::Down.into(),
+            KeyCode::Enter.into(),
+        ]);
+
+        let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+        assert_eq!(ans.index, 3);
+    }
+}
+
+#[test]
+fn test_default_separator_width() {
+    // the separator fills the rest of the line regardless of the width
+    for width in [20, 60] {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .choices(vec!["Yes".into(), requestty::DefaultSeparator, "No".into()]);
+
+        let mut backend = helpers::SnapshotOnFlushBackend::new((width, 10).into());
+        let mut events =
+            TestEvents::new(vec![KeyEvent::from(KeyCode::Down), KeyCode::Enter.into()]);
+
+        let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+
+        assert_eq!(ans.index, 2);
+    }
+}
+
+#[test]
+fn test_groups() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .choice("Red")
+        .group("Dark", vec!["Black", "Navy"], false)
+        .group("Light", vec!["White", "Yellow"], true)
+        .choice("Blue");
+
+    let size = (50, 20).into();
+    let mut backend = helpers::SnapshotOnFlushBackend::new(size);
+    // Enter on a header expands or collapses the group instead of selecting it
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Down),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Down.into(),
+        KeyCode::Left.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.index, 3);
+    assert_eq!(ans.text, "Navy");
+}
@@ -0,0 +1,73 @@
+use std::{cell::RefCell, io, rc::Rc};
+
+use requestty::{PromptModule, Question};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, TestEvents},
+};
+
+/// A buffer which can still be read after it is given to the module.
+#[derive(Clone, Default)]
+struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+impl io::Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_stream_to() {
+    let buf = SharedBuf::default();
+    let questions = vec![
+        Question::input("name").build(),
+        Question::password("password").build(),
+        Question::confirm("skipped").when(false).build(),
+        Question::select("colour")
+            .choices(vec!["Red", "Blue"])
+            .build(),
+    ];
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('j').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('p').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let mut module = PromptModule::new(questions).stream_to(buf.clone());
+
+    // Every answer is written as soon as it is given
+    module.prompt_with(&mut backend, &mut events).unwrap();
+    assert_eq!(
+        String::from_utf8(buf.0.borrow().clone()).unwrap(),
+        "{\"name\":\"name\",\"value\":{\"String\":\"j\"}}\n"
+    );
+
+    module.prompt_all_with(&mut backend, &mut events).unwrap();
+
+    let output = String::from_utf8(buf.0.borrow().clone()).unwrap();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(
+        lines,
+        vec![
+            serde_json::json!({ "name": "name", "value": { "String": "j" } }),
+            serde_json::json!({ "name": "password", "value": { "String": "****" } }),
+            serde_json::json!({
+                "name": "colour",
+                "value": { "ListItem": { "index": 1, "text": "Blue" } }
+            }),
+        ]
+    );
+}
@@ -0,0 +1,124 @@
+use std::{
+    fmt::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use requestty::{ErrorKind, OnEsc, Question};
+use tracing_crate::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+use ui::events::{KeyCode, TestEvents};
+
+/// Formats the name and fields of an event or span as `name field=value...`
+struct FieldsVisitor(String);
+
+impl Visit for FieldsVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        write!(self.0, " {}={:?}", field.name(), value).unwrap();
+    }
+}
+
+/// Records every event along with the span it was emitted in
+#[derive(Default)]
+struct Recorder {
+    spans: Mutex<Vec<String>>,
+    stack: Mutex<Vec<usize>>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        let mut visitor = FieldsVisitor(span.metadata().name().to_owned());
+        span.record(&mut visitor);
+
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(visitor.0);
+        span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = FieldsVisitor(event.metadata().name().to_owned());
+        event.record(&mut visitor);
+
+        let span = match self.stack.lock().unwrap().last() {
+            Some(&i) => self.spans.lock().unwrap()[i - 1].clone(),
+            None => String::new(),
+        };
+
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("[{}] {}", span, visitor.0));
+    }
+
+    fn enter(&self, span: &span::Id) {
+        self.stack.lock().unwrap().push(span.into_u64() as usize);
+    }
+
+    fn exit(&self, _: &span::Id) {
+        self.stack.lock().unwrap().pop();
+    }
+}
+
+#[test]
+fn test_tracing_events() {
+    let recorder = Recorder::default();
+    let events = Arc::clone(&recorder.events);
+
+    let questions = vec![
+        Question::input("name")
+            .validate(|name, _| {
+                if name.is_empty() {
+                    Err("Please enter a name".into())
+                } else {
+                    Ok(())
+                }
+            })
+            .build(),
+        Question::confirm("skipped").when(false).build(),
+        Question::confirm("aborted")
+            .on_esc(OnEsc::Terminate)
+            .build(),
+    ];
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut key_events = TestEvents::new(vec![
+        KeyCode::Enter.into(),
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Esc.into(),
+    ]);
+
+    let res = tracing_crate::subscriber::with_default(recorder, || {
+        requestty::prompt_with(questions, &mut backend, &mut key_events)
+    });
+    assert!(matches!(res, Err(ErrorKind::Aborted)));
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            r#"[question name=name] prompt.start"#.to_owned(),
+            r#"[question name=name] prompt.key"#.to_owned(),
+            r#"[question name=name] prompt.validate result="error""#.to_owned(),
+            r#"[question name=name] prompt.key"#.to_owned(),
+            r#"[question name=name] prompt.key"#.to_owned(),
+            r#"[question name=name] prompt.validate result="finish""#.to_owned(),
+            r#"[question name=name] prompt.finish outcome=Submitted"#.to_owned(),
+            r#"[question name=name] question.answered"#.to_owned(),
+            r#"[question name=skipped] question.skip reason="when""#.to_owned(),
+            r#"[question name=aborted] prompt.start"#.to_owned(),
+            r#"[question name=aborted] prompt.key"#.to_owned(),
+            r#"[question name=aborted] prompt.error error="aborted""#.to_owned(),
+            r#"[question name=aborted] question.error error=ESC"#.to_owned(),
+        ]
+    );
+}
@@ -0,0 +1,129 @@
+use requestty::{Answer, Answers, PromptModule, Question, Transcript};
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, KeyEvent, KeyModifiers, TestEvents},
+};
+
+fn questions() -> Vec<Question<'static>> {
+    vec![
+        Question::input("name").build(),
+        Question::confirm("skipped").when(false).build(),
+        Question::select("colour")
+            .choices(vec!["red", "green", "blue"])
+            .build(),
+    ]
+}
+
+#[test]
+fn test_record_replay() {
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyEvent::new(KeyCode::Char('B'), KeyModifiers::SHIFT),
+        KeyCode::Enter.into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let transcript = PromptModule::new(questions())
+        .record_with(&mut backend, &mut events)
+        .unwrap();
+
+    let names: Vec<_> = transcript.questions.iter().map(|q| &*q.name).collect();
+    assert_eq!(names, ["name", "colour"]);
+    assert_eq!(transcript.questions[0].keys.len(), 3);
+    assert_eq!(transcript.questions[1].keys.len(), 2);
+    assert_eq!(transcript.answers["name"].as_string(), Some("aB"));
+    assert_eq!(
+        transcript.answers["colour"].as_list_item().map(|i| i.index),
+        Some(1)
+    );
+
+    let json = serde_json::to_string(&transcript).unwrap();
+    let transcript: Transcript = serde_json::from_str(&json).unwrap();
+
+    let answers = PromptModule::new(questions())
+        .prompt_all_with(&mut backend, &mut transcript.events())
+        .unwrap();
+
+    assert_eq!(answers, transcript.answers);
+}
+
+#[test]
+fn test_format() {
+    let key =
+        serde_json::to_value(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)).unwrap();
+    assert_eq!(
+        key,
+        serde_json::json!({ "code": { "Char": "c" }, "modifiers": 2 })
+    );
+
+    assert!(serde_json::from_value::<KeyEvent>(serde_json::json!({
+        "code": "Enter",
+        "modifiers": 0b1000
+    }))
+    .is_err());
+}
+
+#[test]
+fn test_secret() {
+    let questions = || {
+        vec![
+            Question::input("user").build(),
+            Question::password("password").build(),
+            Question::confirm("remember").build(),
+        ]
+    };
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('p').into(),
+        KeyCode::Char('w').into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('y').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let transcript = PromptModule::new(questions())
+        .record_with(&mut backend, &mut events)
+        .unwrap();
+
+    // None of the keys pressed for the password are recorded
+    let secret: Vec<_> = transcript.questions.iter().map(|q| q.secret).collect();
+    assert_eq!(secret, [false, true, false]);
+    assert!(transcript.questions[1].keys.is_empty());
+    assert_eq!(transcript.answers["password"].as_string(), Some("****"));
+
+    let json = serde_json::to_string(&transcript).unwrap();
+    let transcript: Transcript = serde_json::from_str(&json).unwrap();
+
+    // The password has to be given to replay the transcript
+    let answers = PromptModule::new(questions())
+        .prompt_all_with(
+            &mut backend,
+            &mut transcript.events_with(|name| {
+                assert_eq!(name, "password");
+                Some("hunter2".into())
+            }),
+        )
+        .unwrap();
+
+    assert_eq!(answers["user"].as_string(), Some("a"));
+    assert_eq!(answers["password"].as_string(), Some("hunter2"));
+    assert_eq!(answers["remember"].as_bool(), Some(true));
+
+    // Otherwise, it has to be answered beforehand so that it is skipped
+    let mut answers = Answers::default();
+    answers.insert("password".into(), Answer::String("hunter2".into()));
+
+    let answers = PromptModule::new(questions())
+        .with_answers(answers)
+        .prompt_all_with(&mut backend, &mut transcript.events())
+        .unwrap();
+
+    assert_eq!(answers["user"].as_string(), Some("a"));
+    assert_eq!(answers["remember"].as_bool(), Some(true));
+}